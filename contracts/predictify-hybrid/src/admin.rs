@@ -2,7 +2,10 @@ extern crate alloc;
 use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 // use alloc::string::ToString; // Unused import
 
-use crate::config::{ConfigManager, ConfigUtils, ContractConfig, Environment};
+use crate::config::{
+    ConfigManager, ConfigUtils, ContractConfig, Environment, PendingConfigUpdate,
+    ADMIN_TRANSFER_EXPIRY_SECONDS,
+};
 use crate::errors::Error;
 use crate::events::EventEmitter;
 use crate::extensions::ExtensionManager;
@@ -93,6 +96,16 @@ pub struct AdminRoleAssignment {
     pub is_active: bool,
 }
 
+/// Pending two-step admin ownership transfer
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct PendingAdminTransfer {
+    pub current_admin: Address,
+    pub proposed_admin: Address,
+    pub proposed_at: u64,
+    pub expires_at: u64,
+}
+
 /// Admin analytics
 #[derive(Clone, Debug)]
 #[contracttype]
@@ -278,12 +291,7 @@ impl AdminInitializer {
         // Initialize basic admin setup
         AdminInitializer::initialize(env, admin)?;
 
-        let config = match environment {
-            Environment::Development => ConfigManager::get_development_config(env),
-            Environment::Testnet => ConfigManager::get_testnet_config(env),
-            Environment::Mainnet => ConfigManager::get_mainnet_config(env),
-            Environment::Custom => ConfigManager::get_development_config(env),
-        };
+        let config = ConfigManager::get_default_config(env, environment);
         ConfigManager::store_config(env, &config)?;
 
         // Emit configuration initialization event
@@ -718,6 +726,8 @@ impl AdminAccessControl {
             "manage_disputes" => Ok(AdminPermission::ManageDisputes),
             "view_analytics" => Ok(AdminPermission::ViewAnalytics),
             "emergency_actions" => Ok(AdminPermission::EmergencyActions),
+            "transfer_admin" => Ok(AdminPermission::EmergencyActions),
+            "upgrade_contract" => Ok(AdminPermission::EmergencyActions),
             _ => Err(Error::InvalidInput),
         }
     }
@@ -1175,6 +1185,149 @@ impl AdminRoleManager {
     }
 }
 
+// ===== ADMIN OWNERSHIP TRANSFER =====
+
+/// Two-step transfer of contract ownership.
+///
+/// The current admin proposes a successor, who must then authenticate and
+/// accept before the proposal expires. Ownership never moves to an address
+/// that has not proven it can sign, so a typo in the proposed address cannot
+/// lock the contract. On acceptance the new admin replaces the stored
+/// "Admin" key and receives the SuperAdmin role with all its permissions.
+pub struct AdminTransferManager;
+
+impl AdminTransferManager {
+    /// Proposes `new_admin` as the next contract admin.
+    ///
+    /// The proposal replaces any earlier pending proposal and expires after
+    /// `ADMIN_TRANSFER_EXPIRY_SECONDS`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::AdminNotSet` - The contract has not been initialized
+    /// - `Error::Unauthorized` - Caller is not the current SuperAdmin
+    /// - `Error::InvalidInput` - `new_admin` is already the admin
+    pub fn propose_transfer(
+        env: &Env,
+        admin: &Address,
+        new_admin: &Address,
+    ) -> Result<PendingAdminTransfer, Error> {
+        AdminAccessControl::validate_admin_for_action(env, admin, "transfer_admin")?;
+        AdminValidator::validate_admin_address(env, new_admin)?;
+
+        if admin == new_admin {
+            return Err(Error::InvalidInput);
+        }
+
+        let now = env.ledger().timestamp();
+        let pending = PendingAdminTransfer {
+            current_admin: admin.clone(),
+            proposed_admin: new_admin.clone(),
+            proposed_at: now,
+            expires_at: now + ADMIN_TRANSFER_EXPIRY_SECONDS,
+        };
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, "PendingAdmin"), &pending);
+
+        EventEmitter::emit_admin_transfer_proposed(env, admin, new_admin, pending.expires_at);
+
+        let mut params = Map::new(env);
+        params.set(String::from_str(env, "new_admin"), new_admin.to_string());
+        AdminActionLogger::log_action(
+            env,
+            admin,
+            "propose_admin_transfer",
+            None,
+            params,
+            true,
+            None,
+        )?;
+
+        Ok(pending)
+    }
+
+    /// Completes a pending transfer. Must be signed by the proposed admin.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidState` - No transfer is pending or the proposal expired
+    /// - `Error::Unauthorized` - Caller is not the proposed admin
+    pub fn accept_transfer(env: &Env, new_admin: &Address) -> Result<(), Error> {
+        new_admin.require_auth();
+
+        let pending = Self::get_pending_transfer(env).ok_or(Error::InvalidState)?;
+        if &pending.proposed_admin != new_admin {
+            return Err(Error::Unauthorized);
+        }
+        if env.ledger().timestamp() >= pending.expires_at {
+            return Err(Error::InvalidState);
+        }
+
+        // The outgoing admin hands over the SuperAdmin role before losing it
+        AdminRoleManager::assign_role(
+            env,
+            new_admin,
+            AdminRole::SuperAdmin,
+            &pending.current_admin,
+        )?;
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, "Admin"), new_admin);
+        env.storage()
+            .persistent()
+            .remove(&Symbol::new(env, "PendingAdmin"));
+
+        EventEmitter::emit_admin_transfer_accepted(env, &pending.current_admin, new_admin);
+        AdminActionLogger::log_action(
+            env,
+            new_admin,
+            "accept_admin_transfer",
+            None,
+            Map::new(env),
+            true,
+            None,
+        )?;
+
+        Ok(())
+    }
+
+    /// Withdraws a pending transfer (current admin only).
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the current SuperAdmin
+    /// - `Error::InvalidState` - No transfer is pending
+    pub fn cancel_transfer(env: &Env, admin: &Address) -> Result<(), Error> {
+        AdminAccessControl::validate_admin_for_action(env, admin, "transfer_admin")?;
+
+        let pending = Self::get_pending_transfer(env).ok_or(Error::InvalidState)?;
+        env.storage()
+            .persistent()
+            .remove(&Symbol::new(env, "PendingAdmin"));
+
+        EventEmitter::emit_admin_transfer_cancelled(env, admin, &pending.proposed_admin);
+        AdminActionLogger::log_action(
+            env,
+            admin,
+            "cancel_admin_transfer",
+            None,
+            Map::new(env),
+            true,
+            None,
+        )?;
+
+        Ok(())
+    }
+
+    /// Returns the pending transfer, including an expired one that was never accepted.
+    pub fn get_pending_transfer(env: &Env) -> Option<PendingAdminTransfer> {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, "PendingAdmin"))
+    }
+}
+
 // ===== ADMIN FUNCTIONS =====
 pub struct AdminFunctions;
 
@@ -1527,11 +1680,13 @@ impl AdminFunctions {
         Ok(())
     }
 
-    /// Updates the platform fee configuration (admin only).
+    /// Queues an update to the platform fee configuration (admin only).
     ///
     /// This function allows authorized admins to modify the fee structure
     /// used throughout the platform, including platform fees, creation fees,
-    /// and other fee-related parameters. Changes take effect immediately.
+    /// and other fee-related parameters. Fees are part of the contract
+    /// configuration, so changes take effect once the configuration timelock
+    /// expires.
     ///
     /// # Parameters
     ///
@@ -1541,8 +1696,8 @@ impl AdminFunctions {
     ///
     /// # Returns
     ///
-    /// Returns `Result<FeeConfig, Error>` where:
-    /// - `Ok(FeeConfig)` - Updated fee configuration
+    /// Returns `Result<PendingConfigUpdate, Error>` where:
+    /// - `Ok(PendingConfigUpdate)` - The queued update and when it takes effect
     /// - `Err(Error)` - Update failed due to permissions or validation
     ///
     /// # Errors
@@ -1550,6 +1705,7 @@ impl AdminFunctions {
     /// This function returns specific errors:
     /// - `Error::Unauthorized` - Admin lacks UpdateFees permission
     /// - `Error::InvalidInput` - Fee configuration contains invalid values
+    /// - `Error::InvalidState` - Another configuration update is still pending
     /// - Fee validation errors from FeeManager
     /// - Storage operation errors
     ///
@@ -1569,9 +1725,8 @@ impl AdminFunctions {
     ///
     /// // Update platform fees
     /// match AdminFunctions::update_fee_config(&env, &admin, &new_config) {
-    ///     Ok(updated_config) => {
-    ///         println!("Fees updated successfully");
-    ///         println!("New platform fee: {}%", updated_config.platform_fee_percentage / 100);
+    ///     Ok(pending) => {
+    ///         println!("Fee update queued until {}", pending.effective_at);
     ///     },
     ///     Err(e) => {
     ///         println!("Failed to update fees: {:?}", e);
@@ -1592,14 +1747,14 @@ impl AdminFunctions {
     /// The update process:
     /// 1. **Permission Validation**: Ensures admin has UpdateFees permission
     /// 2. **Configuration Validation**: Validates new fee parameters
-    /// 3. **Fee Update**: Uses FeeManager to apply new configuration
+    /// 3. **Queueing**: Uses FeeManager to queue the new contract configuration
     /// 4. **Action Logging**: Records fee update for audit trail
     ///
     /// # Impact and Considerations
     ///
-    /// Fee updates have immediate platform-wide effects:
-    /// - New markets use updated creation fees
-    /// - Existing market resolutions use updated platform fees
+    /// Fee updates have platform-wide effects once in force:
+    /// - New markets use updated creation and platform fees
+    /// - Existing markets keep the fees snapshotted at their creation
     /// - User interfaces should reflect new fee structure
     /// - Consider gradual rollout for major fee changes
     ///
@@ -1614,12 +1769,13 @@ impl AdminFunctions {
         env: &Env,
         admin: &Address,
         new_config: &FeeConfig,
-    ) -> Result<FeeConfig, Error> {
+    ) -> Result<PendingConfigUpdate, Error> {
         // Validate admin permissions
         AdminAccessControl::validate_admin_for_action(env, admin, "update_fees")?;
 
-        // Update fee configuration
-        let updated_config = FeeManager::update_fee_config(env, admin.clone(), new_config.clone())?;
+        // Queue the fee update behind the configuration timelock
+        let pending = FeeManager::update_fee_config(env, admin.clone(), new_config.clone())?;
+        EventEmitter::emit_config_update_queued(env, admin, pending.effective_at);
 
         // Log admin action
         let mut params = Map::new(env);
@@ -1633,14 +1789,16 @@ impl AdminFunctions {
         );
         AdminActionLogger::log_action(env, admin, "update_fees", None, params, true, None)?;
 
-        Ok(updated_config)
+        Ok(pending)
     }
 
-    /// Updates the core contract configuration (admin only).
+    /// Queues an update to the core contract configuration (admin only).
     ///
-    /// This function allows authorized admins to modify fundamental contract
-    /// settings including market limits, validation thresholds, oracle timeouts,
-    /// and other operational parameters. Changes affect all contract operations.
+    /// This function allows authorized admins to propose changes to fundamental
+    /// contract settings including fees, voting rules, market limits and oracle
+    /// timeouts. Changes are timelocked: they take effect
+    /// `CONFIG_UPDATE_DELAY_SECONDS` after being queued and can be cancelled
+    /// with `cancel_contract_config_update` until then.
     ///
     /// # Parameters
     ///
@@ -1650,109 +1808,108 @@ impl AdminFunctions {
     ///
     /// # Returns
     ///
-    /// Returns `Result<(), Error>` where:
-    /// - `Ok(())` - Configuration updated successfully
+    /// Returns `Result<PendingConfigUpdate, Error>` where:
+    /// - `Ok(PendingConfigUpdate)` - The queued update and its effective timestamp
     /// - `Err(Error)` - Update failed due to permissions or validation
     ///
     /// # Errors
     ///
     /// This function returns specific errors:
     /// - `Error::Unauthorized` - Admin lacks UpdateConfig permission
-    /// - `Error::InvalidInput` - Configuration contains invalid values
-    /// - Configuration validation errors from ConfigManager
-    /// - Storage operation errors
+    /// - `Error::InvalidState` - Another update is still pending
+    /// - Configuration validation errors from ConfigValidator
     ///
     /// # Example
     ///
     /// ```rust
     /// # use soroban_sdk::{Env, Address};
     /// # use predictify_hybrid::admin::AdminFunctions;
-    /// # use predictify_hybrid::config::{ContractConfig, Environment};
+    /// # use predictify_hybrid::config::ConfigManager;
     /// # let env = Env::default();
     /// # let admin = Address::generate(&env);
-    /// # let new_config = ContractConfig {
-    /// #     environment: Environment::Mainnet,
-    /// #     max_market_duration_days: 365,
-    /// #     min_market_duration_days: 1,
-    /// #     max_outcomes_per_market: 10,
-    /// #     oracle_timeout_seconds: 3600,
-    /// # };
+    /// let mut new_config = ConfigManager::get_mainnet_config(&env);
+    /// new_config.fees.platform_fee_percentage = 4;
     ///
-    /// // Update contract configuration for mainnet
     /// match AdminFunctions::update_contract_config(&env, &admin, &new_config) {
-    ///     Ok(()) => {
-    ///         println!("Contract configuration updated successfully");
+    ///     Ok(pending) => {
+    ///         println!("Configuration update effective at {}", pending.effective_at);
     ///     },
     ///     Err(e) => {
-    ///         println!("Failed to update configuration: {:?}", e);
+    ///         println!("Failed to queue configuration update: {:?}", e);
     ///     }
     /// }
     /// ```
     ///
-    /// # Configuration Parameters
-    ///
-    /// The ContractConfig typically includes:
-    /// - **Environment**: Target deployment environment (Development/Testnet/Mainnet)
-    /// - **Market Limits**: Duration limits, outcome limits, participation limits
-    /// - **Validation Thresholds**: Minimum stakes, consensus requirements
-    /// - **Oracle Settings**: Timeout values, retry limits, fallback options
-    /// - **Extension Limits**: Maximum extensions per market, total extension days
-    ///
     /// # Update Process
     ///
     /// The configuration update process:
     /// 1. **Permission Validation**: Ensures admin has UpdateConfig permission
     /// 2. **Configuration Validation**: Validates all configuration parameters
-    /// 3. **Config Update**: Uses ConfigManager to store new configuration
-    /// 4. **Environment Detection**: Determines and logs environment type
+    /// 3. **Queueing**: Stores the update with its effective timestamp
+    /// 4. **Event Emission**: Emits a config update queued event
     /// 5. **Action Logging**: Records configuration change for audit trail
     ///
     /// # Impact Assessment
     ///
-    /// Configuration changes can have significant impacts:
-    /// - **Market Creation**: New limits apply to future markets
-    /// - **Existing Markets**: Some changes may affect active markets
-    /// - **Oracle Integration**: Timeout changes affect oracle reliability
-    /// - **User Experience**: Limits affect what users can do
-    ///
-    /// # Environment-Specific Considerations
-    ///
-    /// Different environments have different optimal settings:
-    /// - **Development**: Relaxed limits for testing
-    /// - **Testnet**: Production-like but with test-friendly parameters
-    /// - **Mainnet**: Strict, secure, production-optimized settings
-    ///
-    /// # Change Management
-    ///
-    /// For production deployments:
-    /// - Test configuration changes thoroughly
-    /// - Consider gradual rollout strategies
-    /// - Monitor system behavior after changes
-    /// - Have rollback procedures ready
-    /// - Document all configuration changes
+    /// - **Existing Markets**: Keep the configuration snapshot taken at creation
+    /// - **New Markets**: Use the new configuration once it is in effect
+    /// - **Users**: Have the full timelock window to react to announced changes
     pub fn update_contract_config(
         env: &Env,
         admin: &Address,
         new_config: &ContractConfig,
-    ) -> Result<(), Error> {
+    ) -> Result<PendingConfigUpdate, Error> {
         // Validate admin permissions
         AdminAccessControl::validate_admin_for_action(env, admin, "update_config")?;
 
-        // Update contract configuration
-        ConfigManager::update_config(env, &new_config)?;
-        let env_name = ConfigUtils::get_environment_name(&new_config);
+        // Queue contract configuration update
+        let pending = ConfigManager::update_config(env, admin, new_config)?;
+        EventEmitter::emit_config_update_queued(env, admin, pending.effective_at);
+
+        let env_name = ConfigUtils::get_environment_name(new_config);
         let mut params = Map::new(env);
         params.set(String::from_str(env, "environment"), env_name);
         AdminActionLogger::log_action(env, admin, "update_config", None, params, true, None)?;
 
+        Ok(pending)
+    }
+
+    /// Cancels a queued contract configuration update (admin only).
+    ///
+    /// Cancellation is only possible while the update is still timelocked;
+    /// once its effective timestamp has passed the update is in force.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Admin lacks UpdateConfig permission
+    /// - `Error::ConfigurationNotFound` - No update is queued
+    /// - `Error::InvalidState` - The update has already taken effect
+    pub fn cancel_contract_config_update(env: &Env, admin: &Address) -> Result<(), Error> {
+        // Validate admin permissions
+        AdminAccessControl::validate_admin_for_action(env, admin, "update_config")?;
+
+        let cancelled = ConfigManager::cancel_pending_config(env)?;
+        EventEmitter::emit_config_update_cancelled(env, admin, cancelled.effective_at);
+
+        AdminActionLogger::log_action(
+            env,
+            admin,
+            "cancel_config_update",
+            None,
+            Map::new(env),
+            true,
+            None,
+        )?;
+
         Ok(())
     }
 
     /// Resets the contract configuration to default values (admin only).
     ///
     /// This function allows authorized admins to restore the contract configuration
-    /// to its default state, effectively undoing all previous configuration changes.
-    /// This is useful for recovery scenarios or returning to known-good settings.
+    /// to the defaults of the environment the contract was initialized for,
+    /// effectively undoing all previous configuration changes. Like any other
+    /// configuration update, the reset is queued behind the configuration timelock.
     ///
     /// # Parameters
     ///
@@ -1761,15 +1918,15 @@ impl AdminFunctions {
     ///
     /// # Returns
     ///
-    /// Returns `Result<ContractConfig, Error>` where:
-    /// - `Ok(ContractConfig)` - The default configuration that was applied
+    /// Returns `Result<PendingConfigUpdate, Error>` where:
+    /// - `Ok(PendingConfigUpdate)` - The queued reset and when it takes effect
     /// - `Err(Error)` - Reset failed due to permissions or system errors
     ///
     /// # Errors
     ///
     /// This function returns specific errors:
     /// - `Error::Unauthorized` - Admin lacks ResetConfig permission
-    /// - Configuration reset errors from ConfigManager
+    /// - `Error::InvalidState` - Another configuration update is still pending
     /// - Storage operation errors
     ///
     /// # Example
@@ -1782,9 +1939,8 @@ impl AdminFunctions {
     ///
     /// // Reset configuration to defaults after problematic changes
     /// match AdminFunctions::reset_config_to_defaults(&env, &admin) {
-    ///     Ok(default_config) => {
-    ///         println!("Configuration reset to defaults successfully");
-    ///         println!("Environment: {:?}", default_config.environment);
+    ///     Ok(pending) => {
+    ///         println!("Configuration reset queued until {}", pending.effective_at);
     ///     },
     ///     Err(e) => {
     ///         println!("Failed to reset configuration: {:?}", e);
@@ -1795,7 +1951,7 @@ impl AdminFunctions {
     /// # Default Configuration
     ///
     /// The default configuration typically includes:
-    /// - **Environment**: Development (safest default)
+    /// - **Environment**: The environment the contract was initialized for
     /// - **Market Duration**: 1-30 days (conservative range)
     /// - **Outcomes Limit**: 2-5 outcomes per market
     /// - **Oracle Timeout**: 1 hour (reasonable default)
@@ -1805,10 +1961,10 @@ impl AdminFunctions {
     ///
     /// The reset process:
     /// 1. **Permission Validation**: Ensures admin has ResetConfig permission
-    /// 2. **Default Retrieval**: Gets default configuration from ConfigManager
-    /// 3. **Configuration Reset**: Applies default configuration
+    /// 2. **Default Retrieval**: Gets the environment's defaults from ConfigManager
+    /// 3. **Queueing**: Queues the defaults behind the configuration timelock
     /// 4. **Action Logging**: Records reset action for audit trail
-    /// 5. **Return Defaults**: Returns the applied default configuration
+    /// 5. **Return Update**: Returns the queued configuration update
     ///
     /// # Use Cases
     ///
@@ -1822,8 +1978,8 @@ impl AdminFunctions {
     /// # Impact and Considerations
     ///
     /// Resetting configuration affects:
-    /// - **Active Markets**: May change behavior of ongoing markets
-    /// - **User Limits**: Changes what users can do immediately
+    /// - **Active Markets**: Keep the configuration snapshot taken at creation
+    /// - **User Limits**: Change once the timelock expires
     /// - **Oracle Integration**: May affect oracle timeout behavior
     /// - **Platform Behavior**: Returns all settings to baseline
     ///
@@ -1842,17 +1998,21 @@ impl AdminFunctions {
     /// - Update fee structures if needed
     /// - Verify oracle integrations work correctly
     /// - Test market creation and resolution
-    pub fn reset_config_to_defaults(env: &Env, admin: &Address) -> Result<ContractConfig, Error> {
+    pub fn reset_config_to_defaults(
+        env: &Env,
+        admin: &Address,
+    ) -> Result<PendingConfigUpdate, Error> {
         // Validate admin permissions
         AdminAccessControl::validate_admin_for_action(env, admin, "reset_config")?;
 
-        // Reset configuration
-        let default_config = ConfigManager::reset_to_defaults(env)?;
+        // Queue the reset behind the configuration timelock
+        let pending = ConfigManager::reset_to_defaults(env, admin)?;
+        EventEmitter::emit_config_update_queued(env, admin, pending.effective_at);
 
         // Log admin action
         AdminActionLogger::log_action(env, admin, "reset_config", None, Map::new(env), true, None)?;
 
-        Ok(default_config)
+        Ok(pending)
     }
 }
 
@@ -2845,6 +3005,86 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_admin_transfer_two_step() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+        let stranger = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            AdminInitializer::initialize(&env, &admin).unwrap();
+
+            // Proposing does not move ownership
+            AdminTransferManager::propose_transfer(&env, &admin, &new_admin).unwrap();
+            assert!(AdminUtils::is_admin(&env, &admin));
+            assert_eq!(
+                AdminTransferManager::accept_transfer(&env, &stranger),
+                Err(Error::Unauthorized)
+            );
+
+            AdminTransferManager::accept_transfer(&env, &new_admin).unwrap();
+            assert!(AdminUtils::is_admin(&env, &new_admin));
+            assert!(!AdminUtils::is_admin(&env, &admin));
+            assert!(AdminTransferManager::get_pending_transfer(&env).is_none());
+
+            // New admin inherits the full SuperAdmin permission set
+            assert_eq!(
+                AdminRoleManager::get_admin_role(&env, &new_admin).unwrap(),
+                AdminRole::SuperAdmin
+            );
+            assert!(AdminAccessControl::validate_permission(
+                &env,
+                &new_admin,
+                &AdminPermission::EmergencyActions
+            )
+            .is_ok());
+        });
+    }
+
+    #[test]
+    fn test_admin_transfer_expiry_and_cancel() {
+        use soroban_sdk::testutils::Ledger;
+
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+
+        // Each call runs in its own frame so auth can be required again
+        env.as_contract(&contract_id, || {
+            AdminInitializer::initialize(&env, &admin).unwrap();
+            AdminTransferManager::propose_transfer(&env, &admin, &new_admin).unwrap();
+        });
+
+        env.ledger()
+            .set_timestamp(env.ledger().timestamp() + ADMIN_TRANSFER_EXPIRY_SECONDS);
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                AdminTransferManager::accept_transfer(&env, &new_admin),
+                Err(Error::InvalidState)
+            );
+            assert!(AdminUtils::is_admin(&env, &admin));
+        });
+
+        // A fresh proposal can be withdrawn before acceptance
+        env.as_contract(&contract_id, || {
+            AdminTransferManager::propose_transfer(&env, &admin, &new_admin).unwrap();
+        });
+        env.as_contract(&contract_id, || {
+            AdminTransferManager::cancel_transfer(&env, &admin).unwrap();
+        });
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                AdminTransferManager::accept_transfer(&env, &new_admin),
+                Err(Error::InvalidState)
+            );
+        });
+    }
+
     #[test]
     fn test_admin_testing_utilities() {
         let env = Env::default();
@@ -28,6 +28,7 @@ pub struct VoteData {
     pub voter: Address,
     pub outcome: String,
     pub stake_amount: i128,
+    pub referrer: Option<Address>,
 }
 
 #[derive(Clone, Debug)]
@@ -268,6 +269,17 @@ impl BatchProcessor {
             vote_data.stake_amount,
         )?;
 
+        // Attribute the vote to its referrer
+        if let Some(referrer) = &vote_data.referrer {
+            crate::referrals::ReferralManager::record_referral(
+                env,
+                &vote_data.market_id,
+                &vote_data.voter,
+                referrer,
+                vote_data.stake_amount,
+            )?;
+        }
+
         Ok(())
     }
 
@@ -828,6 +840,7 @@ impl BatchTesting {
             voter: Address::generate(env),
             outcome: String::from_str(env, "Yes"),
             stake_amount: 1_000_000_000, // 100 XLM
+            referrer: None,
         }
     }
 
@@ -316,6 +316,7 @@ mod batch_operations_tests {
             voter: Address::generate(&env),
             outcome: String::from_str(&env, "Yes"),
             stake_amount: 1_000_000_000,
+            referrer: None,
         };
         
         // Invalid vote data - zero stake
@@ -324,6 +325,7 @@ mod batch_operations_tests {
             voter: Address::generate(&env),
             outcome: String::from_str(&env, "Yes"),
             stake_amount: 0,
+            referrer: None,
         };
         
         // Invalid vote data - empty outcome
@@ -332,6 +334,7 @@ mod batch_operations_tests {
             voter: Address::generate(&env),
             outcome: String::from_str(&env, ""),
             stake_amount: 1_000_000_000,
+            referrer: None,
         };
         
         // Test claim data validation
@@ -0,0 +1,139 @@
+//! Market creation bonds for Predictify Hybrid
+//!
+//! Creators without the `CreateMarket` admin permission back their market with
+//! a bond in the stake token instead of paying the creation fee:
+//! - The bond is held by the contract while the market is open
+//! - It is returned to the creator when the market resolves cleanly
+//! - It is slashed into the fee treasury if a dispute cancels the market as
+//!   invalid or ambiguous
+
+use soroban_sdk::{contracttype, symbol_short, token, Address, Env, Symbol};
+
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::treasury::FeeTreasury;
+
+// ===== BOND TYPES =====
+
+/// Lifecycle of a creation bond
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BondStatus {
+    /// Bond is held by the contract
+    Held,
+    /// Bond was returned to the creator
+    Returned,
+    /// Bond was slashed into the fee treasury
+    Slashed,
+}
+
+/// Bond posted by a permissionless market creator
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreationBond {
+    /// Market the bond backs
+    pub market_id: Symbol,
+    /// Creator who posted the bond
+    pub creator: Address,
+    /// Token the bond is denominated in
+    pub token: Address,
+    /// Bond amount
+    pub amount: i128,
+    /// Current bond status
+    pub status: BondStatus,
+}
+
+// ===== BOND MANAGER =====
+
+/// Posts, returns and slashes market creation bonds
+pub struct BondManager;
+
+impl BondManager {
+    /// Transfer a creation bond from the creator into the contract.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - `amount` is not positive
+    /// - `Error::InvalidState` - No token is configured, or the market already has a bond
+    pub fn post_bond(
+        env: &Env,
+        market_id: &Symbol,
+        creator: &Address,
+        amount: i128,
+    ) -> Result<CreationBond, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        if Self::get_bond(env, market_id).is_some() {
+            return Err(Error::InvalidState);
+        }
+
+        let token = FeeTreasury::get_token(env)?;
+        token::Client::new(env, &token).transfer(creator, &env.current_contract_address(), &amount);
+
+        let bond = CreationBond {
+            market_id: market_id.clone(),
+            creator: creator.clone(),
+            token,
+            amount,
+            status: BondStatus::Held,
+        };
+        Self::store_bond(env, &bond);
+
+        EventEmitter::emit_creation_bond_updated(env, &bond);
+        Ok(bond)
+    }
+
+    /// Return a held bond to its creator.
+    ///
+    /// Does nothing if the market has no bond or it was already settled.
+    pub fn release_bond(env: &Env, market_id: &Symbol) -> Result<(), Error> {
+        let mut bond = match Self::get_bond(env, market_id) {
+            Some(bond) if bond.status == BondStatus::Held => bond,
+            _ => return Ok(()),
+        };
+
+        token::Client::new(env, &bond.token).transfer(
+            &env.current_contract_address(),
+            &bond.creator,
+            &bond.amount,
+        );
+
+        bond.status = BondStatus::Returned;
+        Self::store_bond(env, &bond);
+
+        EventEmitter::emit_creation_bond_updated(env, &bond);
+        Ok(())
+    }
+
+    /// Slash a held bond into the fee treasury.
+    ///
+    /// Does nothing if the market has no bond or it was already settled.
+    pub fn slash_bond(env: &Env, market_id: &Symbol) -> Result<(), Error> {
+        let mut bond = match Self::get_bond(env, market_id) {
+            Some(bond) if bond.status == BondStatus::Held => bond,
+            _ => return Ok(()),
+        };
+
+        FeeTreasury::accrue(env, market_id, bond.amount)?;
+
+        bond.status = BondStatus::Slashed;
+        Self::store_bond(env, &bond);
+
+        EventEmitter::emit_creation_bond_updated(env, &bond);
+        Ok(())
+    }
+
+    /// Get the creation bond backing a market, if any.
+    pub fn get_bond(env: &Env, market_id: &Symbol) -> Option<CreationBond> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("crt_bond"), market_id.clone()))
+    }
+
+    fn store_bond(env: &Env, bond: &CreationBond) {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("crt_bond"), bond.market_id.clone()), bond);
+    }
+}
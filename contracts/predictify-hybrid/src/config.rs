@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Env, String, Symbol};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
 
 use crate::errors::Error;
 
@@ -34,6 +34,35 @@ pub const MAX_QUESTION_LENGTH: u32 = 500;
 /// Maximum outcome length in characters
 pub const MAX_OUTCOME_LENGTH: u32 = 100;
 
+/// Maximum number of markets returned by one listing query
+pub const MAX_MARKETS_PER_PAGE: u32 = 50;
+
+/// Maximum number of index positions one listing query scans
+pub const MAX_MARKETS_SCANNED_PER_PAGE: u32 = 200;
+
+// ===== MARKET METADATA CONSTANTS =====
+
+/// Maximum market category length in characters
+pub const MAX_CATEGORY_LENGTH: u32 = 32;
+
+/// Maximum number of tags per market
+pub const MAX_MARKET_TAGS: u32 = 5;
+
+/// Maximum tag length in characters
+pub const MAX_TAG_LENGTH: u32 = 32;
+
+/// Maximum market description length in characters
+pub const MAX_DESCRIPTION_LENGTH: u32 = 1000;
+
+/// Maximum length of each resolution criteria field in characters
+pub const MAX_RESOLUTION_CRITERIA_LENGTH: u32 = 1000;
+
+/// Maximum source URL length in characters
+pub const MAX_SOURCE_URL_LENGTH: u32 = 256;
+
+/// Maximum image hash length in characters
+pub const MAX_IMAGE_HASH_LENGTH: u32 = 128;
+
 // ===== FEE CONSTANTS =====
 
 /// Default platform fee percentage (2%)
@@ -57,6 +86,39 @@ pub const MAX_PLATFORM_FEE_PERCENTAGE: i128 = 10;
 /// Minimum platform fee percentage
 pub const MIN_PLATFORM_FEE_PERCENTAGE: i128 = 0;
 
+/// Default share of the platform fee paid to market creators (20%)
+pub const DEFAULT_CREATOR_FEE_PERCENTAGE: i128 = 20;
+
+/// Maximum share of the platform fee paid to market creators (50%)
+pub const MAX_CREATOR_FEE_PERCENTAGE: i128 = 50;
+
+/// Default share of a claimant's platform fee credited to their referrer (10%)
+pub const DEFAULT_REFERRAL_FEE_PERCENTAGE: i128 = 10;
+
+/// Maximum share of a claimant's platform fee credited to their referrer (50%)
+pub const MAX_REFERRAL_FEE_PERCENTAGE: i128 = 50;
+
+/// Default fee kept from stakes refunded by an invalid market (none)
+pub const DEFAULT_INVALID_MARKET_FEE_PERCENTAGE: i128 = 0;
+
+/// Default cumulative volume for the medium fee discount tier (100 XLM)
+pub const DEFAULT_DISCOUNT_VOLUME_MEDIUM: i128 = 1_000_000_000;
+
+/// Default cumulative volume for the high fee discount tier (1000 XLM)
+pub const DEFAULT_DISCOUNT_VOLUME_HIGH: i128 = 10_000_000_000;
+
+/// Default share of the platform fee waived in the medium discount tier (10%)
+pub const DEFAULT_DISCOUNT_PERCENTAGE_MEDIUM: i128 = 10;
+
+/// Default share of the platform fee waived in the high discount tier (25%)
+pub const DEFAULT_DISCOUNT_PERCENTAGE_HIGH: i128 = 25;
+
+/// Maximum share of the platform fee waived by a volume discount (50%)
+pub const MAX_DISCOUNT_PERCENTAGE: i128 = 50;
+
+/// Maximum fee kept from stakes refunded by an invalid market (5%)
+pub const MAX_INVALID_MARKET_FEE_PERCENTAGE: i128 = 5;
+
 // ===== VOTING CONSTANTS =====
 
 /// Minimum vote stake (0.1 XLM)
@@ -80,6 +142,12 @@ pub const HIGH_ACTIVITY_THRESHOLD: u32 = 100;
 /// Dispute extension hours
 pub const DISPUTE_EXTENSION_HOURS: u32 = 24;
 
+/// Reward paid to winning disputers as a percentage of their bond (20%)
+pub const DISPUTE_REWARD_PERCENTAGE: i128 = 20;
+
+/// Final dispute escalation round, decided by the admin arbiter
+pub const MAX_DISPUTE_ESCALATION_LEVEL: u32 = 3;
+
 // ===== EXTENSION CONSTANTS =====
 
 /// Maximum extension days
@@ -111,6 +179,9 @@ pub const COMMUNITY_WEIGHT_PERCENTAGE: u32 = 30;
 /// Minimum votes for community consensus
 pub const MIN_VOTES_FOR_CONSENSUS: u32 = 5;
 
+/// Minimum stake for a dispute vote to count under one-address-one-vote (1 XLM)
+pub const MIN_DISPUTE_VOTE_STAKE: i128 = 10_000_000;
+
 // ===== ORACLE CONSTANTS =====
 
 /// Maximum oracle price age (1 hour)
@@ -139,6 +210,17 @@ pub const RESOLUTION_ANALYTICS_STORAGE_KEY: &str = "ResolutionAnalytics";
 /// Storage key for oracle statistics
 pub const ORACLE_STATS_STORAGE_KEY: &str = "OracleStats";
 
+/// Storage key for a queued configuration update
+pub const PENDING_CONFIG_STORAGE_KEY: &str = "PendingConfig";
+
+// ===== GOVERNANCE CONSTANTS =====
+
+/// Delay before a queued configuration update takes effect (48 hours)
+pub const CONFIG_UPDATE_DELAY_SECONDS: u64 = 48 * 60 * 60;
+
+/// Time a proposed admin has to accept an ownership transfer (7 days)
+pub const ADMIN_TRANSFER_EXPIRY_SECONDS: u64 = 7 * 24 * 60 * 60;
+
 // ===== CONFIGURATION STRUCTS =====
 
 /// Deployment environment specification for the Predictify Hybrid contract.
@@ -359,6 +441,13 @@ pub struct NetworkConfig {
 ///     max_fee_amount: 100_000_000,     // 10 XLM maximum
 ///     collection_threshold: 50_000_000, // Collect at 5 XLM
 ///     fees_enabled: true,              // Fees are active
+///     creator_fee_percentage: 20,      // 20% of the fee to the creator
+///     referral_fee_percentage: 10,     // 10% of a claimant's fee to their referrer
+///     invalid_market_fee_percentage: 0, // Invalid markets refund stakes in full
+///     discount_volume_medium: 1_000_000_000,  // 10% off from 100 XLM staked
+///     discount_volume_high: 10_000_000_000,   // 25% off from 1000 XLM staked
+///     discount_percentage_medium: 10,
+///     discount_percentage_high: 25,
 /// };
 ///
 /// // Calculate platform fee for a 100 XLM payout
@@ -418,6 +507,42 @@ pub struct FeeConfig {
     /// - Creation fees may still apply (depends on implementation)
     /// - Useful for promotional periods or testing
     pub fees_enabled: bool,
+
+    /// Share of the platform fee paid to the market creator (percentage).
+    ///
+    /// Rewards creators for attracting volume. The share is scaled by the
+    /// market's size tier, so larger markets earn their creator more, and is
+    /// capped at [`MAX_CREATOR_FEE_PERCENTAGE`].
+    pub creator_fee_percentage: i128,
+
+    /// Share of a claimant's platform fee credited to their referrer (percentage).
+    ///
+    /// Applies to votes cast with a referrer. The credit is booked to the
+    /// referrer in the fee treasury when the referred user claims, and is
+    /// capped at [`MAX_REFERRAL_FEE_PERCENTAGE`].
+    pub referral_fee_percentage: i128,
+
+    /// Share of each stake kept when a market resolves as invalid (percentage).
+    ///
+    /// Invalid markets refund every voter's stake; this fee is taken from each
+    /// refund and booked to the fee treasury. Zero by default, and capped at
+    /// [`MAX_INVALID_MARKET_FEE_PERCENTAGE`].
+    pub invalid_market_fee_percentage: i128,
+
+    /// Cumulative staked volume (in stroops) that earns the medium discount.
+    pub discount_volume_medium: i128,
+
+    /// Cumulative staked volume (in stroops) that earns the high discount.
+    pub discount_volume_high: i128,
+
+    /// Share of the platform fee waived in the medium tier (percentage).
+    pub discount_percentage_medium: i128,
+
+    /// Share of the platform fee waived in the high tier (percentage).
+    ///
+    /// Must not be below the medium tier's share, and is capped at
+    /// [`MAX_DISCOUNT_PERCENTAGE`].
+    pub discount_percentage_high: i128,
 }
 
 /// Voting and dispute mechanism configuration for prediction markets.
@@ -456,6 +581,7 @@ pub struct FeeConfig {
 ///     large_market_threshold: 1_000_000_000, // 100 XLM = large market
 ///     high_activity_threshold: 100,     // 100+ votes = high activity
 ///     dispute_extension_hours: 24,      // 24 hour dispute window
+///     dispute_reward_percentage: 20,    // 20% of the bond for overturning a result
 /// };
 ///
 /// // Check if market qualifies as large
@@ -517,6 +643,13 @@ pub struct VotingConfig {
     /// - Oracle re-evaluation
     /// - Consensus building
     pub dispute_extension_hours: u32,
+
+    /// Reward paid to disputers who overturn a result, as a percentage of
+    /// their bond.
+    ///
+    /// Funded first from the stakes of dispute voters on the losing side,
+    /// then from the market's platform fee.
+    pub dispute_reward_percentage: i128,
 }
 
 /// Market creation and structure configuration parameters.
@@ -745,7 +878,7 @@ pub struct ExtensionConfig {
 /// # Example
 ///
 /// ```rust
-/// # use predictify_hybrid::config::ResolutionConfig;
+/// # use predictify_hybrid::config::{DisputeVoteWeighting, ResolutionConfig};
 ///
 /// // Create balanced resolution configuration
 /// let resolution_config = ResolutionConfig {
@@ -754,6 +887,8 @@ pub struct ExtensionConfig {
 ///     oracle_weight_percentage: 70, // Oracle has 70% influence
 ///     community_weight_percentage: 30, // Community has 30% influence
 ///     min_votes_for_consensus: 5,  // Need at least 5 votes
+///     dispute_vote_weighting: DisputeVoteWeighting::Stake,
+///     min_dispute_vote_stake: 10_000_000, // 1 XLM to count per address
 /// };
 ///
 /// // Calculate weighted resolution
@@ -811,6 +946,34 @@ pub struct ResolutionConfig {
     ///
     /// Typical values: 3-10 votes depending on platform size
     pub min_votes_for_consensus: u32,
+
+    /// How dispute votes are weighted when tallying a dispute.
+    ///
+    /// Stake weighting lets large holders swing disputes; quadratic,
+    /// reputation and one-address-one-vote weighting dampen that.
+    pub dispute_vote_weighting: DisputeVoteWeighting,
+
+    /// Minimum stake a dispute vote needs to count under
+    /// `DisputeVoteWeighting::Reputation` and
+    /// `DisputeVoteWeighting::OneAddressOneVote`.
+    ///
+    /// Filters out dust votes from throwaway addresses.
+    pub min_dispute_vote_stake: i128,
+}
+
+/// Strategy for weighting dispute votes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DisputeVoteWeighting {
+    /// Each vote counts its stake
+    Stake,
+    /// Each vote counts the square root of its stake
+    QuadraticStake,
+    /// Each vote counts the voter's historic accuracy score if its stake
+    /// meets the minimum
+    Reputation,
+    /// Each address counts once if its stake meets the minimum
+    OneAddressOneVote,
 }
 
 /// Oracle integration and reliability configuration parameters.
@@ -1021,6 +1184,85 @@ pub struct ContractConfig {
     pub oracle: OracleConfig,
 }
 
+/// A configuration update waiting out its timelock.
+///
+/// Configuration changes are never applied in the same transaction that
+/// proposes them. Instead they are queued with an `effective_at` timestamp
+/// of at least [`CONFIG_UPDATE_DELAY_SECONDS`] in the future, giving users
+/// time to react and giving admins a window to cancel a mistaken change.
+///
+/// Only one update can be pending at a time; a new proposal requires the
+/// previous one to be cancelled or to have taken effect.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct PendingConfigUpdate {
+    /// The configuration that will apply once the timelock expires
+    pub config: ContractConfig,
+    /// Admin who queued the update
+    pub proposed_by: Address,
+    /// Timestamp when the update was queued
+    pub proposed_at: u64,
+    /// Timestamp from which the update is in effect
+    pub effective_at: u64,
+}
+
+/// Per-market overrides of the global contract configuration.
+///
+/// Some markets need different economics than the contract-wide defaults,
+/// e.g. a high-stakes election market with larger minimum stakes and a
+/// longer dispute window versus a small community poll with lower fees.
+/// Each value that is set replaces the corresponding setting of the
+/// configuration governing the market; unset values keep the global settings.
+///
+/// Overrides are validated with [`ConfigValidator`] before a market is
+/// created and are fixed for the lifetime of the market.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[contracttype]
+pub struct MarketConfigOverrides {
+    /// Platform fee percentage charged on this market's payouts
+    pub platform_fee_percentage: Option<i128>,
+    /// Minimum stake required to vote
+    pub min_vote_stake: Option<i128>,
+    /// Minimum stake required to dispute the result
+    pub min_dispute_stake: Option<i128>,
+    /// Hours the market is extended when its result is disputed
+    pub dispute_extension_hours: Option<u32>,
+    /// Maximum number of days for a single extension
+    pub max_extension_days: Option<u32>,
+    /// Maximum number of extensions allowed
+    pub max_total_extensions: Option<u32>,
+    /// Extension fee per day in stroops
+    pub extension_fee_per_day: Option<i128>,
+}
+
+impl MarketConfigOverrides {
+    /// Apply the set overrides to a configuration
+    pub fn apply_to(&self, config: &mut ContractConfig) {
+        if let Some(value) = self.platform_fee_percentage {
+            config.fees.platform_fee_percentage = value;
+        }
+        if let Some(value) = self.min_vote_stake {
+            config.voting.min_vote_stake = value;
+        }
+        if let Some(value) = self.min_dispute_stake {
+            config.voting.min_dispute_stake = value;
+        }
+        if let Some(value) = self.dispute_extension_hours {
+            config.voting.dispute_extension_hours = value;
+        }
+        if let Some(value) = self.max_extension_days {
+            config.extension.max_extension_days = value;
+        }
+        if let Some(value) = self.max_total_extensions {
+            config.extension.max_total_extensions = value;
+        }
+        if let Some(value) = self.extension_fee_per_day {
+            config.extension.fee_per_day = value;
+        }
+
+    }
+}
+
 // ===== CONFIGURATION MANAGER =====
 
 /// Centralized configuration management for the Predictify Hybrid contract.
@@ -1417,6 +1659,13 @@ impl ConfigManager {
             max_fee_amount: MAX_FEE_AMOUNT,
             collection_threshold: FEE_COLLECTION_THRESHOLD,
             fees_enabled: true,
+            creator_fee_percentage: DEFAULT_CREATOR_FEE_PERCENTAGE,
+            referral_fee_percentage: DEFAULT_REFERRAL_FEE_PERCENTAGE,
+            invalid_market_fee_percentage: DEFAULT_INVALID_MARKET_FEE_PERCENTAGE,
+            discount_volume_medium: DEFAULT_DISCOUNT_VOLUME_MEDIUM,
+            discount_volume_high: DEFAULT_DISCOUNT_VOLUME_HIGH,
+            discount_percentage_medium: DEFAULT_DISCOUNT_PERCENTAGE_MEDIUM,
+            discount_percentage_high: DEFAULT_DISCOUNT_PERCENTAGE_HIGH,
         }
     }
 
@@ -1480,6 +1729,13 @@ impl ConfigManager {
             max_fee_amount: 2_000_000_000,     // 200 XLM for mainnet
             collection_threshold: 200_000_000, // 20 XLM for mainnet
             fees_enabled: true,
+            creator_fee_percentage: DEFAULT_CREATOR_FEE_PERCENTAGE,
+            referral_fee_percentage: DEFAULT_REFERRAL_FEE_PERCENTAGE,
+            invalid_market_fee_percentage: DEFAULT_INVALID_MARKET_FEE_PERCENTAGE,
+            discount_volume_medium: DEFAULT_DISCOUNT_VOLUME_MEDIUM,
+            discount_volume_high: DEFAULT_DISCOUNT_VOLUME_HIGH,
+            discount_percentage_medium: DEFAULT_DISCOUNT_PERCENTAGE_MEDIUM,
+            discount_percentage_high: DEFAULT_DISCOUNT_PERCENTAGE_HIGH,
         }
     }
 
@@ -1546,6 +1802,7 @@ impl ConfigManager {
             large_market_threshold: LARGE_MARKET_THRESHOLD,
             high_activity_threshold: HIGH_ACTIVITY_THRESHOLD,
             dispute_extension_hours: DISPUTE_EXTENSION_HOURS,
+            dispute_reward_percentage: DISPUTE_REWARD_PERCENTAGE,
         }
     }
 
@@ -1613,6 +1870,7 @@ impl ConfigManager {
             large_market_threshold: 2_000_000_000, // 200 XLM for mainnet
             high_activity_threshold: 200,          // 200 votes for mainnet
             dispute_extension_hours: 48,           // 48 hours for mainnet
+            dispute_reward_percentage: DISPUTE_REWARD_PERCENTAGE,
         }
     }
 
@@ -1819,6 +2077,8 @@ impl ConfigManager {
             oracle_weight_percentage: ORACLE_WEIGHT_PERCENTAGE,
             community_weight_percentage: COMMUNITY_WEIGHT_PERCENTAGE,
             min_votes_for_consensus: MIN_VOTES_FOR_CONSENSUS,
+            dispute_vote_weighting: DisputeVoteWeighting::Stake,
+            min_dispute_vote_stake: MIN_DISPUTE_VOTE_STAKE,
         }
     }
 
@@ -2066,6 +2326,14 @@ impl ConfigManager {
     /// - Oracle integration and resolution
     /// - Admin operations and updates
     pub fn get_config(env: &Env) -> Result<ContractConfig, Error> {
+        // A queued update whose timelock has expired is already in effect,
+        // even if nobody has written it back to storage yet.
+        if let Some(pending) = Self::get_pending_config(env) {
+            if env.ledger().timestamp() >= pending.effective_at {
+                return Ok(pending.config);
+            }
+        }
+
         let key = Symbol::new(env, "ContractConfig");
         env.storage()
             .persistent()
@@ -2073,130 +2341,279 @@ impl ConfigManager {
             .ok_or(Error::ConfigurationNotFound)
     }
 
-    /// Updates the contract configuration in persistent storage.
+    /// Queues a contract configuration update behind the configuration timelock.
     ///
-    /// This function provides a convenient wrapper for updating the stored
-    /// contract configuration, ensuring consistency with the storage mechanism
-    /// and maintaining the same storage key and behavior.
+    /// Configuration updates never apply immediately. The new configuration is
+    /// validated and stored as a [`PendingConfigUpdate`] that becomes effective
+    /// [`CONFIG_UPDATE_DELAY_SECONDS`] after it was queued. Until then it can be
+    /// inspected with [`ConfigManager::get_pending_config`] and withdrawn with
+    /// [`ConfigManager::cancel_pending_config`].
     ///
     /// # Parameters
     ///
     /// * `env` - The Soroban environment for storage operations
-    /// * `config` - The updated contract configuration to store
+    /// * `proposed_by` - The admin queueing the update
+    /// * `config` - The configuration to apply once the timelock expires
     ///
     /// # Returns
     ///
-    /// Returns `Ok(())` on successful update, or an `Error` if storage fails.
+    /// Returns the queued `PendingConfigUpdate` on success.
+    ///
+    /// # Errors
+    ///
+    /// - Validation errors from `ConfigValidator::validate_contract_config`
+    /// - `Error::InvalidState` - Another update is still waiting out its timelock
     ///
     /// # Example
     ///
     /// ```rust
-    /// # use soroban_sdk::Env;
-    /// # use predictify_hybrid::config::ConfigManager;
+    /// # use soroban_sdk::{Env, Address};
+    /// # use predictify_hybrid::config::{ConfigManager, CONFIG_UPDATE_DELAY_SECONDS};
     /// # let env = Env::default();
+    /// # let admin = Address::generate(&env);
     ///
-    /// // Get current configuration
-    /// let mut current_config = ConfigManager::get_development_config(&env);
-    /// ConfigManager::store_config(&env, &current_config).unwrap();
+    /// let mut config = ConfigManager::get_development_config(&env);
+    /// ConfigManager::store_config(&env, &config).unwrap();
     ///
-    /// // Modify fee settings
-    /// current_config.fees.platform_fee_percentage = 3; // Increase to 3%
-    /// current_config.fees.creation_fee = 15_000_000; // Increase to 1.5 XLM
-    ///
-    /// // Update stored configuration
-    /// let result = ConfigManager::update_config(&env, &current_config);
-    /// assert!(result.is_ok());
+    /// config.fees.platform_fee_percentage = 3;
+    /// let pending = ConfigManager::update_config(&env, &admin, &config).unwrap();
+    /// assert_eq!(
+    ///     pending.effective_at,
+    ///     env.ledger().timestamp() + CONFIG_UPDATE_DELAY_SECONDS
+    /// );
     ///
-    /// // Verify update
-    /// let updated_config = ConfigManager::get_config(&env).unwrap();
-    /// assert_eq!(updated_config.fees.platform_fee_percentage, 3);
+    /// // The current configuration is unchanged until the timelock expires
+    /// assert_eq!(ConfigManager::get_config(&env).unwrap().fees.platform_fee_percentage, 2);
     /// ```
     ///
     /// # Update Semantics
     ///
-    /// Configuration updates:
-    /// - Completely replace the existing configuration
-    /// - Are atomic operations ensuring consistency
-    /// - Take effect immediately for subsequent contract calls
-    /// - Should be validated before updating
-    ///
-    /// # Administrative Context
-    ///
-    /// Configuration updates are typically performed by:
-    /// - Contract administrators during governance actions
-    /// - Automated systems responding to market conditions
-    /// - Migration scripts during contract upgrades
-    /// - Emergency response procedures
-    pub fn update_config(env: &Env, config: &ContractConfig) -> Result<(), Error> {
-        Self::store_config(env, config)
+    /// - Markets keep the configuration snapshot taken at their creation
+    /// - A matured update is returned by `get_config` right away and written
+    ///   back to storage by the next `apply_pending_config` or `update_config`
+    /// - Only one update may be pending; cancel it before queueing another
+    pub fn update_config(
+        env: &Env,
+        proposed_by: &Address,
+        config: &ContractConfig,
+    ) -> Result<PendingConfigUpdate, Error> {
+        ConfigValidator::validate_contract_config(config)?;
+
+        // Flush a matured update first so it is not lost when overwritten
+        Self::apply_pending_config(env)?;
+        if Self::get_pending_config(env).is_some() {
+            return Err(Error::InvalidState);
+        }
+
+        let now = env.ledger().timestamp();
+        let pending = PendingConfigUpdate {
+            config: config.clone(),
+            proposed_by: proposed_by.clone(),
+            proposed_at: now,
+            effective_at: now + CONFIG_UPDATE_DELAY_SECONDS,
+        };
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, PENDING_CONFIG_STORAGE_KEY), &pending);
+
+        Ok(pending)
+    }
+
+    /// Returns the queued configuration update, if any.
+    ///
+    /// A returned update may already be in effect when its `effective_at`
+    /// has passed but it has not yet been written back to storage.
+    pub fn get_pending_config(env: &Env) -> Option<PendingConfigUpdate> {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, PENDING_CONFIG_STORAGE_KEY))
+    }
+
+    /// Cancels a queued configuration update before it takes effect.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::ConfigurationNotFound` - No update is queued
+    /// - `Error::InvalidState` - The update's timelock has already expired
+    pub fn cancel_pending_config(env: &Env) -> Result<PendingConfigUpdate, Error> {
+        let pending = Self::get_pending_config(env).ok_or(Error::ConfigurationNotFound)?;
+        if env.ledger().timestamp() >= pending.effective_at {
+            return Err(Error::InvalidState);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&Symbol::new(env, PENDING_CONFIG_STORAGE_KEY));
+        Ok(pending)
+    }
+
+    /// Writes a matured configuration update back to storage.
+    ///
+    /// Returns `Ok(true)` when an update was applied and `Ok(false)` when
+    /// nothing is queued or the queued update is still timelocked.
+    pub fn apply_pending_config(env: &Env) -> Result<bool, Error> {
+        let pending = match Self::get_pending_config(env) {
+            Some(pending) => pending,
+            None => return Ok(false),
+        };
+        if env.ledger().timestamp() < pending.effective_at {
+            return Ok(false);
+        }
+
+        Self::store_config(env, &pending.config)?;
+        env.storage()
+            .persistent()
+            .remove(&Symbol::new(env, PENDING_CONFIG_STORAGE_KEY));
+        Ok(true)
+    }
+
+    /// Records the configuration in effect for a newly created market.
+    ///
+    /// Markets are governed by the rules that applied when they were created,
+    /// so later configuration updates never change an existing market
+    /// retroactively. When no configuration is stored, the development
+    /// defaults the contract runs on are recorded.
+    pub fn snapshot_market_config(env: &Env, market_id: &Symbol) {
+        let key = (symbol_short!("mkt_cfg"), market_id.clone());
+        env.storage()
+            .persistent()
+            .set(&key, &Self::get_runtime_config(env));
     }
 
-    /// Resets the contract configuration to development defaults and stores it.
+    /// Returns the configuration that governs a market.
+    ///
+    /// This is the snapshot taken when the market was created. The current
+    /// configuration is never substituted, since it may include updates made
+    /// after the market was created.
     ///
-    /// This function provides a convenient way to reset the contract configuration
-    /// to safe development defaults, useful for testing, recovery scenarios,
-    /// or initial contract setup.
+    /// # Errors
+    ///
+    /// - `Error::ConfigurationNotFound` - No snapshot was recorded for the market
+    pub fn get_market_config(env: &Env, market_id: &Symbol) -> Result<ContractConfig, Error> {
+        let key = (symbol_short!("mkt_cfg"), market_id.clone());
+        env.storage()
+            .persistent()
+            .get::<_, ContractConfig>(&key)
+            .ok_or(Error::ConfigurationNotFound)
+    }
+
+    /// Returns the configuration that drives runtime behavior.
+    ///
+    /// Contracts initialized before a configuration was stored keep running
+    /// on the development defaults, which match the constants the contract
+    /// used before configuration drove behavior.
+    pub fn get_runtime_config(env: &Env) -> ContractConfig {
+        Self::get_config(env).unwrap_or_else(|_| Self::get_development_config(env))
+    }
+
+    /// Market-scoped counterpart of `get_runtime_config`, preferring the
+    /// configuration snapshot that governs the market.
+    ///
+    /// This is the effective configuration of the market: the snapshot taken
+    /// at creation with any per-market overrides applied. Markets created
+    /// before snapshots were recorded run on the current contract
+    /// configuration.
+    pub fn get_market_runtime_config(env: &Env, market_id: &Symbol) -> ContractConfig {
+        Self::get_market_config(env, market_id).unwrap_or_else(|_| Self::get_runtime_config(env))
+    }
+
+    /// Applies per-market overrides on top of the configuration governing a
+    /// market and records the result as the market's effective configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `ConfigValidator` error of the first invalid section; the
+    /// market's configuration is left unchanged in that case.
+    pub fn apply_market_overrides(
+        env: &Env,
+        market_id: &Symbol,
+        overrides: &MarketConfigOverrides,
+    ) -> Result<ContractConfig, Error> {
+        let mut config = Self::get_market_runtime_config(env, market_id);
+        ConfigValidator::validate_market_overrides(&config, overrides)?;
+        overrides.apply_to(&mut config);
+
+        let key = (symbol_short!("mkt_cfg"), market_id.clone());
+        env.storage().persistent().set(&key, &config);
+        Ok(config)
+    }
+
+    /// Replaces the resolution configuration of a market, e.g. to select how
+    /// its dispute votes are weighted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidInput` if the resolution configuration is invalid.
+    pub fn set_market_resolution_config(
+        env: &Env,
+        market_id: &Symbol,
+        resolution: ResolutionConfig,
+    ) -> Result<ContractConfig, Error> {
+        ConfigValidator::validate_resolution_config(&resolution)?;
+        let mut config = Self::get_market_runtime_config(env, market_id);
+        config.resolution = resolution;
+
+        let key = (symbol_short!("mkt_cfg"), market_id.clone());
+        env.storage().persistent().set(&key, &config);
+        Ok(config)
+    }
+
+    /// Returns the default configuration for a deployment environment.
+    ///
+    /// `Environment::Custom` deployments start from the development defaults.
+    pub fn get_default_config(env: &Env, environment: &Environment) -> ContractConfig {
+        match environment {
+            Environment::Development => Self::get_development_config(env),
+            Environment::Testnet => Self::get_testnet_config(env),
+            Environment::Mainnet => Self::get_mainnet_config(env),
+            Environment::Custom => Self::get_development_config(env),
+        }
+    }
+
+    /// Queues a reset of the contract configuration to the defaults of the
+    /// environment the contract was initialized for.
+    ///
+    /// A reset is a configuration update like any other: it goes through
+    /// [`ConfigManager::update_config`] and only takes effect once the
+    /// configuration timelock expires.
     ///
     /// # Parameters
     ///
     /// * `env` - The Soroban environment for configuration generation and storage
+    /// * `proposed_by` - The admin queueing the reset
     ///
     /// # Returns
     ///
-    /// Returns the newly stored development `ContractConfig` on success,
-    /// or an `Error` if storage fails.
+    /// Returns the queued `PendingConfigUpdate` on success.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidState` - Another update is still waiting out its timelock
     ///
     /// # Example
     ///
     /// ```rust
-    /// # use soroban_sdk::Env;
+    /// # use soroban_sdk::{Env, Address};
     /// # use predictify_hybrid::config::{ConfigManager, Environment};
     /// # let env = Env::default();
+    /// # let admin = Address::generate(&env);
     ///
-    /// // Store a custom configuration first
-    /// let mainnet_config = ConfigManager::get_mainnet_config(&env);
-    /// ConfigManager::store_config(&env, &mainnet_config).unwrap();
-    ///
-    /// // Reset to development defaults
-    /// let reset_config = ConfigManager::reset_to_defaults(&env).unwrap();
+    /// let mut config = ConfigManager::get_mainnet_config(&env);
+    /// config.fees.platform_fee_percentage = 5;
+    /// ConfigManager::store_config(&env, &config).unwrap();
     ///
-    /// // Verify reset to development environment
-    /// assert_eq!(reset_config.network.environment, Environment::Development);
-    /// assert_eq!(reset_config.fees.platform_fee_percentage, 2); // Default 2%
-    ///
-    /// // Confirm it's stored
-    /// let stored_config = ConfigManager::get_config(&env).unwrap();
-    /// assert_eq!(stored_config.network.environment, Environment::Development);
+    /// // Mainnet deployments reset to the mainnet defaults
+    /// let pending = ConfigManager::reset_to_defaults(&env, &admin).unwrap();
+    /// assert_eq!(pending.config.network.environment, Environment::Mainnet);
+    /// assert_eq!(pending.config.fees.platform_fee_percentage, 3);
     /// ```
-    ///
-    /// # Reset Behavior
-    ///
-    /// Configuration reset:
-    /// - Uses development configuration as the default baseline
-    /// - Overwrites any existing stored configuration
-    /// - Provides safe, conservative settings suitable for testing
-    /// - Returns the newly stored configuration for immediate use
-    ///
-    /// # Use Cases
-    ///
-    /// Configuration reset is useful for:
-    /// - **Testing**: Clean slate for test scenarios
-    /// - **Recovery**: Restore known-good configuration after issues
-    /// - **Development**: Quick setup for development environments
-    /// - **Debugging**: Eliminate configuration as a variable
-    /// - **Migration**: Safe fallback during configuration updates
-    ///
-    /// # Safety Considerations
-    ///
-    /// Development defaults provide:
-    /// - Conservative fee structures
-    /// - Accessible participation thresholds
-    /// - Reasonable timeout and retry settings
-    /// - Safe oracle and resolution parameters
-    pub fn reset_to_defaults(env: &Env) -> Result<ContractConfig, Error> {
-        let config = Self::get_development_config(env);
-        Self::store_config(env, &config)?;
-        Ok(config)
+    pub fn reset_to_defaults(
+        env: &Env,
+        proposed_by: &Address,
+    ) -> Result<PendingConfigUpdate, Error> {
+        let environment = Self::get_runtime_config(env).network.environment;
+        let config = Self::get_default_config(env, &environment);
+        Self::update_config(env, proposed_by, &config)
     }
 }
 
@@ -2217,6 +2634,19 @@ impl ConfigValidator {
         Ok(())
     }
 
+    /// Validate per-market overrides against the configuration they apply to
+    pub fn validate_market_overrides(
+        base: &ContractConfig,
+        overrides: &MarketConfigOverrides,
+    ) -> Result<(), Error> {
+        let mut config = base.clone();
+        overrides.apply_to(&mut config);
+        Self::validate_fee_config(&config.fees)?;
+        Self::validate_voting_config(&config.voting)?;
+        Self::validate_extension_config(&config.extension)?;
+        Ok(())
+    }
+
     /// Validate fee configuration
     pub fn validate_fee_config(config: &FeeConfig) -> Result<(), Error> {
         if config.platform_fee_percentage < MIN_PLATFORM_FEE_PERCENTAGE
@@ -2239,6 +2669,37 @@ impl ConfigValidator {
             return Err(Error::InvalidFeeConfig);
         }
 
+        if config.creator_fee_percentage < 0
+            || config.creator_fee_percentage > MAX_CREATOR_FEE_PERCENTAGE
+        {
+            return Err(Error::InvalidFeeConfig);
+        }
+
+        if config.referral_fee_percentage < 0
+            || config.referral_fee_percentage > MAX_REFERRAL_FEE_PERCENTAGE
+        {
+            return Err(Error::InvalidFeeConfig);
+        }
+
+        if config.invalid_market_fee_percentage < 0
+            || config.invalid_market_fee_percentage > MAX_INVALID_MARKET_FEE_PERCENTAGE
+        {
+            return Err(Error::InvalidFeeConfig);
+        }
+
+        if config.discount_volume_medium <= 0
+            || config.discount_volume_high < config.discount_volume_medium
+        {
+            return Err(Error::InvalidFeeConfig);
+        }
+
+        if config.discount_percentage_medium < 0
+            || config.discount_percentage_high < config.discount_percentage_medium
+            || config.discount_percentage_high > MAX_DISCOUNT_PERCENTAGE
+        {
+            return Err(Error::InvalidFeeConfig);
+        }
+
         Ok(())
     }
 
@@ -2268,6 +2729,10 @@ impl ConfigValidator {
             return Err(Error::InvalidInput);
         }
 
+        if config.dispute_reward_percentage < 0 || config.dispute_reward_percentage > 100 {
+            return Err(Error::InvalidInput);
+        }
+
         Ok(())
     }
 
@@ -2323,6 +2788,10 @@ impl ConfigValidator {
             return Err(Error::InvalidInput);
         }
 
+        if config.min_dispute_vote_stake < 0 {
+            return Err(Error::InvalidInput);
+        }
+
         Ok(())
     }
 
@@ -2469,6 +2938,13 @@ impl ConfigTesting {
                 max_fee_amount: 500_000_000,
                 collection_threshold: 50_000_000,
                 fees_enabled: true,
+                creator_fee_percentage: DEFAULT_CREATOR_FEE_PERCENTAGE,
+                referral_fee_percentage: DEFAULT_REFERRAL_FEE_PERCENTAGE,
+                invalid_market_fee_percentage: DEFAULT_INVALID_MARKET_FEE_PERCENTAGE,
+                discount_volume_medium: DEFAULT_DISCOUNT_VOLUME_MEDIUM,
+                discount_volume_high: DEFAULT_DISCOUNT_VOLUME_HIGH,
+                discount_percentage_medium: DEFAULT_DISCOUNT_PERCENTAGE_MEDIUM,
+                discount_percentage_high: DEFAULT_DISCOUNT_PERCENTAGE_HIGH,
             },
             voting: VotingConfig {
                 min_vote_stake: 500_000,
@@ -2478,6 +2954,7 @@ impl ConfigTesting {
                 large_market_threshold: 500_000_000,
                 high_activity_threshold: 50,
                 dispute_extension_hours: 12,
+                dispute_reward_percentage: DISPUTE_REWARD_PERCENTAGE,
             },
             market: MarketConfig {
                 max_duration_days: 30,
@@ -2499,6 +2976,8 @@ impl ConfigTesting {
                 oracle_weight_percentage: 60,
                 community_weight_percentage: 40,
                 min_votes_for_consensus: 3,
+                dispute_vote_weighting: DisputeVoteWeighting::Stake,
+                min_dispute_vote_stake: 5_000_000,
             },
             oracle: OracleConfig {
                 max_price_age: 1800,
@@ -2514,7 +2993,7 @@ impl ConfigTesting {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::testutils::{Address as _, Ledger};
 
     #[test]
     fn test_config_manager_default_configs() {
@@ -2598,12 +3077,149 @@ mod tests {
                 config.fees.platform_fee_percentage
             );
 
-            // Test reset to defaults
-            let reset_config = ConfigManager::reset_to_defaults(&env).unwrap();
+            // Resets are queued behind the timelock
+            let admin = Address::generate(&env);
+            let pending = ConfigManager::reset_to_defaults(&env, &admin).unwrap();
             assert_eq!(
-                reset_config.fees.platform_fee_percentage,
+                pending.config.fees.platform_fee_percentage,
                 DEFAULT_PLATFORM_FEE_PERCENTAGE
             );
+            assert_eq!(pending.effective_at, CONFIG_UPDATE_DELAY_SECONDS);
+        });
+    }
+
+    #[test]
+    fn test_config_update_timelock() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let config = ConfigManager::get_development_config(&env);
+
+        env.as_contract(&contract_id, || {
+            ConfigManager::store_config(&env, &config).unwrap();
+
+            let mut new_config = config.clone();
+            new_config.fees.platform_fee_percentage = 5;
+            let pending = ConfigManager::update_config(&env, &admin, &new_config).unwrap();
+            assert_eq!(pending.effective_at, CONFIG_UPDATE_DELAY_SECONDS);
+
+            // Still timelocked: the old config applies and a second update is rejected
+            assert_eq!(
+                ConfigManager::get_config(&env)
+                    .unwrap()
+                    .fees
+                    .platform_fee_percentage,
+                2
+            );
+            assert_eq!(
+                ConfigManager::update_config(&env, &admin, &new_config).unwrap_err(),
+                Error::InvalidState
+            );
+
+            // Cancelling before the effective time discards the update
+            ConfigManager::cancel_pending_config(&env).unwrap();
+            assert!(ConfigManager::get_pending_config(&env).is_none());
+            assert_eq!(
+                ConfigManager::cancel_pending_config(&env).unwrap_err(),
+                Error::ConfigurationNotFound
+            );
+
+            // Once the timelock expires the update applies and can no longer be cancelled
+            ConfigManager::update_config(&env, &admin, &new_config).unwrap();
+            env.ledger().set_timestamp(CONFIG_UPDATE_DELAY_SECONDS);
+            assert_eq!(
+                ConfigManager::get_config(&env)
+                    .unwrap()
+                    .fees
+                    .platform_fee_percentage,
+                5
+            );
+            assert_eq!(
+                ConfigManager::cancel_pending_config(&env).unwrap_err(),
+                Error::InvalidState
+            );
+
+            assert!(ConfigManager::apply_pending_config(&env).unwrap());
+            assert!(ConfigManager::get_pending_config(&env).is_none());
+            assert_eq!(
+                ConfigManager::get_config(&env)
+                    .unwrap()
+                    .fees
+                    .platform_fee_percentage,
+                5
+            );
+        });
+    }
+
+    #[test]
+    fn test_reset_to_defaults_keeps_environment() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let mut config = ConfigManager::get_mainnet_config(&env);
+        config.fees.platform_fee_percentage = 5;
+
+        env.as_contract(&contract_id, || {
+            ConfigManager::store_config(&env, &config).unwrap();
+
+            let pending = ConfigManager::reset_to_defaults(&env, &admin).unwrap();
+            assert_eq!(pending.config.network.environment, Environment::Mainnet);
+            assert_eq!(
+                pending.config.fees.platform_fee_percentage,
+                ConfigManager::get_mainnet_config(&env)
+                    .fees
+                    .platform_fee_percentage
+            );
+
+            // The current configuration is unchanged until the timelock expires
+            assert_eq!(
+                ConfigManager::get_config(&env)
+                    .unwrap()
+                    .fees
+                    .platform_fee_percentage,
+                5
+            );
+            assert_eq!(
+                ConfigManager::reset_to_defaults(&env, &admin).unwrap_err(),
+                Error::InvalidState
+            );
+        });
+    }
+
+    #[test]
+    fn test_market_config_snapshot() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let market_id = Symbol::new(&env, "market_1");
+        let config = ConfigManager::get_development_config(&env);
+
+        env.as_contract(&contract_id, || {
+            ConfigManager::store_config(&env, &config).unwrap();
+            ConfigManager::snapshot_market_config(&env, &market_id);
+
+            let mut new_config = config.clone();
+            new_config.fees.platform_fee_percentage = 5;
+            ConfigManager::update_config(&env, &admin, &new_config).unwrap();
+            env.ledger().set_timestamp(CONFIG_UPDATE_DELAY_SECONDS);
+            ConfigManager::apply_pending_config(&env).unwrap();
+
+            // The market keeps the rules that applied at its creation
+            let market_config = ConfigManager::get_market_config(&env, &market_id).unwrap();
+            assert_eq!(market_config.fees.platform_fee_percentage, 2);
+
+            // Markets without a snapshot run on the current configuration
+            let other_market = Symbol::new(&env, "market_2");
+            assert_eq!(
+                ConfigManager::get_market_config(&env, &other_market).unwrap_err(),
+                Error::ConfigurationNotFound
+            );
+            assert_eq!(
+                ConfigManager::get_market_runtime_config(&env, &other_market)
+                    .fees
+                    .platform_fee_percentage,
+                5
+            );
         });
     }
 
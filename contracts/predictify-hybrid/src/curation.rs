@@ -0,0 +1,239 @@
+//! Market curation queue for Predictify Hybrid
+//!
+//! Markets proposed by creators without the `CreateMarket` admin permission
+//! start in `MarketState::Pending` and wait in a review queue:
+//! - A market admin approves the proposal, opening it for voting
+//! - A market admin may correct the proposal's oracle configuration first
+//! - A market admin rejects the proposal, cancelling the market and
+//!   refunding the creation bond
+
+use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, Symbol, Vec};
+
+use crate::bonds::BondManager;
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::markets::{MarketStateLogic, MarketStateManager, MarketUtils, MarketValidator};
+use crate::types::{Market, MarketState, OracleConfig};
+
+/// Maximum number of proposals that may wait for review at once
+pub const MAX_PENDING_PROPOSALS: u32 = 100;
+
+// ===== CURATION TYPES =====
+
+/// A market awaiting review
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketProposal {
+    /// Proposed market
+    pub market_id: Symbol,
+    /// Creator who proposed the market
+    pub proposer: Address,
+    /// Proposal timestamp
+    pub proposed_at: u64,
+}
+
+/// Outcome of a review step
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalDecision {
+    /// Market was approved and opened for voting
+    Approved,
+    /// Market's oracle configuration was edited
+    OracleEdited,
+    /// Market was rejected and cancelled
+    Rejected,
+}
+
+// ===== CURATION MANAGER =====
+
+/// Manages the review queue for proposed markets
+pub struct CurationManager;
+
+impl CurationManager {
+    /// Add a pending market to the review queue.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidState` - `MAX_PENDING_PROPOSALS` markets already wait for review
+    pub fn propose(env: &Env, market_id: &Symbol, proposer: &Address) -> Result<(), Error> {
+        let mut queue = Self::get_pending_markets(env);
+        if queue.len() >= MAX_PENDING_PROPOSALS {
+            return Err(Error::InvalidState);
+        }
+
+        let proposal = MarketProposal {
+            market_id: market_id.clone(),
+            proposer: proposer.clone(),
+            proposed_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("proposal"), market_id.clone()), &proposal);
+
+        queue.push_back(market_id.clone());
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("cur_queue"), &queue);
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("cur_pend"), market_id.clone()), &true);
+
+        EventEmitter::emit_market_proposed(env, market_id, proposer);
+        Ok(())
+    }
+
+    /// Approve a pending market and open it for voting.
+    ///
+    /// The market's end time is pushed back by the time spent in review, so
+    /// approved markets keep their full voting period. A market scheduled to
+    /// open later stays `Pending` until its start time.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Reviewer lacks the `CreateMarket` permission
+    /// - `Error::InvalidState` - Market is not pending review
+    pub fn approve(env: &Env, reviewer: &Address, market_id: &Symbol) -> Result<(), Error> {
+        Self::validate_reviewer(env, reviewer)?;
+        let (mut market, proposal) = Self::get_pending_market(env, market_id)?;
+
+        let current_time = env.ledger().timestamp();
+        if market.has_started(current_time) {
+            let opened_at = market.start_time.unwrap_or(0).max(proposal.proposed_at);
+            market.end_time += current_time - opened_at;
+            Self::transition(env, market_id, &mut market, MarketState::Active)?;
+        }
+        Self::remove_from_queue(env, market_id);
+
+        EventEmitter::emit_market_reviewed(env, market_id, reviewer, ProposalDecision::Approved);
+        Ok(())
+    }
+
+    /// Replace the oracle configuration of a pending market.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Reviewer lacks the `CreateMarket` permission
+    /// - `Error::InvalidState` - Market is not pending review
+    /// - Oracle validation errors for an invalid `oracle_config`
+    pub fn edit_oracle_config(
+        env: &Env,
+        reviewer: &Address,
+        market_id: &Symbol,
+        oracle_config: OracleConfig,
+    ) -> Result<(), Error> {
+        Self::validate_reviewer(env, reviewer)?;
+        let (mut market, _) = Self::get_pending_market(env, market_id)?;
+        MarketValidator::validate_oracle_config(env, &oracle_config)?;
+
+        market.oracle_config = oracle_config;
+        MarketStateManager::update_market(env, market_id, &market);
+
+        EventEmitter::emit_market_reviewed(
+            env,
+            market_id,
+            reviewer,
+            ProposalDecision::OracleEdited,
+        );
+        Ok(())
+    }
+
+    /// Reject a pending market, cancelling it and refunding its creation bond.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Reviewer lacks the `CreateMarket` permission
+    /// - `Error::InvalidState` - Market is not pending review
+    pub fn reject(env: &Env, reviewer: &Address, market_id: &Symbol) -> Result<(), Error> {
+        Self::validate_reviewer(env, reviewer)?;
+        let (mut market, _) = Self::get_pending_market(env, market_id)?;
+
+        Self::transition(env, market_id, &mut market, MarketState::Cancelled)?;
+        Self::remove_from_queue(env, market_id);
+        BondManager::release_bond(env, market_id)?;
+
+        EventEmitter::emit_market_reviewed(env, market_id, reviewer, ProposalDecision::Rejected);
+        Ok(())
+    }
+
+    /// Get the proposal record for a market, if it was proposed for review.
+    pub fn get_proposal(env: &Env, market_id: &Symbol) -> Option<MarketProposal> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("proposal"), market_id.clone()))
+    }
+
+    /// Get the markets waiting for review, oldest first.
+    ///
+    /// The queue holds at most `MAX_PENDING_PROPOSALS` markets.
+    pub fn get_pending_markets(env: &Env) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("cur_queue"))
+            .unwrap_or(vec![env])
+    }
+
+    /// Check whether a market is still waiting in the review queue.
+    pub fn is_awaiting_review(env: &Env, market_id: &Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&(symbol_short!("cur_pend"), market_id.clone()))
+    }
+
+    /// Check whether a proposed market has left the review queue, approved
+    /// or rejected.
+    pub fn is_reviewed(env: &Env, market_id: &Symbol) -> bool {
+        Self::get_proposal(env, market_id).is_some() && !Self::is_awaiting_review(env, market_id)
+    }
+
+    fn validate_reviewer(env: &Env, reviewer: &Address) -> Result<(), Error> {
+        reviewer.require_auth();
+
+        // Reviewers are the admins allowed to create markets directly
+        if !MarketUtils::can_create_without_bond(env, reviewer) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn get_pending_market(
+        env: &Env,
+        market_id: &Symbol,
+    ) -> Result<(Market, MarketProposal), Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.state != MarketState::Pending {
+            return Err(Error::InvalidState);
+        }
+        let proposal = Self::get_proposal(env, market_id).ok_or(Error::InvalidState)?;
+        Ok((market, proposal))
+    }
+
+    fn transition(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        new_state: MarketState,
+    ) -> Result<(), Error> {
+        let old_state = market.state;
+        MarketStateLogic::validate_state_transition(old_state, new_state)?;
+        market.state = new_state;
+        MarketStateManager::update_market(env, market_id, market);
+        MarketStateLogic::emit_state_change_event(env, market_id, old_state, new_state);
+        Ok(())
+    }
+
+    fn remove_from_queue(env: &Env, market_id: &Symbol) {
+        let queue = Self::get_pending_markets(env);
+        let mut remaining = Vec::new(env);
+        for id in queue.iter() {
+            if &id != market_id {
+                remaining.push_back(id);
+            }
+        }
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("cur_queue"), &remaining);
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("cur_pend"), market_id.clone()));
+    }
+}
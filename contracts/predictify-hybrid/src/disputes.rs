@@ -1,10 +1,22 @@
 #![allow(dead_code)]
 
 use crate::{
+    config::{
+        ConfigManager, DisputeVoteWeighting, ResolutionConfig, VotingConfig,
+        MAX_DISPUTE_ESCALATION_LEVEL,
+    },
     errors::Error,
+    events::{EventEmitter, EventLogger, MarketActivityKind},
+    fees::FeeManager,
     markets::MarketStateManager,
-    types::Market,
-    voting::{VotingUtils, DISPUTE_EXTENSION_HOURS, MIN_DISPUTE_STAKE},
+    metadata::MarketMetadataManager,
+    referrals::ReferralManager,
+    reputation::ReputationManager,
+    resolution::{InvalidationReason, MarketResolutionManager},
+    treasury::FeeTreasury,
+    types::{Market, MarketState, ResolutionCriteria},
+    utils::NumericUtils,
+    voting::VotingUtils,
 };
 use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol, Vec};
 
@@ -197,11 +209,15 @@ pub struct DisputeStats {
 /// * `community_weight` - Influence of community votes in final decision (scaled integer)
 /// * `dispute_impact` - How much disputes affected the final outcome (scaled integer)
 /// * `resolution_timestamp` - When the final resolution was determined
+/// * `vote_weighting` - Strategy the market's dispute votes were weighted with
+/// * `support_weight` - Weighted votes for upholding the disputes
+/// * `against_weight` - Weighted votes for rejecting the disputes
 ///
 /// # Example
 ///
 /// ```rust
 /// # use soroban_sdk::{Env, Symbol, String};
+/// # use predictify_hybrid::config::DisputeVoteWeighting;
 /// # use predictify_hybrid::disputes::DisputeResolution;
 /// # let env = Env::default();
 ///
@@ -212,6 +228,9 @@ pub struct DisputeStats {
 ///     community_weight: 40, // 40% community influence
 ///     dispute_impact: 25, // 25% change from original oracle result
 ///     resolution_timestamp: env.ledger().timestamp(),
+///     vote_weighting: DisputeVoteWeighting::QuadraticStake,
+///     support_weight: 7_071, // sqrt of a 5 XLM stake
+///     against_weight: 3_162, // sqrt of a 1 XLM stake
 /// };
 ///
 /// // Verify hybrid resolution weights sum to 100%
@@ -251,6 +270,9 @@ pub struct DisputeResolution {
     pub community_weight: i128,
     pub dispute_impact: i128,
     pub resolution_timestamp: u64,
+    pub vote_weighting: DisputeVoteWeighting,
+    pub support_weight: i128,
+    pub against_weight: i128,
 }
 
 /// Represents an individual vote cast on a dispute by a community member.
@@ -460,11 +482,12 @@ pub enum DisputeVotingStatus {
     Cancelled,
 }
 
-/// Data structure for disputes that have been escalated to higher authority.
+/// A round of dispute escalation.
 ///
-/// When standard community voting cannot resolve a dispute (due to ties,
-/// insufficient participation, or complexity), the dispute can be escalated
-/// to admin review or specialized resolution mechanisms.
+/// When a participant disagrees with the result of a dispute voting round,
+/// they can escalate the dispute by posting a bond that doubles the previous
+/// round's bond. Each escalation opens a new voting window; the final round
+/// (`MAX_DISPUTE_ESCALATION_LEVEL`) is decided by the admin arbiter instead.
 ///
 /// # Fields
 ///
@@ -472,8 +495,11 @@ pub enum DisputeVotingStatus {
 /// * `escalated_by` - Address of the user who requested escalation
 /// * `escalation_reason` - Explanation for why escalation was necessary
 /// * `escalation_timestamp` - When the escalation was requested
-/// * `escalation_level` - Tier of escalation (1=admin, 2=governance, etc.)
-/// * `requires_admin_review` - Whether admin intervention is needed
+/// * `escalation_level` - Escalation round, starting at 1
+/// * `requires_admin_review` - Whether this is the final, arbiter-decided round
+/// * `bond` - Bond posted by the escalator for this round
+/// * `supports_dispute` - Side the escalator argues for (true = overturn the oracle)
+/// * `round_end` - When the round's voting window or arbiter deadline ends
 ///
 /// # Example
 ///
@@ -489,12 +515,15 @@ pub enum DisputeVotingStatus {
 ///     escalation_reason: String::from_str(&env,
 ///         "Voting resulted in exact tie, need admin decision"),
 ///     escalation_timestamp: env.ledger().timestamp(),
-///     escalation_level: 1, // Admin review
-///     requires_admin_review: true,
+///     escalation_level: 1, // First appeal round
+///     requires_admin_review: false,
+///     bond: 20_000_000, // Twice the 1 XLM dispute stake
+///     supports_dispute: true,
+///     round_end: env.ledger().timestamp() + 86400,
 /// };
 ///
-/// // Escalation requires admin intervention
-/// assert!(escalation.requires_admin_review);
+/// // Early rounds go back to community voting
+/// assert!(!escalation.requires_admin_review);
 /// assert_eq!(escalation.escalation_level, 1);
 /// ```
 ///
@@ -507,20 +536,18 @@ pub enum DisputeVotingStatus {
 /// - **Complex Cases**: Subjective outcomes requiring expert judgment
 /// - **Appeal Requests**: Losing party contests the result
 ///
-/// # Escalation Levels
+/// # Escalation Rounds
 ///
-/// 1. **Level 1**: Admin review and decision
-/// 2. **Level 2**: Governance token holder voting
-/// 3. **Level 3**: External arbitration or expert panel
-/// 4. **Level 4**: Legal or regulatory intervention
-///
-/// # Resolution Authority
-///
-/// - **Admin Review**: Fast resolution for clear-cut cases
-/// - **Governance Voting**: Democratic resolution for policy matters
-/// - **Expert Panel**: Specialized knowledge for technical disputes
-/// - **Legal Process**: Final resort for high-stakes disagreements
+/// - **Bonds**: Each round's bond is double the previous one, starting from
+///   the market's minimum dispute stake
+/// - **Voting**: Each non-final round opens a new community voting window
+/// - **Arbitration**: The final round closes voting and awaits the admin,
+///   which may itself be a multisig account
+/// - **Timeouts**: Every round gets a `DisputeTimeout` ending with the round
+/// - **Settlement**: Escalators on the winning side get their bonds back;
+///   losing escalation bonds are slashed into the fee treasury
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DisputeEscalation {
     pub dispute_id: Symbol,
     pub escalated_by: Address,
@@ -528,6 +555,9 @@ pub struct DisputeEscalation {
     pub escalation_timestamp: u64,
     pub escalation_level: u32,
     pub requires_admin_review: bool,
+    pub bond: i128,
+    pub supports_dispute: bool,
+    pub round_end: u64,
 }
 
 /// Records the distribution of fees and stakes after dispute resolution.
@@ -603,6 +633,7 @@ pub struct DisputeEscalation {
 /// - **Quality Control**: Penalties for incorrect dispute judgments
 /// - **Platform Sustainability**: Fees support ongoing operations
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DisputeFeeDistribution {
     pub dispute_id: Symbol,
     pub total_fees: i128,
@@ -613,8 +644,41 @@ pub struct DisputeFeeDistribution {
     pub fees_distributed: bool,
 }
 
+/// Final settlement of a market's dispute bonds and dispute votes.
+///
+/// Created once when a disputed market is resolved or cancelled. Winning
+/// disputers and dispute voters claim their payouts from it with
+/// `DisputeManager::claim_dispute_payout`; losing bonds are slashed into the
+/// fee treasury when it is created.
+///
+/// # Fields
+///
+/// * `market_id` - Market whose disputes were settled
+/// * `upheld` - Whether the disputes overturned the oracle result
+/// * `disputer_pool` - Total bonds posted by disputers
+/// * `disputer_reward` - Reward shared by disputers pro-rata to their bond
+/// * `winning_vote_stake` - Total stake of dispute voters on the winning side
+/// * `voter_reward` - Reward shared by winning dispute voters pro-rata to stake
+/// * `protocol_reward` - Part of `disputer_reward` funded from the market's platform fee
+/// * `slashed` - Amount slashed into the fee treasury
+/// * `settled_at` - Settlement timestamp
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeSettlement {
+    pub market_id: Symbol,
+    pub upheld: bool,
+    pub disputer_pool: i128,
+    pub disputer_reward: i128,
+    pub winning_vote_stake: i128,
+    pub voter_reward: i128,
+    pub protocol_reward: i128,
+    pub slashed: i128,
+    pub settled_at: u64,
+}
+
 /// Represents dispute timeout configuration
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DisputeTimeout {
     pub dispute_id: Symbol,
     pub market_id: Symbol,
@@ -628,7 +692,7 @@ pub struct DisputeTimeout {
 
 /// Represents dispute timeout status
 #[contracttype]
-#[derive(PartialEq, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DisputeTimeoutStatus {
     Active,
     Expired,
@@ -813,9 +877,10 @@ impl DisputeManager {
         // Get and validate market
         let mut market = MarketStateManager::get_market(env, &market_id)?;
         DisputeValidator::validate_market_for_dispute(env, &market)?;
+        let voting_config = ConfigManager::get_market_runtime_config(env, &market_id).voting;
 
         // Validate dispute parameters
-        DisputeValidator::validate_dispute_parameters(env, &user, &market, stake)?;
+        DisputeValidator::validate_dispute_parameters(env, &user, &market, stake, &voting_config)?;
 
         // Process stake transfer
         VotingUtils::transfer_stake(env, &user, stake)?;
@@ -826,19 +891,49 @@ impl DisputeManager {
             market_id: market_id.clone(),
             stake,
             timestamp: env.ledger().timestamp(),
-            reason,
+            reason: reason.clone(),
             status: DisputeStatus::Active,
         };
 
         // Add dispute to market
         DisputeUtils::add_dispute_to_market(&mut market, dispute)?;
+        MarketStateManager::end_market_if_expired(env, &market_id, &mut market);
+        if market.state == MarketState::Ended {
+            MarketStateManager::transition_state(
+                env,
+                &market_id,
+                &mut market,
+                MarketState::Disputed,
+            )?;
+        }
 
         // Extend market for dispute period
-        DisputeUtils::extend_market_for_dispute(&mut market, env)?;
+        DisputeUtils::extend_market_for_dispute(&mut market, env, &voting_config)?;
+
+        // Open community voting on the market's disputes
+        if DisputeUtils::get_dispute_voting(env, &market_id).is_err() {
+            DisputeUtils::open_dispute_voting(env, &market_id, &voting_config)?;
+        }
 
         // Update market in storage
         MarketStateManager::update_market(env, &market_id, &market);
 
+        EventEmitter::emit_dispute_created(
+            env,
+            &market_id,
+            &user,
+            stake,
+            reason,
+            DisputeValidator::get_resolution_criteria(env, &market_id),
+        );
+        EventLogger::record_market_activity(
+            env,
+            &market_id,
+            MarketActivityKind::Dispute,
+            &user,
+            &String::from_str(env, ""),
+            stake,
+        );
         Ok(())
     }
 
@@ -946,6 +1041,12 @@ impl DisputeManager {
         let oracle_weight = DisputeAnalytics::calculate_oracle_weight(&market);
         let community_weight = DisputeAnalytics::calculate_community_weight(&market);
 
+        // Tally dispute votes with the market's weighting strategy
+        let resolution_config =
+            ConfigManager::get_market_runtime_config(env, &market_id).resolution;
+        let (support_weight, against_weight) =
+            DisputeUtils::calculate_weighted_tally(env, &market_id, &resolution_config);
+
         // Create resolution record
         let resolution = DisputeResolution {
             market_id: market_id.clone(),
@@ -954,15 +1055,257 @@ impl DisputeManager {
             community_weight,
             dispute_impact,
             resolution_timestamp: env.ledger().timestamp(),
+            vote_weighting: resolution_config.dispute_vote_weighting,
+            support_weight,
+            against_weight,
         };
 
         // Update market with final outcome
         DisputeUtils::finalize_market_with_resolution(&mut market, final_outcome)?;
+        MarketStateManager::end_market_if_expired(env, &market_id, &mut market);
+        MarketStateManager::transition_state(env, &market_id, &mut market, MarketState::Resolved)?;
         MarketStateManager::update_market(env, &market_id, &market);
 
+        // Also settles dispute bonds and votes: disputers win if the oracle
+        // result was overturned
+        MarketResolutionManager::settle_resolved_market(env, &market_id)?;
+
         Ok(resolution)
     }
 
+    /// Cancels a disputed market as invalid or ambiguous.
+    ///
+    /// Used when a dispute shows the market question cannot be resolved
+    /// fairly. The market is voided with an invalid verdict: it moves to
+    /// `MarketState::Cancelled`, every voter can claim back their stake and
+    /// any creation bond posted by its creator is slashed into the fee
+    /// treasury. The disputes are settled as upheld.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::MarketAlreadyResolved` - Market already has a winning outcome
+    /// - `Error::InvalidInput` - Market has no disputes
+    /// - `Error::InvalidState` - Market cannot move to `Cancelled`
+    pub fn cancel_disputed_market(
+        env: &Env,
+        market_id: Symbol,
+        admin: Address,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        DisputeValidator::validate_admin_permissions(env, &admin)?;
+
+        let market = MarketStateManager::get_market(env, &market_id)?;
+        DisputeValidator::validate_market_for_resolution(env, &market)?;
+
+        MarketResolutionManager::void_market(env, &market_id, InvalidationReason::Dispute)?;
+
+        // The disputers showed the market to be invalid, so their side wins
+        Self::settle_disputes(env, &market, &market_id, true)?;
+        Ok(())
+    }
+
+    /// Settles a market's dispute bonds and dispute votes.
+    ///
+    /// If the disputes are upheld, disputers are owed their bond back plus
+    /// `VotingConfig::dispute_reward_percentage` of it. The reward is taken
+    /// from the stakes of dispute voters on the losing side first and any
+    /// shortfall from the market's platform fee. If the disputes fail, the
+    /// disputer bonds are slashed into the fee treasury. Dispute voters on the
+    /// winning side share what remains of the losing side's stakes pro-rata;
+    /// if nobody voted on the winning side, those stakes are slashed too.
+    /// Escalation bonds are returned to escalators on the winning side and
+    /// slashed otherwise.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::DisputeFeeDistributionFailed` - The disputes were already settled
+    pub fn settle_disputes(
+        env: &Env,
+        market: &Market,
+        market_id: &Symbol,
+        upheld: bool,
+    ) -> Result<DisputeSettlement, Error> {
+        if DisputeUtils::get_dispute_settlement(env, market_id).is_some() {
+            return Err(Error::DisputeFeeDistributionFailed);
+        }
+
+        let disputer_pool = market.total_dispute_stakes();
+        let (support_stake, against_stake) = match DisputeUtils::get_dispute_voting(env, market_id)
+        {
+            Ok(voting) => (voting.total_support_stake, voting.total_against_stake),
+            Err(_) => (0, 0),
+        };
+
+        let mut settlement = DisputeSettlement {
+            market_id: market_id.clone(),
+            upheld,
+            disputer_pool,
+            disputer_reward: 0,
+            winning_vote_stake: 0,
+            voter_reward: 0,
+            protocol_reward: 0,
+            slashed: 0,
+            settled_at: env.ledger().timestamp(),
+        };
+
+        if upheld {
+            let voting_config = ConfigManager::get_market_runtime_config(env, market_id).voting;
+            let target = (disputer_pool * voting_config.dispute_reward_percentage) / 100;
+            let from_voters = target.min(against_stake);
+            let protocol_reward =
+                (target - from_voters).min(DisputeUtils::get_available_market_fee(env, market_id));
+
+            settlement.disputer_reward = from_voters + protocol_reward;
+            settlement.protocol_reward = protocol_reward;
+            settlement.winning_vote_stake = support_stake;
+            settlement.voter_reward = against_stake - from_voters;
+        } else {
+            settlement.slashed = disputer_pool;
+            settlement.winning_vote_stake = against_stake;
+            settlement.voter_reward = support_stake;
+        }
+
+        if settlement.winning_vote_stake == 0 {
+            settlement.slashed += settlement.voter_reward;
+            settlement.voter_reward = 0;
+        }
+
+        // Escalation bonds on the losing side are slashed
+        for round in DisputeUtils::get_dispute_rounds(env, market_id).iter() {
+            if round.supports_dispute != upheld {
+                settlement.slashed += round.bond;
+            }
+        }
+
+        if settlement.slashed > 0 {
+            FeeTreasury::accrue(env, market_id, settlement.slashed)?;
+        }
+
+        // Score dispute voters on whether they backed the settled side
+        for voter in DisputeUtils::get_dispute_voters(env, market_id).iter() {
+            if let Some(vote) = DisputeUtils::get_dispute_vote(env, market_id, &voter) {
+                ReputationManager::record_prediction(env, &voter, vote.vote == upheld);
+            }
+        }
+
+        // Close community voting and record who won it
+        if let Ok(mut voting) = DisputeUtils::get_dispute_voting(env, market_id) {
+            voting.status = DisputeVotingStatus::Completed;
+            DisputeUtils::store_dispute_voting(env, market_id, &voting)?;
+            let distribution =
+                DisputeUtils::distribute_fees_based_on_outcome(env, market_id, &voting, upheld)?;
+            DisputeUtils::emit_fee_distribution_event(env, market_id, &distribution);
+        }
+
+        DisputeUtils::store_dispute_settlement(env, &settlement);
+        Ok(settlement)
+    }
+
+    /// Pays out a participant's share of a settled dispute.
+    ///
+    /// Winning disputers receive their bond plus their share of the disputer
+    /// reward; winning dispute voters receive their stake plus their share of
+    /// the voter reward. Each address can claim once per market.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::DisputeResolutionConditionsNotMet` - The market's disputes are not settled
+    /// - `Error::AlreadyClaimed` - The user already claimed their payout
+    /// - `Error::NothingToClaim` - The user has no winning bond or vote
+    pub fn claim_dispute_payout(
+        env: &Env,
+        user: Address,
+        market_id: Symbol,
+    ) -> Result<i128, Error> {
+        user.require_auth();
+
+        let payout = Self::calculate_dispute_payout(env, &user, &market_id)?;
+        let claim_key = (symbol_short!("dsp_paid"), market_id.clone(), user.clone());
+        if env.storage().persistent().has(&claim_key) {
+            return Err(Error::AlreadyClaimed);
+        }
+        if payout <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+
+        env.storage().persistent().set(&claim_key, &payout);
+        VotingUtils::transfer_winnings(env, &user, payout)?;
+
+        EventEmitter::emit_dispute_payout_claimed(env, &market_id, &user, payout);
+        Ok(payout)
+    }
+
+    /// Calculates what a participant can claim from a settled dispute.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::DisputeResolutionConditionsNotMet` - The market's disputes are not settled
+    pub fn calculate_dispute_payout(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+    ) -> Result<i128, Error> {
+        let settlement = DisputeUtils::get_dispute_settlement(env, market_id)
+            .ok_or(Error::DisputeResolutionConditionsNotMet)?;
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let mut payout = 0;
+
+        let bond = DisputeUtils::get_user_dispute_stake(&market, user);
+        if settlement.upheld && bond > 0 {
+            payout += bond + (bond * settlement.disputer_reward) / settlement.disputer_pool;
+        }
+
+        if let Some(vote) = DisputeUtils::get_dispute_vote(env, market_id, user) {
+            if vote.vote == settlement.upheld && settlement.winning_vote_stake > 0 {
+                payout += vote.stake
+                    + (vote.stake * settlement.voter_reward) / settlement.winning_vote_stake;
+            }
+        }
+
+        // Escalation bonds on the winning side are returned
+        for round in DisputeUtils::get_dispute_rounds(env, market_id).iter() {
+            if round.escalated_by == *user && round.supports_dispute == settlement.upheld {
+                payout += round.bond;
+            }
+        }
+
+        Ok(payout)
+    }
+
+    /// Get the settlement of a market's disputes, if they were settled
+    pub fn get_dispute_settlement(env: &Env, market_id: &Symbol) -> Option<DisputeSettlement> {
+        DisputeUtils::get_dispute_settlement(env, market_id)
+    }
+
+    /// Sets how a market's disputes are resolved, including the strategy its
+    /// dispute votes are weighted with (admin only).
+    ///
+    /// The configuration is locked once the market has been disputed.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - The caller is not the admin
+    /// - `Error::InvalidState` - The market has already been disputed
+    /// - `Error::InvalidInput` - The resolution configuration is invalid
+    pub fn set_market_resolution_config(
+        env: &Env,
+        admin: Address,
+        market_id: Symbol,
+        resolution: ResolutionConfig,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        DisputeValidator::validate_admin_permissions(env, &admin)?;
+
+        let market = MarketStateManager::get_market(env, &market_id)?;
+        if market.total_dispute_stakes() > 0 {
+            return Err(Error::InvalidState);
+        }
+
+        ConfigManager::set_market_resolution_config(env, &market_id, resolution)?;
+        Ok(())
+    }
+
     /// Retrieves comprehensive dispute statistics for a specific market.
     ///
     /// This function calculates and returns detailed statistics about dispute
@@ -1414,16 +1757,16 @@ impl DisputeManager {
     ///
     /// The outcome determination process:
     /// 1. **Vote Aggregation**: Collect all votes with stakes
-    /// 2. **Stake Weighting**: Apply economic weight to each vote
-    /// 3. **Threshold Analysis**: Check minimum participation requirements
-    /// 4. **Outcome Decision**: Determine result based on weighted consensus
+    /// 2. **Weighting**: Weight each vote with the market's `DisputeVoteWeighting`
+    /// 3. **Outcome Decision**: Uphold the dispute if the supporting weight is larger
     ///
-    /// # Weighting Logic
+    /// # Weighting Strategies
     ///
-    /// - **Stake-Weighted Voting**: Larger stakes have more influence
-    /// - **Participation Threshold**: Minimum votes required for validity
-    /// - **Economic Consensus**: Stakes must exceed minimum threshold
-    /// - **Tie Breaking**: Admin intervention required for exact ties
+    /// - **Stake**: Larger stakes have proportionally more influence
+    /// - **Quadratic Stake**: Influence grows with the square root of the stake
+    /// - **Reputation**: Each vote with at least the minimum stake counts the voter's accuracy score
+    /// - **One Address One Vote**: Each address with at least the minimum stake counts once
+    /// - **Tie Breaking**: Ties reject the dispute
     ///
     /// # Use Cases
     ///
@@ -1438,10 +1781,13 @@ impl DisputeManager {
         // Validate voting is completed
         DisputeValidator::validate_voting_completed(&voting_data)?;
 
-        // Calculate outcome based on stake-weighted voting
-        let outcome = DisputeUtils::calculate_stake_weighted_outcome(&voting_data);
+        // Calculate outcome with the market's weighting strategy
+        let resolution_config =
+            ConfigManager::get_market_runtime_config(env, &dispute_id).resolution;
+        let (support_weight, against_weight) =
+            DisputeUtils::calculate_weighted_tally(env, &dispute_id, &resolution_config);
 
-        Ok(outcome)
+        Ok(support_weight > against_weight)
     }
 
     /// Distributes stakes and fees to the winning side of a resolved dispute.
@@ -1597,29 +1943,29 @@ impl DisputeManager {
     /// - **Complex Cases**: Subjective outcomes requiring expert judgment
     /// - **Appeal Process**: Losing party contests the result
     ///
-    /// # Escalation Levels
+    /// # Escalation Rounds
     ///
-    /// 1. **Level 1**: Admin review and decision
-    /// 2. **Level 2**: Governance token holder voting
-    /// 3. **Level 3**: External arbitration panel
-    /// 4. **Level 4**: Legal or regulatory intervention
+    /// 1. **Rounds 1..N-1**: New community voting window, bond doubles each round
+    /// 2. **Round N** (`MAX_DISPUTE_ESCALATION_LEVEL`): Voting closes and the
+    ///    admin arbiter decides through `resolve_dispute`
     ///
     /// # Process Flow
     ///
     /// 1. **Authentication**: Verify escalation requester
-    /// 2. **Validation**: Check escalation eligibility
-    /// 3. **Record Creation**: Store escalation with reasoning
-    /// 4. **Admin Notification**: Alert administrators of escalation
-    /// 5. **Status Update**: Mark dispute as escalated
+    /// 2. **Validation**: Check the current round has closed and the user took
+    ///    part on the side that lost it
+    /// 3. **Bond**: Transfer twice the previous round's bond from the escalator
+    ///    (twice the largest dispute stake for the first round)
+    /// 4. **Round Opening**: Reopen voting, or close it for the final round
+    /// 5. **Timeout**: Start a `DisputeTimeout` ending with the round
     /// 6. **Event Emission**: Broadcast escalation event
     ///
-    /// # Resolution Authority
+    /// # Errors
     ///
-    /// Escalated disputes require:
-    /// - **Admin Review**: Manual evaluation by authorized administrators
-    /// - **Expert Judgment**: Specialized knowledge for complex cases
-    /// - **Governance Process**: Community governance for policy matters
-    /// - **External Arbitration**: Independent third-party resolution
+    /// - `Error::DisputeEscalationNotAllowed` - The user neither disputed nor
+    ///   voted, the user's side is winning the current round, the current
+    ///   round is still open, the dispute is settled, or the final round was
+    ///   already reached
     pub fn escalate_dispute(
         env: &Env,
         user: Address,
@@ -1629,17 +1975,56 @@ impl DisputeManager {
         // Require authentication from the user
         user.require_auth();
 
-        // Validate escalation conditions
-        DisputeValidator::validate_dispute_escalation_conditions(env, &user, &dispute_id)?;
+        // Validate escalation conditions and find the side being argued
+        let supports_dispute =
+            DisputeValidator::validate_dispute_escalation_conditions(env, &user, &dispute_id)?;
+
+        // Each round's bond doubles the one posted for the round before it;
+        // the first round doubles the largest stake behind the dispute
+        let voting_config = ConfigManager::get_market_runtime_config(env, &dispute_id).voting;
+        let (escalation_level, bond) = match DisputeUtils::get_dispute_escalation(env, &dispute_id)
+        {
+            Some(previous) => (previous.escalation_level + 1, previous.bond * 2),
+            None => {
+                let market = MarketStateManager::get_market(env, &dispute_id)?;
+                let largest_stake = market
+                    .dispute_stakes
+                    .iter()
+                    .map(|(_, stake)| stake)
+                    .max()
+                    .unwrap_or(0)
+                    .max(voting_config.min_dispute_stake);
+                (1, largest_stake * 2)
+            }
+        };
+
+        VotingUtils::transfer_stake(env, &user, bond)?;
+
+        // Reopen voting for another round, or hand the final round to the arbiter
+        let now = env.ledger().timestamp();
+        let round_end = now + (voting_config.dispute_extension_hours as u64) * 3600;
+        let requires_admin_review = escalation_level >= MAX_DISPUTE_ESCALATION_LEVEL;
+        let mut voting = DisputeUtils::get_dispute_voting(env, &dispute_id)?;
+        voting.voting_start = now;
+        voting.voting_end = if requires_admin_review {
+            now
+        } else {
+            round_end
+        };
+        DisputeUtils::store_dispute_voting(env, &dispute_id, &voting)?;
+        DisputeUtils::start_round_timeout(env, &dispute_id, round_end)?;
 
         // Create escalation record
         let escalation = DisputeEscalation {
             dispute_id: dispute_id.clone(),
             escalated_by: user.clone(),
             escalation_reason: reason,
-            escalation_timestamp: env.ledger().timestamp(),
-            escalation_level: 1, // Start at level 1
-            requires_admin_review: true,
+            escalation_timestamp: now,
+            escalation_level,
+            requires_admin_review,
+            bond,
+            supports_dispute,
+            round_end,
         };
 
         // Store escalation
@@ -1651,6 +2036,11 @@ impl DisputeManager {
         Ok(escalation)
     }
 
+    /// Get the escalation rounds of a dispute, oldest first
+    pub fn get_dispute_rounds(env: &Env, dispute_id: &Symbol) -> Vec<DisputeEscalation> {
+        DisputeUtils::get_dispute_rounds(env, dispute_id)
+    }
+
     /// Get dispute votes
     pub fn get_dispute_votes(env: &Env, dispute_id: &Symbol) -> Result<Vec<DisputeVote>, Error> {
         DisputeUtils::get_dispute_votes(env, dispute_id)
@@ -1764,11 +2154,15 @@ impl DisputeManager {
         env: &Env,
         dispute_id: Symbol,
     ) -> Result<DisputeTimeoutOutcome, Error> {
-        // Get dispute voting data
-        let voting_data = DisputeUtils::get_dispute_voting(env, &dispute_id)?;
-
-        // Determine outcome based on stake-weighted voting
-        let outcome = if voting_data.total_support_stake > voting_data.total_against_stake {
+        // Dispute voting data must exist
+        let _voting_data = DisputeUtils::get_dispute_voting(env, &dispute_id)?;
+
+        // Determine outcome with the market's weighting strategy
+        let resolution_config =
+            ConfigManager::get_market_runtime_config(env, &dispute_id).resolution;
+        let (support_weight, against_weight) =
+            DisputeUtils::calculate_weighted_tally(env, &dispute_id, &resolution_config);
+        let outcome = if support_weight > against_weight {
             String::from_str(env, "Support")
         } else {
             String::from_str(env, "Against")
@@ -1783,7 +2177,7 @@ impl DisputeManager {
             resolution_timestamp: env.ledger().timestamp(),
             reason: String::from_str(
                 env,
-                "Dispute timeout expired - automatic resolution based on stake-weighted voting",
+                "Dispute timeout expired - automatic resolution based on weighted voting",
             ),
         };
 
@@ -1869,6 +2263,14 @@ impl DisputeManager {
 pub struct DisputeValidator;
 
 impl DisputeValidator {
+    /// Get the resolution criteria a dispute on this market is judged against
+    ///
+    /// Markets created without structured criteria have none, in which case
+    /// only the market question applies.
+    pub fn get_resolution_criteria(env: &Env, market_id: &Symbol) -> Option<ResolutionCriteria> {
+        MarketMetadataManager::get_resolution_criteria(env, market_id)
+    }
+
     /// Validate market state for dispute
     pub fn validate_market_for_dispute(env: &Env, market: &Market) -> Result<(), Error> {
         // Check if market has ended
@@ -1927,9 +2329,10 @@ impl DisputeValidator {
         user: &Address,
         market: &Market,
         stake: i128,
+        config: &VotingConfig,
     ) -> Result<(), Error> {
         // Validate stake amount
-        if stake < MIN_DISPUTE_STAKE {
+        if stake < config.min_dispute_stake {
             return Err(Error::InsufficientStake);
         }
 
@@ -2030,33 +2433,51 @@ impl DisputeValidator {
     }
 
     /// Validate dispute escalation conditions
+    ///
+    /// Only the side losing the current round's weighted tally may escalate.
+    /// Returns the side the user argues for: `true` for disputers and
+    /// supporting voters, `false` for opposing voters.
     pub fn validate_dispute_escalation_conditions(
         env: &Env,
         user: &Address,
         dispute_id: &Symbol,
-    ) -> Result<(), Error> {
-        // Check if user has participated in the dispute
-        let votes = DisputeUtils::get_dispute_votes(env, dispute_id)?;
-        let mut has_participated = false;
+    ) -> Result<bool, Error> {
+        // The current round must be over and the dispute unsettled
+        let voting = DisputeUtils::get_dispute_voting(env, dispute_id)
+            .map_err(|_| Error::DisputeEscalationNotAllowed)?;
+        if env.ledger().timestamp() <= voting.voting_end
+            || matches!(voting.status, DisputeVotingStatus::Completed)
+        {
+            return Err(Error::DisputeEscalationNotAllowed);
+        }
 
-        for vote in votes.iter() {
-            if vote.user == *user {
-                has_participated = true;
-                break;
+        // Check the final round has not been reached
+        if let Some(escalation) = DisputeUtils::get_dispute_escalation(env, dispute_id) {
+            if escalation.escalation_level >= MAX_DISPUTE_ESCALATION_LEVEL {
+                return Err(Error::DisputeEscalationNotAllowed);
             }
         }
 
-        if !has_participated {
-            return Err(Error::DisputeEscalationNotAllowed);
-        }
+        // Check if user has participated in the dispute
+        let market = MarketStateManager::get_market(env, dispute_id)?;
+        let supports_dispute = if DisputeUtils::get_user_dispute_stake(&market, user) > 0 {
+            true
+        } else {
+            DisputeUtils::get_dispute_vote(env, dispute_id, user)
+                .map(|vote| vote.vote)
+                .ok_or(Error::DisputeEscalationNotAllowed)?
+        };
 
-        // Check if escalation already exists
-        let escalation = DisputeUtils::get_dispute_escalation(env, dispute_id);
-        if escalation.is_some() {
+        // Check the user's side lost the current round
+        let resolution_config =
+            ConfigManager::get_market_runtime_config(env, dispute_id).resolution;
+        let (support_weight, against_weight) =
+            DisputeUtils::calculate_weighted_tally(env, dispute_id, &resolution_config);
+        if supports_dispute == (support_weight > against_weight) {
             return Err(Error::DisputeEscalationNotAllowed);
         }
 
-        Ok(())
+        Ok(supports_dispute)
     }
 
     /// Validate dispute timeout parameters
@@ -2122,8 +2543,12 @@ impl DisputeUtils {
     }
 
     /// Extend market for dispute period
-    pub fn extend_market_for_dispute(market: &mut Market, _env: &Env) -> Result<(), Error> {
-        let extension_seconds = (DISPUTE_EXTENSION_HOURS as u64) * 3600;
+    pub fn extend_market_for_dispute(
+        market: &mut Market,
+        _env: &Env,
+        config: &VotingConfig,
+    ) -> Result<(), Error> {
+        let extension_seconds = (config.dispute_extension_hours as u64) * 3600;
         market.end_time += extension_seconds;
         Ok(())
     }
@@ -2204,16 +2629,17 @@ impl DisputeUtils {
         market.dispute_stakes.get(user.clone()).unwrap_or(0)
     }
 
-    /// Calculate dispute impact on market resolution
-    pub fn calculate_dispute_impact(market: &Market) -> f64 {
+    /// Calculate dispute impact on market resolution as an integer
+    /// percentage of the total stake
+    pub fn calculate_dispute_impact(market: &Market) -> i128 {
         let total_staked = market.total_staked;
         let total_disputes = market.total_dispute_stakes();
 
         if total_staked == 0 {
-            return 0.0;
+            return 0;
         }
 
-        (total_disputes as f64) / (total_staked as f64)
+        (total_disputes * 100) / total_staked
     }
 
     /// Add vote to dispute
@@ -2238,12 +2664,58 @@ impl DisputeUtils {
         // Store updated voting data
         Self::store_dispute_voting(env, dispute_id, &voting_data)?;
 
-        // Store the vote
+        // Store the vote and index its voter
         Self::store_dispute_vote(env, dispute_id, &vote)?;
+        let mut voters = Self::get_dispute_voters(env, dispute_id);
+        voters.push_back(vote.user);
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("dsp_vtrs"), dispute_id.clone()), &voters);
 
         Ok(())
     }
 
+    /// Open community voting on a market's disputes
+    ///
+    /// Voting runs for the dispute extension period and is keyed by the
+    /// market id.
+    pub fn open_dispute_voting(
+        env: &Env,
+        market_id: &Symbol,
+        config: &VotingConfig,
+    ) -> Result<(), Error> {
+        let voting_start = env.ledger().timestamp();
+        let voting = DisputeVoting {
+            dispute_id: market_id.clone(),
+            voting_start,
+            voting_end: voting_start + (config.dispute_extension_hours as u64) * 3600,
+            total_votes: 0,
+            support_votes: 0,
+            against_votes: 0,
+            total_support_stake: 0,
+            total_against_stake: 0,
+            status: DisputeVotingStatus::Active,
+        };
+        Self::store_dispute_voting(env, market_id, &voting)?;
+        Self::start_round_timeout(env, market_id, voting.voting_end)
+    }
+
+    /// Start the timeout of a dispute round, replacing the previous round's
+    pub fn start_round_timeout(env: &Env, market_id: &Symbol, round_end: u64) -> Result<(), Error> {
+        let created_at = env.ledger().timestamp();
+        let timeout = DisputeTimeout {
+            dispute_id: market_id.clone(),
+            market_id: market_id.clone(),
+            timeout_hours: (round_end.saturating_sub(created_at) / 3600) as u32,
+            created_at,
+            expires_at: round_end,
+            extended_at: None,
+            total_extension_hours: 0,
+            status: DisputeTimeoutStatus::Active,
+        };
+        Self::store_dispute_timeout(env, market_id, &timeout)
+    }
+
     /// Get dispute voting data
     pub fn get_dispute_voting(env: &Env, dispute_id: &Symbol) -> Result<DisputeVoting, Error> {
         let key = (symbol_short!("dispute_v"), dispute_id.clone());
@@ -2277,22 +2749,72 @@ impl DisputeUtils {
 
     /// Get dispute votes
     pub fn get_dispute_votes(env: &Env, dispute_id: &Symbol) -> Result<Vec<DisputeVote>, Error> {
-        // This is a simplified implementation - in a real system you'd need to track all votes
-        let votes = Vec::new(env);
-
-        // Get the voting data to access stored votes
+        // Voting data must exist for the dispute
         let _voting_data = Self::get_dispute_voting(env, dispute_id)?;
 
-        // In a real implementation, you would iterate through stored vote keys
-        // For now, return empty vector as this would require tracking vote keys separately
+        let mut votes = Vec::new(env);
+        for voter in Self::get_dispute_voters(env, dispute_id).iter() {
+            if let Some(vote) = Self::get_dispute_vote(env, dispute_id, &voter) {
+                votes.push_back(vote);
+            }
+        }
         Ok(votes)
     }
 
+    /// Get a user's vote on a dispute, if any
+    pub fn get_dispute_vote(env: &Env, dispute_id: &Symbol, user: &Address) -> Option<DisputeVote> {
+        let key = (symbol_short!("vote"), dispute_id.clone(), user.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Get the addresses that voted on a dispute, in voting order
+    pub fn get_dispute_voters(env: &Env, dispute_id: &Symbol) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("dsp_vtrs"), dispute_id.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
     /// Calculate stake-weighted outcome
     pub fn calculate_stake_weighted_outcome(voting_data: &DisputeVoting) -> bool {
         voting_data.total_support_stake > voting_data.total_against_stake
     }
 
+    /// Calculate the weight of a dispute vote under a resolution configuration
+    pub fn calculate_vote_weight(env: &Env, vote: &DisputeVote, config: &ResolutionConfig) -> i128 {
+        match config.dispute_vote_weighting {
+            DisputeVoteWeighting::Stake => vote.stake,
+            DisputeVoteWeighting::QuadraticStake => NumericUtils::sqrt(&vote.stake),
+            // Per-address weightings ignore dust votes from throwaway addresses
+            _ if vote.stake < config.min_dispute_vote_stake => 0,
+            DisputeVoteWeighting::Reputation => {
+                ReputationManager::get_accuracy_score(env, &vote.user)
+            }
+            DisputeVoteWeighting::OneAddressOneVote => 1,
+        }
+    }
+
+    /// Calculate the weighted (support, against) totals of a dispute's votes
+    pub fn calculate_weighted_tally(
+        env: &Env,
+        dispute_id: &Symbol,
+        config: &ResolutionConfig,
+    ) -> (i128, i128) {
+        let mut support_weight = 0;
+        let mut against_weight = 0;
+        for voter in Self::get_dispute_voters(env, dispute_id).iter() {
+            if let Some(vote) = Self::get_dispute_vote(env, dispute_id, &voter) {
+                let weight = Self::calculate_vote_weight(env, &vote, config);
+                if vote.vote {
+                    support_weight += weight;
+                } else {
+                    against_weight += weight;
+                }
+            }
+        }
+        (support_weight, against_weight)
+    }
+
     /// Distribute fees based on outcome
     pub fn distribute_fees_based_on_outcome(
         env: &Env,
@@ -2312,13 +2834,20 @@ impl DisputeUtils {
             voting_data.total_support_stake
         };
 
+        let mut winner_addresses = Vec::new(env);
+        for vote in Self::get_dispute_votes(env, dispute_id)?.iter() {
+            if vote.vote == outcome {
+                winner_addresses.push_back(vote.user);
+            }
+        }
+
         // Create fee distribution record
         let fee_distribution = DisputeFeeDistribution {
             dispute_id: dispute_id.clone(),
             total_fees,
             winner_stake,
             loser_stake,
-            winner_addresses: Vec::new(env), // Would be populated with actual winner addresses
+            winner_addresses,
             distribution_timestamp: env.ledger().timestamp(),
             fees_distributed: true,
         };
@@ -2361,6 +2890,36 @@ impl DisputeUtils {
             }))
     }
 
+    /// Store dispute settlement
+    pub fn store_dispute_settlement(env: &Env, settlement: &DisputeSettlement) {
+        let key = (symbol_short!("dsp_setl"), settlement.market_id.clone());
+        env.storage().persistent().set(&key, settlement);
+    }
+
+    /// Get dispute settlement
+    pub fn get_dispute_settlement(env: &Env, market_id: &Symbol) -> Option<DisputeSettlement> {
+        let key = (symbol_short!("dsp_setl"), market_id.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Get the platform fee still available to fund dispute rewards
+    pub fn get_available_market_fee(env: &Env, market_id: &Symbol) -> i128 {
+        let fee_amount = match FeeManager::get_claim_fee_breakdown(env, market_id) {
+            Some(breakdown) => breakdown.fee_amount,
+            None => return 0,
+        };
+        let committed = FeeManager::get_claim_rebates(env, market_id)
+            + ReferralManager::get_market_credits(env, market_id);
+        (fee_amount - committed).max(0)
+    }
+
+    /// Get the dispute rewards funded from a market's platform fee
+    pub fn get_protocol_dispute_reward(env: &Env, market_id: &Symbol) -> i128 {
+        Self::get_dispute_settlement(env, market_id)
+            .map(|settlement| settlement.protocol_reward)
+            .unwrap_or(0)
+    }
+
     /// Store dispute escalation
     pub fn store_dispute_escalation(
         env: &Env,
@@ -2369,29 +2928,39 @@ impl DisputeUtils {
     ) -> Result<(), Error> {
         let key = (symbol_short!("dispute_e"), dispute_id.clone());
         env.storage().persistent().set(&key, escalation);
+
+        let mut rounds = Self::get_dispute_rounds(env, dispute_id);
+        rounds.push_back(escalation.clone());
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("dsp_rnds"), dispute_id.clone()), &rounds);
         Ok(())
     }
 
-    /// Get dispute escalation
+    /// Get the latest dispute escalation
     pub fn get_dispute_escalation(env: &Env, dispute_id: &Symbol) -> Option<DisputeEscalation> {
         let key = (symbol_short!("dispute_e"), dispute_id.clone());
         env.storage().persistent().get(&key)
     }
 
+    /// Get every escalation round of a dispute, oldest first
+    pub fn get_dispute_rounds(env: &Env, dispute_id: &Symbol) -> Vec<DisputeEscalation> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("dsp_rnds"), dispute_id.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
     /// Emit dispute vote event
 
     pub fn emit_dispute_vote_event(
         env: &Env,
-        _dispute_id: &Symbol,
+        dispute_id: &Symbol,
         user: &Address,
         vote: bool,
         stake: i128,
     ) {
-        // In a real implementation, this would emit an event
-        // For now, we'll just store it in persistent storage
-        let event_key = symbol_short!("vote_evt");
-        let event_data = (user.clone(), vote, stake, env.ledger().timestamp());
-        env.storage().persistent().set(&event_key, &event_data);
+        EventEmitter::emit_dispute_vote_cast(env, dispute_id, user, vote, stake);
     }
 
     /// Emit fee distribution event
@@ -2401,28 +2970,17 @@ impl DisputeUtils {
         _dispute_id: &Symbol,
         distribution: &DisputeFeeDistribution,
     ) {
-        // In a real implementation, this would emit an event
-        // For now, we'll just store it in persistent storage
-        let event_key = symbol_short!("fee_event");
-        env.storage().persistent().set(&event_key, distribution);
+        EventEmitter::emit_dispute_fees_distributed(env, distribution);
     }
 
     /// Emit dispute escalation event
     pub fn emit_dispute_escalation_event(
         env: &Env,
         _dispute_id: &Symbol,
-        user: &Address,
+        _user: &Address,
         escalation: &DisputeEscalation,
     ) {
-        // In a real implementation, this would emit an event
-        // For now, we'll just store it in persistent storage
-        let event_key = symbol_short!("esc_event");
-        let event_data = (
-            user.clone(),
-            escalation.escalation_level,
-            env.ledger().timestamp(),
-        );
-        env.storage().persistent().set(&event_key, &event_data);
+        EventEmitter::emit_dispute_escalated(env, escalation);
     }
 
     /// Store dispute timeout
@@ -2511,32 +3069,24 @@ impl DisputeAnalytics {
 
     /// Calculate dispute impact on market
     pub fn calculate_dispute_impact(market: &Market) -> i128 {
-        let impact = DisputeUtils::calculate_dispute_impact(market);
-        (impact * 100.0) as i128 // Convert to integer percentage
+        DisputeUtils::calculate_dispute_impact(market) // Integer percentage
     }
 
     /// Calculate oracle weight in resolution
     pub fn calculate_oracle_weight(market: &Market) -> i128 {
-        let dispute_impact = Self::calculate_dispute_impact(market) as f64 / 100.0; // Convert back to decimal
-
-        // Oracle weight decreases with dispute impact
-        let base_oracle_weight = 0.7;
-        let dispute_penalty = dispute_impact * 0.3;
+        let dispute_impact = Self::calculate_dispute_impact(market);
 
-        let weight = (base_oracle_weight - dispute_penalty).max(0.3);
-        (weight * 100.0) as i128 // Convert to integer percentage
+        // Oracle weight decreases from 70% by 0.3 points per point of impact
+        // (Soroban contracts cannot use floating point)
+        ((7000 - dispute_impact * 30) / 100).max(30)
     }
 
     /// Calculate community weight in resolution
     pub fn calculate_community_weight(market: &Market) -> i128 {
-        let dispute_impact = Self::calculate_dispute_impact(market) as f64 / 100.0; // Convert back to decimal
-
-        // Community weight increases with dispute impact
-        let base_community_weight = 0.3;
-        let dispute_boost = dispute_impact * 0.4;
+        let dispute_impact = Self::calculate_dispute_impact(market);
 
-        let weight = (base_community_weight + dispute_boost).min(0.7);
-        (weight * 100.0) as i128 // Convert to integer percentage
+        // Community weight increases from 30% by 0.4 points per point of impact
+        ((3000 + dispute_impact * 40) / 100).min(70)
     }
 
     /// Calculate community consensus
@@ -2693,6 +3243,9 @@ pub mod testing {
             community_weight: 30, // Using integer percentage
             dispute_impact: 10,   // Using integer percentage
             resolution_timestamp: env.ledger().timestamp(),
+            vote_weighting: DisputeVoteWeighting::Stake,
+            support_weight: 0,
+            against_weight: 0,
         }
     }
 
@@ -2834,13 +3387,15 @@ mod tests {
         let user = Address::generate(&env);
         let mut market = create_test_market(&env, env.ledger().timestamp().saturating_sub(1));
         market.oracle_result = Some(String::from_str(&env, "yes"));
+        let config = ConfigManager::get_default_voting_config();
 
         // Valid stake
         assert!(DisputeValidator::validate_dispute_parameters(
             &env,
             &user,
             &market,
-            MIN_DISPUTE_STAKE
+            config.min_dispute_stake,
+            &config
         )
         .is_ok());
 
@@ -2849,7 +3404,8 @@ mod tests {
             &env,
             &user,
             &market,
-            MIN_DISPUTE_STAKE - 1
+            config.min_dispute_stake - 1,
+            &config
         )
         .is_err());
     }
@@ -2865,7 +3421,7 @@ mod tests {
         market.dispute_stakes.set(user, 2000);
 
         let impact = DisputeUtils::calculate_dispute_impact(&market);
-        assert_eq!(impact, 0.2); // 2000 / 10000
+        assert_eq!(impact, 20); // 2000 / 10000
     }
 
     #[test]
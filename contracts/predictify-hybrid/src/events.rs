@@ -1,10 +1,19 @@
 extern crate alloc;
 
 // use alloc::string::ToString; // Removed to fix Display/ToString trait errors
-use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{
+    contracttype, events::Topics, symbol_short, vec, Address, BytesN, Env, IntoVal, Map, String,
+    Symbol, Val, Vec,
+};
 
+use crate::bonds::{BondStatus, CreationBond};
 use crate::config::Environment;
+use crate::curation::ProposalDecision;
+use crate::disputes::{DisputeEscalation, DisputeFeeDistribution};
 use crate::errors::Error;
+use crate::extensions::ExtensionEvent;
+use crate::storage::StorageOptimizer;
+use crate::types::{MarketState, ResolutionCriteria};
 
 // Define AdminRole locally since it's not available in the crate root
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -18,8 +27,10 @@ pub enum AdminRole {
 ///
 /// This module provides a centralized event emission and logging system with:
 /// - Event types and structures for all contract operations
-/// - Event emission utilities and helpers
-/// - Event logging and monitoring functions
+/// - Event emission utilities publishing to the ledger's event stream, read
+///   off-chain by indexers through RPC `getEvents`
+/// - A versioned topic layout, `(event type, schema version, keys...)`,
+///   described for every event type by `EventDocumentation`
 /// - Event validation and helper functions
 /// - Event testing utilities and examples
 /// - Event documentation and examples
@@ -360,6 +371,7 @@ pub struct MarketResolvedEvent {
 /// - Market identification and disputing party
 /// - Economic stake demonstrating dispute seriousness
 /// - Optional reasoning for dispute justification
+/// - The market's resolution criteria the dispute is judged against
 /// - Precise timing for dispute process management
 ///
 /// # Example Usage
@@ -367,6 +379,7 @@ pub struct MarketResolvedEvent {
 /// ```rust
 /// # use soroban_sdk::{Env, Address, Symbol, String};
 /// # use predictify_hybrid::events::DisputeCreatedEvent;
+/// # use predictify_hybrid::types::ResolutionCriteria;
 /// # let env = Env::default();
 /// # let disputer = Address::generate(&env);
 ///
@@ -377,6 +390,7 @@ pub struct MarketResolvedEvent {
 ///     stake: 50_000_000, // 5.0 XLM dispute stake
 ///     reason: Some(String::from_str(&env,
 ///         "Oracle price appears incorrect - multiple exchanges show different value")),
+///     resolution_criteria: ResolutionCriteria::unspecified(&env),
 ///     timestamp: env.ledger().timestamp(),
 /// };
 ///
@@ -425,6 +439,8 @@ pub struct DisputeCreatedEvent {
     pub stake: i128,
     /// Dispute reason
     pub reason: Option<String>,
+    /// Resolution criteria the market was created with (empty if it has none)
+    pub resolution_criteria: ResolutionCriteria,
     /// Dispute timestamp
     pub timestamp: u64,
 }
@@ -759,6 +775,206 @@ pub struct ConfigInitializedEvent {
     pub timestamp: u64,
 }
 
+/// Config update queued event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigUpdateQueuedEvent {
+    /// Admin who queued the update
+    pub proposed_by: Address,
+    /// Timestamp from which the update applies
+    pub effective_at: u64,
+    /// Queue timestamp
+    pub timestamp: u64,
+}
+
+/// Config update cancelled event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigUpdateCancelledEvent {
+    /// Admin who cancelled the update
+    pub cancelled_by: Address,
+    /// Timestamp the cancelled update would have applied from
+    pub effective_at: u64,
+    /// Cancellation timestamp
+    pub timestamp: u64,
+}
+
+/// Admin ownership transfer event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferEvent {
+    /// Admin handing over ownership
+    pub current_admin: Address,
+    /// Address proposed as the new admin
+    pub proposed_admin: Address,
+    /// Proposal expiry timestamp (0 once accepted or cancelled)
+    pub expires_at: u64,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Contract WASM upgrade event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUpgradedEvent {
+    /// Admin who performed the upgrade
+    pub admin: Address,
+    /// Contract version before the upgrade
+    pub from_version: u32,
+    /// Hash of the installed WASM
+    pub new_wasm_hash: BytesN<32>,
+    /// Upgrade timestamp
+    pub timestamp: u64,
+}
+
+/// Contract migration event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractMigratedEvent {
+    /// Admin who ran the migration
+    pub admin: Address,
+    /// Version of the stored data before migration
+    pub old_version: u32,
+    /// Version of the stored data after migration
+    pub new_version: u32,
+    /// Migration timestamp
+    pub timestamp: u64,
+}
+
+/// Fee accrued to the treasury event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeAccruedEvent {
+    /// Market the fee was collected from
+    pub market_id: Symbol,
+    /// Token the fee is held in
+    pub token: Address,
+    /// Fee amount added to the treasury
+    pub amount: i128,
+    /// Accrual timestamp
+    pub timestamp: u64,
+}
+
+/// Fee withdrawn from the treasury event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeWithdrawnEvent {
+    /// Fee recipient who withdrew
+    pub recipient: Address,
+    /// Token withdrawn
+    pub token: Address,
+    /// Amount withdrawn
+    pub amount: i128,
+    /// Withdrawal timestamp
+    pub timestamp: u64,
+}
+
+/// Creation bond event, emitted whenever a bond is posted or settled
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreationBondEvent {
+    /// Market the bond backs
+    pub market_id: Symbol,
+    /// Creator who posted the bond
+    pub creator: Address,
+    /// Bond amount
+    pub amount: i128,
+    /// Bond status after the update
+    pub status: BondStatus,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Market proposed for review event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketProposedEvent {
+    /// Proposed market
+    pub market_id: Symbol,
+    /// Creator who proposed the market
+    pub proposer: Address,
+    /// Proposal timestamp
+    pub timestamp: u64,
+}
+
+/// Market review decision event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketReviewedEvent {
+    /// Reviewed market
+    pub market_id: Symbol,
+    /// Admin who reviewed the market
+    pub reviewer: Address,
+    /// Review decision
+    pub decision: ProposalDecision,
+    /// Review timestamp
+    pub timestamp: u64,
+}
+
+/// Referral credit event, emitted when a referrer earns a share of a fee
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralCreditedEvent {
+    /// Market the fee was paid on
+    pub market_id: Symbol,
+    /// Referrer credited with the share
+    pub referrer: Address,
+    /// Referred user who paid the fee
+    pub user: Address,
+    /// Amount credited
+    pub amount: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Dispute payout claimed event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputePayoutEvent {
+    /// Market whose disputes were settled
+    pub market_id: Symbol,
+    /// Disputer or dispute voter who claimed
+    pub user: Address,
+    /// Amount paid out
+    pub amount: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Dispute vote cast event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeVoteCastEvent {
+    /// Disputed market the vote was cast on
+    pub dispute_id: Symbol,
+    /// Voter
+    pub voter: Address,
+    /// Whether the vote supports the dispute
+    pub vote: bool,
+    /// Stake backing the vote
+    pub stake: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Market lifecycle transition event
+///
+/// Published exactly once for every change of `Market::state`, so an indexer
+/// can follow a market from `Active` to `Closed` or `Cancelled` from this
+/// event alone.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketStateChangedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// State the market left
+    pub old_state: MarketState,
+    /// State the market entered
+    pub new_state: MarketState,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
 /// Storage cleanup event
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -890,7 +1106,11 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("mkt_crt"), &event);
+        Self::publish_event(
+            env,
+            (symbol_short!("mkt_crt"), market_id.clone(), admin.clone()),
+            &event,
+        );
     }
 
     /// Emit vote cast event
@@ -909,7 +1129,11 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("vote"), &event);
+        Self::publish_event(
+            env,
+            (symbol_short!("vote"), market_id.clone(), voter.clone()),
+            &event,
+        );
     }
 
     /// Emit oracle result event
@@ -934,7 +1158,7 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("oracle_rs"), &event);
+        Self::publish_event(env, (symbol_short!("oracle_rs"), market_id.clone()), &event);
     }
 
     /// Emit market resolved event
@@ -957,7 +1181,7 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("mkt_res"), &event);
+        Self::publish_event(env, (symbol_short!("mkt_res"), market_id.clone()), &event);
     }
 
     /// Emit dispute created event
@@ -967,16 +1191,28 @@ impl EventEmitter {
         disputer: &Address,
         stake: i128,
         reason: Option<String>,
+        resolution_criteria: Option<ResolutionCriteria>,
     ) {
+        let resolution_criteria =
+            resolution_criteria.unwrap_or_else(|| ResolutionCriteria::unspecified(env));
         let event = DisputeCreatedEvent {
             market_id: market_id.clone(),
             disputer: disputer.clone(),
             stake,
             reason,
+            resolution_criteria,
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("dispt_crt"), &event);
+        Self::publish_event(
+            env,
+            (
+                symbol_short!("dispt_crt"),
+                market_id.clone(),
+                disputer.clone(),
+            ),
+            &event,
+        );
     }
 
     /// Emit dispute resolved event
@@ -997,7 +1233,7 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("dispt_res"), &event);
+        Self::publish_event(env, (symbol_short!("dispt_res"), market_id.clone()), &event);
     }
 
     /// Emit fee collected event
@@ -1016,7 +1252,15 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("fee_col"), &event);
+        Self::publish_event(
+            env,
+            (
+                symbol_short!("fee_col"),
+                market_id.clone(),
+                collector.clone(),
+            ),
+            &event,
+        );
     }
 
     /// Emit extension requested event
@@ -1037,7 +1281,11 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("ext_req"), &event);
+        Self::publish_event(
+            env,
+            (symbol_short!("ext_req"), market_id.clone(), admin.clone()),
+            &event,
+        );
     }
 
     /// Emit configuration updated event
@@ -1056,7 +1304,7 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("cfg_upd"), &event);
+        Self::publish_event(env, (symbol_short!("cfg_upd"), updated_by.clone()), &event);
     }
 
     /// Emit error logged event
@@ -1077,7 +1325,7 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("err_log"), &event);
+        Self::publish_event(env, (symbol_short!("err_log"),), &event);
     }
 
     /// Emit performance metric event
@@ -1096,7 +1344,7 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("perf_met"), &event);
+        Self::publish_event(env, (symbol_short!("perf_met"),), &event);
     }
 
     /// Emit admin action logged event
@@ -1109,7 +1357,7 @@ impl EventEmitter {
             success: *success,
         };
 
-        Self::store_event(env, &symbol_short!("adm_act"), &event);
+        Self::publish_event(env, (symbol_short!("adm_act"), admin.clone()), &event);
     }
 
     /// Emit admin initialized event
@@ -1119,7 +1367,7 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("adm_init"), &event);
+        Self::publish_event(env, (symbol_short!("adm_init"), admin.clone()), &event);
     }
 
     /// Emit config initialized event
@@ -1138,7 +1386,314 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("cfg_init"), &event);
+        Self::publish_event(env, (symbol_short!("cfg_init"), admin.clone()), &event);
+    }
+
+    /// Emit config update queued event
+    pub fn emit_config_update_queued(env: &Env, proposed_by: &Address, effective_at: u64) {
+        let event = ConfigUpdateQueuedEvent {
+            proposed_by: proposed_by.clone(),
+            effective_at,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(
+            env,
+            (symbol_short!("cfg_queue"), proposed_by.clone()),
+            &event,
+        );
+    }
+
+    /// Emit config update cancelled event
+    pub fn emit_config_update_cancelled(env: &Env, cancelled_by: &Address, effective_at: u64) {
+        let event = ConfigUpdateCancelledEvent {
+            cancelled_by: cancelled_by.clone(),
+            effective_at,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(
+            env,
+            (symbol_short!("cfg_cancl"), cancelled_by.clone()),
+            &event,
+        );
+    }
+
+    /// Emit admin transfer proposed event
+    pub fn emit_admin_transfer_proposed(
+        env: &Env,
+        current_admin: &Address,
+        proposed_admin: &Address,
+        expires_at: u64,
+    ) {
+        let event = AdminTransferEvent {
+            current_admin: current_admin.clone(),
+            proposed_admin: proposed_admin.clone(),
+            expires_at,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(
+            env,
+            (symbol_short!("adm_prop"), current_admin.clone()),
+            &event,
+        );
+    }
+
+    /// Emit admin transfer accepted event
+    pub fn emit_admin_transfer_accepted(env: &Env, previous_admin: &Address, new_admin: &Address) {
+        let event = AdminTransferEvent {
+            current_admin: previous_admin.clone(),
+            proposed_admin: new_admin.clone(),
+            expires_at: 0,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(env, (symbol_short!("adm_xfer"), new_admin.clone()), &event);
+    }
+
+    /// Emit admin transfer cancelled event
+    pub fn emit_admin_transfer_cancelled(env: &Env, admin: &Address, proposed_admin: &Address) {
+        let event = AdminTransferEvent {
+            current_admin: admin.clone(),
+            proposed_admin: proposed_admin.clone(),
+            expires_at: 0,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(env, (symbol_short!("adm_xcncl"), admin.clone()), &event);
+    }
+
+    /// Emit contract upgraded event
+    pub fn emit_contract_upgraded(
+        env: &Env,
+        admin: &Address,
+        from_version: u32,
+        new_wasm_hash: &BytesN<32>,
+    ) {
+        let event = ContractUpgradedEvent {
+            admin: admin.clone(),
+            from_version,
+            new_wasm_hash: new_wasm_hash.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(env, (symbol_short!("upgrade"), admin.clone()), &event);
+    }
+
+    /// Emit contract migrated event
+    pub fn emit_contract_migrated(env: &Env, admin: &Address, old_version: u32, new_version: u32) {
+        let event = ContractMigratedEvent {
+            admin: admin.clone(),
+            old_version,
+            new_version,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(env, (symbol_short!("migrated"), admin.clone()), &event);
+    }
+
+    /// Emit fee accrued event
+    pub fn emit_fee_accrued(env: &Env, market_id: &Symbol, token: &Address, amount: i128) {
+        let event = FeeAccruedEvent {
+            market_id: market_id.clone(),
+            token: token.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(env, (symbol_short!("fee_accr"), market_id.clone()), &event);
+    }
+
+    /// Emit fee withdrawn event
+    pub fn emit_fee_withdrawn(env: &Env, recipient: &Address, token: &Address, amount: i128) {
+        let event = FeeWithdrawnEvent {
+            recipient: recipient.clone(),
+            token: token.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(env, (symbol_short!("fee_wdrw"), recipient.clone()), &event);
+    }
+
+    /// Emit creation bond event
+    pub fn emit_creation_bond_updated(env: &Env, bond: &CreationBond) {
+        let event = CreationBondEvent {
+            market_id: bond.market_id.clone(),
+            creator: bond.creator.clone(),
+            amount: bond.amount,
+            status: bond.status,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(
+            env,
+            (
+                symbol_short!("crt_bond"),
+                bond.market_id.clone(),
+                bond.creator.clone(),
+            ),
+            &event,
+        );
+    }
+
+    /// Emit market proposed event
+    pub fn emit_market_proposed(env: &Env, market_id: &Symbol, proposer: &Address) {
+        let event = MarketProposedEvent {
+            market_id: market_id.clone(),
+            proposer: proposer.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(
+            env,
+            (
+                symbol_short!("mkt_prop"),
+                market_id.clone(),
+                proposer.clone(),
+            ),
+            &event,
+        );
+    }
+
+    /// Emit market reviewed event
+    pub fn emit_market_reviewed(
+        env: &Env,
+        market_id: &Symbol,
+        reviewer: &Address,
+        decision: ProposalDecision,
+    ) {
+        let event = MarketReviewedEvent {
+            market_id: market_id.clone(),
+            reviewer: reviewer.clone(),
+            decision,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(
+            env,
+            (
+                symbol_short!("mkt_rvw"),
+                market_id.clone(),
+                reviewer.clone(),
+            ),
+            &event,
+        );
+    }
+
+    /// Emit referral credited event
+    pub fn emit_referral_credited(
+        env: &Env,
+        market_id: &Symbol,
+        referrer: &Address,
+        user: &Address,
+        amount: i128,
+    ) {
+        let event = ReferralCreditedEvent {
+            market_id: market_id.clone(),
+            referrer: referrer.clone(),
+            user: user.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(
+            env,
+            (
+                symbol_short!("ref_cred"),
+                market_id.clone(),
+                referrer.clone(),
+            ),
+            &event,
+        );
+    }
+
+    /// Emit dispute payout claimed event
+    pub fn emit_dispute_payout_claimed(
+        env: &Env,
+        market_id: &Symbol,
+        user: &Address,
+        amount: i128,
+    ) {
+        let event = DisputePayoutEvent {
+            market_id: market_id.clone(),
+            user: user.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(
+            env,
+            (symbol_short!("dsp_paid"), market_id.clone(), user.clone()),
+            &event,
+        );
+    }
+
+    /// Emit dispute vote cast event
+    pub fn emit_dispute_vote_cast(
+        env: &Env,
+        dispute_id: &Symbol,
+        voter: &Address,
+        vote: bool,
+        stake: i128,
+    ) {
+        let event = DisputeVoteCastEvent {
+            dispute_id: dispute_id.clone(),
+            voter: voter.clone(),
+            vote,
+            stake,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(
+            env,
+            (symbol_short!("dsp_vote"), dispute_id.clone(), voter.clone()),
+            &event,
+        );
+    }
+
+    /// Emit dispute fees distributed event
+    pub fn emit_dispute_fees_distributed(env: &Env, distribution: &DisputeFeeDistribution) {
+        Self::publish_event(
+            env,
+            (symbol_short!("dsp_fees"), distribution.dispute_id.clone()),
+            distribution,
+        );
+    }
+
+    /// Emit dispute escalated event
+    pub fn emit_dispute_escalated(env: &Env, escalation: &DisputeEscalation) {
+        Self::publish_event(
+            env,
+            (
+                symbol_short!("dsp_esc"),
+                escalation.dispute_id.clone(),
+                escalation.escalated_by.clone(),
+            ),
+            escalation,
+        );
+    }
+
+    /// Emit market extended event
+    pub fn emit_market_extended(
+        env: &Env,
+        market_id: &Symbol,
+        admin: &Address,
+        additional_days: u32,
+    ) {
+        let event = ExtensionEvent {
+            market_id: market_id.clone(),
+            additional_days,
+            admin: admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(
+            env,
+            (symbol_short!("mkt_ext"), market_id.clone(), admin.clone()),
+            &event,
+        );
     }
 
     /// Emit admin role assigned event
@@ -1162,7 +1717,7 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("adm_role"), &event);
+        Self::publish_event(env, (symbol_short!("adm_role"), admin.clone()), &event);
     }
 
     /// Emit admin role deactivated event
@@ -1174,7 +1729,7 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("adm_deact"), &event);
+        Self::publish_event(env, (symbol_short!("adm_deact"), admin.clone()), &event);
     }
 
     /// Emit market closed event
@@ -1185,7 +1740,11 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("mkt_close"), &event);
+        Self::publish_event(
+            env,
+            (symbol_short!("mkt_close"), market_id.clone(), admin.clone()),
+            &event,
+        );
     }
 
     /// Emit market finalized event
@@ -1197,7 +1756,11 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("mkt_final"), &event);
+        Self::publish_event(
+            env,
+            (symbol_short!("mkt_final"), market_id.clone(), admin.clone()),
+            &event,
+        );
     }
 
     /// Emit dispute timeout set event
@@ -1216,7 +1779,11 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("tout_set"), &event);
+        Self::publish_event(
+            env,
+            (symbol_short!("tout_set"), market_id.clone(), set_by.clone()),
+            &event,
+        );
     }
 
     /// Emit dispute timeout expired event
@@ -1235,7 +1802,7 @@ impl EventEmitter {
             resolution_method: resolution_method.clone(),
         };
 
-        Self::store_event(env, &symbol_short!("tout_exp"), &event);
+        Self::publish_event(env, (symbol_short!("tout_exp"), market_id.clone()), &event);
     }
 
     /// Emit dispute timeout extended event
@@ -1254,7 +1821,15 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("tout_ext"), &event);
+        Self::publish_event(
+            env,
+            (
+                symbol_short!("tout_ext"),
+                market_id.clone(),
+                extended_by.clone(),
+            ),
+            &event,
+        );
     }
 
     /// Emit dispute auto-resolved event
@@ -1273,22 +1848,18 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("auto_res"), &event);
+        Self::publish_event(env, (symbol_short!("auto_res"), market_id.clone()), &event);
     }
 
     /// Emit storage cleanup event
-    pub fn emit_storage_cleanup_event(
-        env: &Env,
-        market_id: &Symbol,
-        cleanup_type: &String,
-    ) {
+    pub fn emit_storage_cleanup_event(env: &Env, market_id: &Symbol, cleanup_type: &String) {
         let event = StorageCleanupEvent {
             market_id: market_id.clone(),
             cleanup_type: cleanup_type.clone(),
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("stor_cln"), &event);
+        Self::publish_event(env, (symbol_short!("stor_cln"), market_id.clone()), &event);
     }
 
     /// Emit storage optimization event
@@ -1303,7 +1874,7 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("stor_opt"), &event);
+        Self::publish_event(env, (symbol_short!("stor_opt"), market_id.clone()), &event);
     }
 
     /// Emit storage migration event
@@ -1322,188 +1893,126 @@ impl EventEmitter {
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("stor_mig"), &event);
+        Self::publish_event(env, (symbol_short!("stor_mig"),), &event);
     }
 
     /// Emit circuit breaker event
-    pub fn emit_circuit_breaker_event(
+    /// Emit the canonical event for a market lifecycle transition
+    pub fn emit_market_state_changed(
         env: &Env,
-        event: &CircuitBreakerEvent,
+        market_id: &Symbol,
+        old_state: MarketState,
+        new_state: MarketState,
     ) {
-        Self::store_event(env, &symbol_short!("cb_event"), event);
+        let event = MarketStateChangedEvent {
+            market_id: market_id.clone(),
+            old_state,
+            new_state,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::publish_event(env, (symbol_short!("mkt_state"), market_id.clone()), &event);
     }
 
-    /// Store event in persistent storage
-    fn store_event<T>(env: &Env, event_key: &Symbol, event_data: &T)
+    pub fn emit_circuit_breaker_event(env: &Env, event: &CircuitBreakerEvent) {
+        Self::publish_event(env, (symbol_short!("cb_event"),), event);
+    }
+
+    /// Publish an event to the ledger's event stream
+    ///
+    /// Every event uses the same topic layout: the event type, the
+    /// `EVENT_SCHEMA_VERSION` its data was encoded with, then the market and
+    /// the address the event concerns where it has them, so indexers can
+    /// filter on any of them.
+    fn publish_event<K, T>(env: &Env, topics: K, event: &T)
     where
-        T: Clone + soroban_sdk::IntoVal<soroban_sdk::Env, soroban_sdk::Val>,
+        K: Topics,
+        T: Clone + IntoVal<Env, Val>,
     {
-        env.storage().persistent().set(event_key, event_data);
+        let mut topics: Vec<Val> = topics.into_val(env);
+        topics.insert(1, EVENT_SCHEMA_VERSION.into_val(env));
+        env.events().publish(topics, event.clone());
     }
 }
 
 // ===== EVENT LOGGING AND MONITORING =====
 
-/// Event logging and monitoring utilities
+/// Bounded on-chain log of recent market activity
+///
+/// Clients that cannot run an indexer read a market's latest votes,
+/// disputes, extensions and resolution from here. Each market keeps at most
+/// `StorageConfig::activity_log_capacity` entries, dropping the oldest once
+/// full.
 pub struct EventLogger;
 
 impl EventLogger {
-    /// Get all events of a specific type
-    pub fn get_events<T>(env: &Env, event_type: &Symbol) -> Vec<T>
-    where
-        T: Clone
-            + soroban_sdk::TryFromVal<soroban_sdk::Env, soroban_sdk::Val>
-            + soroban_sdk::IntoVal<soroban_sdk::Env, soroban_sdk::Val>,
-    {
-        match env.storage().persistent().get::<Symbol, T>(event_type) {
-            Some(event) => Vec::from_array(env, [event]),
-            None => Vec::new(env),
-        }
-    }
-
-    /// Get events for a specific market
-    pub fn get_market_events(env: &Env, market_id: &Symbol) -> Vec<MarketEventSummary> {
-        let mut events = Vec::new(env);
-
-        // Get market created events
-        if let Some(event) = env
-            .storage()
-            .persistent()
-            .get::<Symbol, MarketCreatedEvent>(&symbol_short!("mkt_crt"))
-        {
-            if event.market_id == *market_id {
-                events.push_back(MarketEventSummary {
-                    event_type: String::from_str(env, "MarketCreated"),
-                    timestamp: event.timestamp,
-                    details: String::from_str(env, "Market was created"),
-                });
-            }
-        }
-
-        // Get vote cast events
-        if let Some(event) = env
-            .storage()
-            .persistent()
-            .get::<Symbol, VoteCastEvent>(&symbol_short!("vote"))
-        {
-            if event.market_id == *market_id {
-                events.push_back(MarketEventSummary {
-                    event_type: String::from_str(env, "VoteCast"),
-                    timestamp: event.timestamp,
-                    details: String::from_str(env, "Vote was cast"),
-                });
-            }
+    /// Append an entry to a market's activity log
+    pub fn record_market_activity(
+        env: &Env,
+        market_id: &Symbol,
+        kind: MarketActivityKind,
+        actor: &Address,
+        outcome: &String,
+        amount: i128,
+    ) {
+        let capacity = StorageOptimizer::get_storage_config(env).activity_log_capacity;
+        if capacity == 0 {
+            return;
         }
 
-        // Get oracle result events
-        if let Some(event) = env
-            .storage()
-            .persistent()
-            .get::<Symbol, OracleResultEvent>(&symbol_short!("oracle_rs"))
-        {
-            if event.market_id == *market_id {
-                events.push_back(MarketEventSummary {
-                    event_type: String::from_str(env, "OracleResult"),
-                    timestamp: event.timestamp,
-                    details: String::from_str(env, "Oracle result fetched"),
-                });
-            }
+        let mut log = Self::get_activity_log(env, market_id);
+        log.push_back(MarketActivity {
+            kind,
+            actor: actor.clone(),
+            outcome: outcome.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        });
+        while log.len() > capacity {
+            log.pop_front();
         }
-
-        // Get market resolved events
-        if let Some(event) = env
-            .storage()
+        env.storage()
             .persistent()
-            .get::<Symbol, MarketResolvedEvent>(&symbol_short!("mkt_res"))
-        {
-            if event.market_id == *market_id {
-                events.push_back(MarketEventSummary {
-                    event_type: String::from_str(env, "MarketResolved"),
-                    timestamp: event.timestamp,
-                    details: String::from_str(env, "Market was resolved"),
-                });
-            }
-        }
-
-        events
+            .set(&(symbol_short!("activity"), market_id.clone()), &log);
     }
 
-    /// Get recent events (last N events)
-    pub fn get_recent_events(env: &Env, limit: u32) -> Vec<EventSummary> {
-        let mut events = Vec::new(env);
-
-        // This is a simplified implementation
-        // In a real system, you would maintain an event log with timestamps
-        let event_types = vec![
-            env,
-            symbol_short!("mkt_crt"),
-            symbol_short!("vote"),
-            symbol_short!("oracle_rs"),
-            symbol_short!("mkt_res"),
-            symbol_short!("dispt_crt"),
-            symbol_short!("dispt_res"),
-            symbol_short!("fee_col"),
-            symbol_short!("ext_req"),
-            symbol_short!("cfg_upd"),
-            symbol_short!("err_log"),
-            symbol_short!("perf_met"),
-        ];
-
-        let mut count = 0;
-        for event_type in event_types.iter() {
-            if count >= limit {
-                break;
-            }
-
-            // Check if event exists and add to summary
-            if env.storage().persistent().has(&event_type) {
-                events.push_back(EventSummary {
-                    event_type: String::from_str(env, "event"),
-                    timestamp: env.ledger().timestamp(),
-                    details: String::from_str(env, "Event occurred"),
-                });
-                count += 1;
-            }
+    /// Get a page of a market's activity, newest first
+    ///
+    /// `limit` is capped at `MAX_EVENTS_PER_QUERY`.
+    pub fn get_market_activity(
+        env: &Env,
+        market_id: &Symbol,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<MarketActivity> {
+        let log = Self::get_activity_log(env, market_id);
+        let limit = limit.min(MAX_EVENTS_PER_QUERY);
+        let mut page = Vec::new(env);
+        let mut index = log.len().saturating_sub(offset);
+        while index > 0 && page.len() < limit {
+            index -= 1;
+            page.push_back(log.get_unchecked(index));
         }
-
-        events
+        page
     }
 
-    /// Get error events
-    pub fn get_error_events(env: &Env) -> Vec<ErrorLoggedEvent> {
-        Self::get_events(env, &symbol_short!("err_log"))
+    /// Get the number of entries currently held in a market's activity log
+    pub fn get_market_activity_count(env: &Env, market_id: &Symbol) -> u32 {
+        Self::get_activity_log(env, market_id).len()
     }
 
-    /// Get performance metrics
-    pub fn get_performance_metrics(env: &Env) -> Vec<PerformanceMetricEvent> {
-        Self::get_events(env, &symbol_short!("perf_met"))
+    /// Remove a market's activity log
+    pub fn clear_market_activity(env: &Env, market_id: &Symbol) {
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("activity"), market_id.clone()));
     }
 
-    /// Clear old events (cleanup utility)
-    pub fn clear_old_events(env: &Env, _older_than_timestamp: u64) {
-        let event_types = vec![
-            env,
-            symbol_short!("mkt_crt"),
-            symbol_short!("vote"),
-            symbol_short!("oracle_rs"),
-            symbol_short!("mkt_res"),
-            symbol_short!("dispt_crt"),
-            symbol_short!("dispt_res"),
-            symbol_short!("fee_col"),
-            symbol_short!("ext_req"),
-            symbol_short!("cfg_upd"),
-            symbol_short!("err_log"),
-            symbol_short!("perf_met"),
-        ];
-
-        for event_type in event_types.iter() {
-            // In a real implementation, you would check timestamps and remove old events
-            // For now, this is a placeholder
-            if env.storage().persistent().has(&event_type) {
-                // Check if event is older than threshold and remove if needed
-                // This would require storing timestamps with events
-            }
-        }
+    fn get_activity_log(env: &Env, market_id: &Symbol) -> Vec<MarketActivity> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("activity"), market_id.clone()))
+            .unwrap_or(Vec::new(env))
     }
 }
 
@@ -1761,6 +2270,7 @@ impl EventTestingUtils {
             disputer: disputer.clone(),
             stake: 10_0000000,
             reason: Some(String::from_str(env, "Test dispute")),
+            resolution_criteria: ResolutionCriteria::unspecified(env),
             timestamp: env.ledger().timestamp(),
         }
     }
@@ -1814,13 +2324,10 @@ impl EventTestingUtils {
     }
 
     /// Simulate event emission
-    pub fn simulate_event_emission(env: &Env, _event_type: &String) -> bool {
+    pub fn simulate_event_emission(env: &Env, event_type: &String) -> bool {
         // Simulate successful event emission
-
-        let event_key = Symbol::new(env, "event");
-        env.storage()
-            .persistent()
-            .set(&event_key, &String::from_str(env, "test"));
+        env.events()
+            .publish((Symbol::new(env, "event"),), event_type.clone());
 
         true
     }
@@ -1852,9 +2359,63 @@ pub struct MarketEventSummary {
     pub details: String,
 }
 
+/// Kind of market activity kept in the activity log
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MarketActivityKind {
+    /// A user voted on an outcome
+    Vote,
+    /// A user disputed the market result
+    Dispute,
+    /// An admin extended the market
+    Extension,
+    /// The market was resolved
+    Resolution,
+}
+
+/// Entry in a market's activity log
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketActivity {
+    /// Kind of activity
+    pub kind: MarketActivityKind,
+    /// Address that performed the activity, the contract itself for
+    /// resolutions
+    pub actor: Address,
+    /// Voted outcome for votes, winning outcome for resolutions, empty
+    /// otherwise
+    pub outcome: String,
+    /// Stake for votes and disputes, additional days for extensions, zero
+    /// for resolutions
+    pub amount: i128,
+    /// Activity timestamp
+    pub timestamp: u64,
+}
+
+/// Registry entry describing the layout of one event type
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventSchema {
+    /// Event name
+    pub name: String,
+    /// First topic of the event
+    pub topic: Symbol,
+    /// Schema version, published as the second topic
+    pub version: u32,
+    /// Names of the topics following the schema version
+    pub topic_keys: Vec<String>,
+    /// Names of the event data fields, in order
+    pub fields: Vec<String>,
+    /// Event description
+    pub description: String,
+}
+
 // ===== EVENT CONSTANTS =====
 
 /// Event system constants
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+pub const DEFAULT_ACTIVITY_LOG_CAPACITY: u32 = 50;
+pub const MAX_ACTIVITY_LOG_CAPACITY: u32 = 200;
 pub const MAX_EVENTS_PER_QUERY: u32 = 100;
 pub const EVENT_RETENTION_DAYS: u64 = 30 * 24 * 60 * 60; // 30 days
 pub const RECENT_EVENT_THRESHOLD: u64 = 24 * 60 * 60; // 24 hours
@@ -1871,57 +2432,553 @@ impl EventDocumentation {
     }
 
     /// Get event type documentation
-    pub fn get_event_type_docs(env: &Env) -> Map<String, String> {
+    ///
+    /// Returns the machine-readable registry of every event the contract
+    /// publishes, keyed by event name. Each entry gives the event's first
+    /// topic, the keys in the topics that follow the schema version, and the
+    /// fields of its data in order.
+    pub fn get_event_type_docs(env: &Env) -> Map<String, EventSchema> {
         let mut docs = Map::new(env);
 
-        docs.set(
-            String::from_str(env, "MarketCreated"),
-            String::from_str(env, "Emitted when a new market is created"),
+        Self::register(
+            env,
+            &mut docs,
+            "MarketCreated",
+            symbol_short!("mkt_crt"),
+            &["market_id", "admin"],
+            &[
+                "market_id",
+                "question",
+                "outcomes",
+                "admin",
+                "end_time",
+                "timestamp",
+            ],
+            "Emitted when a new market is created",
         );
-        docs.set(
-            String::from_str(env, "VoteCast"),
-            String::from_str(env, "Emitted when a user casts a vote"),
+        Self::register(
+            env,
+            &mut docs,
+            "VoteCast",
+            symbol_short!("vote"),
+            &["market_id", "voter"],
+            &["market_id", "voter", "outcome", "stake", "timestamp"],
+            "Emitted when a user casts a vote",
         );
-        docs.set(
-            String::from_str(env, "OracleResult"),
-            String::from_str(env, "Emitted when oracle result is fetched"),
+        Self::register(
+            env,
+            &mut docs,
+            "OracleResult",
+            symbol_short!("oracle_rs"),
+            &["market_id"],
+            &[
+                "market_id",
+                "result",
+                "provider",
+                "feed_id",
+                "price",
+                "threshold",
+                "comparison",
+                "timestamp",
+            ],
+            "Emitted when oracle result is fetched",
         );
-        docs.set(
-            String::from_str(env, "MarketResolved"),
-            String::from_str(env, "Emitted when a market is resolved"),
+        Self::register(
+            env,
+            &mut docs,
+            "MarketResolved",
+            symbol_short!("mkt_res"),
+            &["market_id"],
+            &[
+                "market_id",
+                "final_outcome",
+                "oracle_result",
+                "community_consensus",
+                "resolution_method",
+                "confidence_score",
+                "timestamp",
+            ],
+            "Emitted when a market is resolved",
         );
-        docs.set(
-            String::from_str(env, "DisputeCreated"),
-            String::from_str(env, "Emitted when a dispute is created"),
+        Self::register(
+            env,
+            &mut docs,
+            "MarketStateChanged",
+            symbol_short!("mkt_state"),
+            &["market_id"],
+            &["market_id", "old_state", "new_state", "timestamp"],
+            "Emitted once for every market lifecycle transition",
         );
-        docs.set(
-            String::from_str(env, "DisputeResolved"),
-            String::from_str(env, "Emitted when a dispute is resolved"),
+        Self::register(
+            env,
+            &mut docs,
+            "DisputeCreated",
+            symbol_short!("dispt_crt"),
+            &["market_id", "disputer"],
+            &[
+                "market_id",
+                "disputer",
+                "stake",
+                "reason",
+                "resolution_criteria",
+                "timestamp",
+            ],
+            "Emitted when a dispute is created",
         );
-        docs.set(
-            String::from_str(env, "FeeCollected"),
-            String::from_str(env, "Emitted when fees are collected"),
+        Self::register(
+            env,
+            &mut docs,
+            "DisputeResolved",
+            symbol_short!("dispt_res"),
+            &["market_id"],
+            &[
+                "market_id",
+                "outcome",
+                "winners",
+                "losers",
+                "fee_distribution",
+                "timestamp",
+            ],
+            "Emitted when a dispute is resolved",
         );
-        docs.set(
-            String::from_str(env, "ExtensionRequested"),
-            String::from_str(env, "Emitted when market extension is requested"),
+        Self::register(
+            env,
+            &mut docs,
+            "DisputeVoteCast",
+            symbol_short!("dsp_vote"),
+            &["dispute_id", "voter"],
+            &["dispute_id", "voter", "vote", "stake", "timestamp"],
+            "Emitted when a user votes on a dispute",
         );
-        docs.set(
-            String::from_str(env, "ConfigUpdated"),
-            String::from_str(env, "Emitted when configuration is updated"),
+        Self::register(
+            env,
+            &mut docs,
+            "DisputeFeesDistributed",
+            symbol_short!("dsp_fees"),
+            &["dispute_id"],
+            &[
+                "dispute_id",
+                "total_fees",
+                "winner_stake",
+                "loser_stake",
+                "winner_addresses",
+                "distribution_timestamp",
+                "fees_distributed",
+            ],
+            "Emitted when dispute fees are distributed",
         );
-        docs.set(
-            String::from_str(env, "ErrorLogged"),
-            String::from_str(env, "Emitted when an error is logged"),
+        Self::register(
+            env,
+            &mut docs,
+            "DisputeEscalated",
+            symbol_short!("dsp_esc"),
+            &["dispute_id", "escalated_by"],
+            &[
+                "dispute_id",
+                "escalated_by",
+                "escalation_reason",
+                "escalation_timestamp",
+                "escalation_level",
+                "requires_admin_review",
+                "bond",
+                "supports_dispute",
+                "round_end",
+            ],
+            "Emitted when a dispute is escalated to a new round",
         );
-        docs.set(
-            String::from_str(env, "PerformanceMetric"),
-            String::from_str(env, "Emitted when performance metrics are recorded"),
+        Self::register(
+            env,
+            &mut docs,
+            "DisputePayoutClaimed",
+            symbol_short!("dsp_paid"),
+            &["market_id", "user"],
+            &["market_id", "user", "amount", "timestamp"],
+            "Emitted when a dispute payout is claimed",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "DisputeTimeoutSet",
+            symbol_short!("tout_set"),
+            &["market_id", "set_by"],
+            &[
+                "dispute_id",
+                "market_id",
+                "timeout_hours",
+                "set_by",
+                "timestamp",
+            ],
+            "Emitted when a dispute timeout is set",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "DisputeTimeoutExpired",
+            symbol_short!("tout_exp"),
+            &["market_id"],
+            &[
+                "dispute_id",
+                "market_id",
+                "expiration_timestamp",
+                "outcome",
+                "resolution_method",
+            ],
+            "Emitted when a dispute timeout expires",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "DisputeTimeoutExtended",
+            symbol_short!("tout_ext"),
+            &["market_id", "extended_by"],
+            &[
+                "dispute_id",
+                "market_id",
+                "additional_hours",
+                "extended_by",
+                "timestamp",
+            ],
+            "Emitted when a dispute timeout is extended",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "DisputeAutoResolved",
+            symbol_short!("auto_res"),
+            &["market_id"],
+            &["dispute_id", "market_id", "outcome", "reason", "timestamp"],
+            "Emitted when a dispute is resolved on timeout",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "FeeCollected",
+            symbol_short!("fee_col"),
+            &["market_id", "collector"],
+            &["market_id", "collector", "amount", "fee_type", "timestamp"],
+            "Emitted when fees are collected",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "FeeAccrued",
+            symbol_short!("fee_accr"),
+            &["market_id"],
+            &["market_id", "token", "amount", "timestamp"],
+            "Emitted when a claim fee accrues to the platform",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "FeeWithdrawn",
+            symbol_short!("fee_wdrw"),
+            &["recipient"],
+            &["recipient", "token", "amount", "timestamp"],
+            "Emitted when accrued fees are withdrawn",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "ReferralCredited",
+            symbol_short!("ref_cred"),
+            &["market_id", "referrer"],
+            &["market_id", "referrer", "user", "amount", "timestamp"],
+            "Emitted when a referrer is credited a share of a claim fee",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "CreationBondUpdated",
+            symbol_short!("crt_bond"),
+            &["market_id", "creator"],
+            &["market_id", "creator", "amount", "status", "timestamp"],
+            "Emitted when a market creation bond changes status",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "MarketProposed",
+            symbol_short!("mkt_prop"),
+            &["market_id", "proposer"],
+            &["market_id", "proposer", "timestamp"],
+            "Emitted when a market is proposed for review",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "MarketReviewed",
+            symbol_short!("mkt_rvw"),
+            &["market_id", "reviewer"],
+            &["market_id", "reviewer", "decision", "timestamp"],
+            "Emitted when a proposed market is reviewed",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "ExtensionRequested",
+            symbol_short!("ext_req"),
+            &["market_id", "admin"],
+            &[
+                "market_id",
+                "admin",
+                "additional_days",
+                "reason",
+                "fee",
+                "timestamp",
+            ],
+            "Emitted when market extension is requested",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "MarketExtended",
+            symbol_short!("mkt_ext"),
+            &["market_id", "admin"],
+            &["market_id", "additional_days", "admin", "timestamp"],
+            "Emitted when a market is extended",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "MarketClosed",
+            symbol_short!("mkt_close"),
+            &["market_id", "admin"],
+            &["market_id", "admin", "timestamp"],
+            "Emitted when a market is closed by an admin",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "MarketFinalized",
+            symbol_short!("mkt_final"),
+            &["market_id", "admin"],
+            &["market_id", "admin", "outcome", "timestamp"],
+            "Emitted when a market is finalized by an admin",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "ConfigInitialized",
+            symbol_short!("cfg_init"),
+            &["admin"],
+            &["admin", "environment", "timestamp"],
+            "Emitted when configuration is initialized",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "ConfigUpdated",
+            symbol_short!("cfg_upd"),
+            &["updated_by"],
+            &[
+                "updated_by",
+                "config_type",
+                "old_value",
+                "new_value",
+                "timestamp",
+            ],
+            "Emitted when configuration is updated",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "ConfigUpdateQueued",
+            symbol_short!("cfg_queue"),
+            &["proposed_by"],
+            &["proposed_by", "effective_at", "timestamp"],
+            "Emitted when a configuration update is queued",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "ConfigUpdateCancelled",
+            symbol_short!("cfg_cancl"),
+            &["cancelled_by"],
+            &["cancelled_by", "effective_at", "timestamp"],
+            "Emitted when a queued configuration update is cancelled",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "AdminInitialized",
+            symbol_short!("adm_init"),
+            &["admin"],
+            &["admin", "timestamp"],
+            "Emitted when the contract admin is initialized",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "AdminActionLogged",
+            symbol_short!("adm_act"),
+            &["admin"],
+            &["admin", "action", "target", "timestamp", "success"],
+            "Emitted when an admin action is logged",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "AdminRoleAssigned",
+            symbol_short!("adm_role"),
+            &["admin"],
+            &["admin", "role", "assigned_by", "timestamp"],
+            "Emitted when an admin role is assigned",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "AdminRoleDeactivated",
+            symbol_short!("adm_deact"),
+            &["admin"],
+            &["admin", "role", "assigned_by", "timestamp"],
+            "Emitted when an admin role is deactivated",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "AdminTransferProposed",
+            symbol_short!("adm_prop"),
+            &["current_admin"],
+            &["current_admin", "proposed_admin", "expires_at", "timestamp"],
+            "Emitted when an admin transfer is proposed",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "AdminTransferAccepted",
+            symbol_short!("adm_xfer"),
+            &["new_admin"],
+            &["current_admin", "proposed_admin", "expires_at", "timestamp"],
+            "Emitted when an admin transfer is accepted",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "AdminTransferCancelled",
+            symbol_short!("adm_xcncl"),
+            &["admin"],
+            &["current_admin", "proposed_admin", "expires_at", "timestamp"],
+            "Emitted when an admin transfer is cancelled",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "ContractUpgraded",
+            symbol_short!("upgrade"),
+            &["admin"],
+            &["admin", "from_version", "new_wasm_hash", "timestamp"],
+            "Emitted when the contract code is upgraded",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "ContractMigrated",
+            symbol_short!("migrated"),
+            &["admin"],
+            &["admin", "old_version", "new_version", "timestamp"],
+            "Emitted when contract storage is migrated",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "ErrorLogged",
+            symbol_short!("err_log"),
+            &[],
+            &[
+                "error_code",
+                "message",
+                "context",
+                "user",
+                "market_id",
+                "timestamp",
+            ],
+            "Emitted when an error is logged",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "PerformanceMetric",
+            symbol_short!("perf_met"),
+            &[],
+            &["metric_name", "value", "unit", "context", "timestamp"],
+            "Emitted when performance metrics are recorded",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "StorageCleanup",
+            symbol_short!("stor_cln"),
+            &["market_id"],
+            &["market_id", "cleanup_type", "timestamp"],
+            "Emitted when market storage is cleaned up",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "StorageOptimization",
+            symbol_short!("stor_opt"),
+            &["market_id"],
+            &["market_id", "optimization_type", "timestamp"],
+            "Emitted when market storage is optimized",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "StorageMigration",
+            symbol_short!("stor_mig"),
+            &[],
+            &[
+                "migration_id",
+                "from_format",
+                "to_format",
+                "markets_migrated",
+                "timestamp",
+            ],
+            "Emitted when storage is migrated to a new format",
+        );
+        Self::register(
+            env,
+            &mut docs,
+            "CircuitBreaker",
+            symbol_short!("cb_event"),
+            &[],
+            &["action", "condition", "reason", "timestamp", "admin"],
+            "Emitted when the circuit breaker changes state",
         );
 
         docs
     }
 
+    fn register(
+        env: &Env,
+        docs: &mut Map<String, EventSchema>,
+        name: &str,
+        topic: Symbol,
+        topic_keys: &[&str],
+        fields: &[&str],
+        description: &str,
+    ) {
+        let to_strings = |names: &[&str]| {
+            let mut strings = Vec::new(env);
+            for name in names {
+                strings.push_back(String::from_str(env, name));
+            }
+            strings
+        };
+
+        docs.set(
+            String::from_str(env, name),
+            EventSchema {
+                name: String::from_str(env, name),
+                topic,
+                version: EVENT_SCHEMA_VERSION,
+                topic_keys: to_strings(topic_keys),
+                fields: to_strings(fields),
+                description: String::from_str(env, description),
+            },
+        );
+    }
+
     /// Get usage examples
     pub fn get_usage_examples(env: &Env) -> Map<String, String> {
         let mut examples = Map::new(env);
@@ -1937,10 +2994,6 @@ impl EventDocumentation {
                 "EventEmitter::emit_vote_cast(env, market_id, voter, outcome, stake)",
             ),
         );
-        examples.set(
-            String::from_str(env, "GetMarketEvents"),
-            String::from_str(env, "EventLogger::get_market_events(env, market_id)"),
-        );
         examples.set(
             String::from_str(&env, "ValidateEvent"),
             String::from_str(
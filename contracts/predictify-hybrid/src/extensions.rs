@@ -1,6 +1,9 @@
 use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, String, Symbol, Vec};
 
+use crate::admin::{AdminAccessControl, AdminPermission};
+use crate::config::{ConfigManager, ExtensionConfig};
 use crate::errors::Error;
+use crate::events::{EventEmitter, EventLogger, MarketActivityKind};
 use crate::types::*;
 
 /// Market extension management system for Predictify Hybrid contract
@@ -12,15 +15,6 @@ use crate::types::*;
 /// - Extension history tracking
 /// - Extension analytics and reporting
 
-// ===== EXTENSION CONSTANTS =====
-// Note: These constants are now managed by the config module
-// Use ConfigManager::get_extension_config() to get current values
-
-const MAX_EXTENSION_DAYS: u32 = crate::config::MAX_EXTENSION_DAYS;
-const MIN_EXTENSION_DAYS: u32 = crate::config::MIN_EXTENSION_DAYS;
-const EXTENSION_FEE_PER_DAY: i128 = crate::config::EXTENSION_FEE_PER_DAY; // 1 XLM per day in stroops
-const MAX_TOTAL_EXTENSIONS: u32 = crate::config::MAX_TOTAL_EXTENSIONS;
-
 // ===== EXTENSION MANAGEMENT =====
 
 /// Comprehensive market extension management system for Predictify Hybrid contracts.
@@ -179,7 +173,8 @@ impl ExtensionManager {
     /// # Security Considerations
     ///
     /// - **Authentication**: Admin must sign the transaction
-    /// - **Authorization**: Only market admin can extend their markets
+    /// - **Authorization**: Only the primary admin or admins with the
+    ///   `ExtendMarket` permission can extend markets
     /// - **Rate Limiting**: Maximum extensions per market enforced
     /// - **Economic Barriers**: Fees prevent spam extensions
     pub fn extend_market_duration(
@@ -218,6 +213,14 @@ impl ExtensionManager {
 
         // Emit extension event
         ExtensionUtils::emit_extension_event(env, &market_id, additional_days, &admin);
+        EventLogger::record_market_activity(
+            env,
+            &market_id,
+            MarketActivityKind::Extension,
+            &admin,
+            &String::from_str(env, ""),
+            additional_days as i128,
+        );
 
         Ok(())
     }
@@ -381,14 +384,25 @@ impl ExtensionManager {
         market_id: Symbol,
     ) -> Result<crate::types::ExtensionStats, Error> {
         let market = MarketStateManager::get_market(env, &market_id)?;
+        let config = ConfigManager::get_market_runtime_config(env, &market_id).extension;
 
         Ok(crate::types::ExtensionStats {
             total_extensions: market.extension_history.len().try_into().unwrap_or(0),
             total_extension_days: market.total_extension_days,
             max_extension_days: market.max_extension_days,
-            can_extend: ExtensionValidator::can_extend_market(env, &market_id, &market.admin)
+            can_extend: ExtensionValidator::validate_extension_conditions(
+                env,
+                &market_id,
+                config.min_extension_days,
+            )
+            .is_ok()
+                && ExtensionValidator::check_extension_limits(
+                    env,
+                    &market_id,
+                    config.min_extension_days,
+                )
                 .is_ok(),
-            extension_fee_per_day: EXTENSION_FEE_PER_DAY,
+            extension_fee_per_day: config.fee_per_day,
         })
     }
 
@@ -475,12 +489,13 @@ impl ExtensionManager {
     /// Calculates the total fee required for extending a market by specified days.
     ///
     /// This function provides transparent fee calculation based on the current
-    /// per-day extension rate. The calculation is straightforward: days multiplied
+    /// per-day extension rate from the extension configuration. The calculation is straightforward: days multiplied
     /// by the daily rate, with no hidden fees or complex pricing structures.
     ///
     /// # Parameters
     ///
     /// * `additional_days` - Number of days to extend the market (1-30)
+    /// * `config` - Extension configuration providing the per-day rate
     ///
     /// # Returns
     ///
@@ -490,11 +505,13 @@ impl ExtensionManager {
     ///
     /// ```rust
     /// # use predictify_hybrid::extensions::ExtensionManager;
+    /// # use predictify_hybrid::config::ConfigManager;
+    /// let config = ConfigManager::get_default_extension_config();
     ///
     /// // Calculate fees for different extension periods
-    /// let one_day_fee = ExtensionManager::calculate_extension_fee(1);
-    /// let one_week_fee = ExtensionManager::calculate_extension_fee(7);
-    /// let one_month_fee = ExtensionManager::calculate_extension_fee(30);
+    /// let one_day_fee = ExtensionManager::calculate_extension_fee(1, &config);
+    /// let one_week_fee = ExtensionManager::calculate_extension_fee(7, &config);
+    /// let one_month_fee = ExtensionManager::calculate_extension_fee(30, &config);
     ///
     /// println!("Extension Fee Calculator");
     /// println!("─────────────────────────");
@@ -521,7 +538,7 @@ impl ExtensionManager {
     /// # Fee Structure
     ///
     /// Extension fees follow a simple linear model:
-    /// - **Base Rate**: 1 XLM per day (10,000,000 stroops)
+    /// - **Base Rate**: `config.fee_per_day` (1 XLM per day by default)
     /// - **Linear Scaling**: Total = days × daily_rate
     /// - **No Discounts**: Same rate regardless of duration
     /// - **No Hidden Fees**: Transparent, predictable pricing
@@ -548,8 +565,8 @@ impl ExtensionManager {
     /// - **Validation**: Verify user has sufficient balance before extension
     /// - **Analytics**: Track fee revenue and extension economics
     /// - **Planning**: Help users optimize extension timing and duration
-    pub fn calculate_extension_fee(additional_days: u32) -> i128 {
-        (additional_days as i128) * EXTENSION_FEE_PER_DAY
+    pub fn calculate_extension_fee(additional_days: u32, config: &ExtensionConfig) -> i128 {
+        (additional_days as i128) * config.fee_per_day
     }
 }
 
@@ -565,12 +582,14 @@ impl ExtensionValidator {
         market_id: &Symbol,
         additional_days: u32,
     ) -> Result<(), Error> {
+        let config = ConfigManager::get_market_runtime_config(env, market_id).extension;
+
         // Validate additional days
-        if additional_days < MIN_EXTENSION_DAYS {
+        if additional_days < config.min_extension_days {
             return Err(Error::InvalidExtensionDays);
         }
 
-        if additional_days > MAX_EXTENSION_DAYS {
+        if additional_days > config.max_extension_days {
             return Err(Error::ExtensionDaysExceeded);
         }
 
@@ -598,6 +617,7 @@ impl ExtensionValidator {
         additional_days: u32,
     ) -> Result<(), Error> {
         let market = MarketStateManager::get_market(env, market_id)?;
+        let config = ConfigManager::get_market_runtime_config(env, market_id).extension;
 
         // Check total extension days limit
         if market.total_extension_days + additional_days > market.max_extension_days {
@@ -605,7 +625,7 @@ impl ExtensionValidator {
         }
 
         // Check number of extensions limit
-        if (market.extension_history.len() as usize) >= (MAX_TOTAL_EXTENSIONS as usize) {
+        if market.extension_history.len() >= config.max_total_extensions {
             return Err(Error::MarketExtensionNotAllowed);
         }
 
@@ -613,15 +633,19 @@ impl ExtensionValidator {
     }
 
     /// Check if admin can extend market
+    ///
+    /// Markets are extended by the primary admin or by admins whose role
+    /// grants the `ExtendMarket` permission, whoever created the market.
     pub fn can_extend_market(env: &Env, market_id: &Symbol, admin: &Address) -> Result<(), Error> {
-        let market = MarketStateManager::get_market(env, market_id)?;
+        MarketStateManager::get_market(env, market_id)?;
 
-        // Check if caller is market admin
-        if market.admin != *admin {
-            return Err(Error::Unauthorized);
+        let stored_admin: Option<Address> =
+            env.storage().persistent().get(&Symbol::new(env, "Admin"));
+        if stored_admin.as_ref() == Some(admin) {
+            return Ok(());
         }
 
-        Ok(())
+        AdminAccessControl::validate_permission(env, admin, &AdminPermission::ExtendMarket)
     }
 }
 
@@ -634,10 +658,11 @@ impl ExtensionUtils {
     /// Handle extension fees
     pub fn handle_extension_fees(
         env: &Env,
-        _market_id: &Symbol,
+        market_id: &Symbol,
         additional_days: u32,
     ) -> Result<i128, Error> {
-        let fee_amount = ExtensionManager::calculate_extension_fee(additional_days);
+        let config = ConfigManager::get_market_runtime_config(env, market_id).extension;
+        let fee_amount = ExtensionManager::calculate_extension_fee(additional_days, &config);
 
         // Get token client for fee collection
         let _token_client = MarketUtils::get_token_client(env)?;
@@ -660,26 +685,7 @@ impl ExtensionUtils {
         additional_days: u32,
         admin: &Address,
     ) {
-        // In Soroban, events are emitted using the env.events() API
-        // For now, we'll use a simple approach with storage
-        let event_key = symbol_short!("ext_event");
-        let event_data = ExtensionEvent {
-            market_id: market_id.clone(),
-            additional_days,
-            admin: admin.clone(),
-            timestamp: env.ledger().timestamp(),
-        };
-
-        env.storage().persistent().set(&event_key, &event_data);
-    }
-
-    /// Get extension events
-    pub fn get_extension_events(env: &Env) -> Vec<ExtensionEvent> {
-        let event_key = symbol_short!("ext_event");
-        match env.storage().persistent().get(&event_key) {
-            Some(event) => vec![env, event],
-            None => vec![env],
-        }
+        EventEmitter::emit_market_extended(env, market_id, admin, additional_days);
     }
 }
 
@@ -763,28 +769,29 @@ mod tests {
                     .unwrap_err(),
                 Error::InvalidExtensionDays
             );
-        });
 
-        assert_eq!(
-            ExtensionValidator::validate_extension_conditions(&env, &symbol_short!("test"), 31)
-                .unwrap_err(),
-            Error::ExtensionDaysExceeded
-        );
+            assert_eq!(
+                ExtensionValidator::validate_extension_conditions(&env, &symbol_short!("test"), 31)
+                    .unwrap_err(),
+                Error::ExtensionDaysExceeded
+            );
+        });
     }
 
     #[test]
     fn test_extension_fee_calculation() {
+        let config = ConfigManager::get_default_extension_config();
         assert_eq!(
-            ExtensionManager::calculate_extension_fee(1),
-            EXTENSION_FEE_PER_DAY
+            ExtensionManager::calculate_extension_fee(1, &config),
+            config.fee_per_day
         );
         assert_eq!(
-            ExtensionManager::calculate_extension_fee(5),
-            5 * EXTENSION_FEE_PER_DAY
+            ExtensionManager::calculate_extension_fee(5, &config),
+            5 * config.fee_per_day
         );
         assert_eq!(
-            ExtensionManager::calculate_extension_fee(30),
-            30 * EXTENSION_FEE_PER_DAY
+            ExtensionManager::calculate_extension_fee(30, &config),
+            30 * config.fee_per_day
         );
     }
 
@@ -796,7 +803,7 @@ mod tests {
             total_extension_days: 10,
             max_extension_days: 30,
             can_extend: true,
-            extension_fee_per_day: EXTENSION_FEE_PER_DAY,
+            extension_fee_per_day: crate::config::EXTENSION_FEE_PER_DAY,
         };
 
         assert_eq!(stats.total_extensions, 2);
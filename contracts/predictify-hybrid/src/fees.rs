@@ -1,8 +1,14 @@
 use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, Map, String, Symbol, Vec};
 use alloc::format;
 
+use crate::config::{
+    ConfigManager, ContractConfig, PendingConfigUpdate, DEFAULT_PLATFORM_FEE_PERCENTAGE,
+};
+use crate::disputes::DisputeUtils;
 use crate::errors::Error;
-use crate::markets::{MarketStateManager, MarketUtils};
+use crate::markets::{MarketAnalytics, MarketStateManager, MarketUtils};
+use crate::referrals::ReferralManager;
+use crate::treasury::FeeTreasury;
 use crate::types::Market;
 
 /// Fee management system for Predictify Hybrid contract
@@ -14,25 +20,6 @@ use crate::types::Market;
 /// - Fee configuration management
 /// - Fee safety checks and validation
 
-// ===== FEE CONSTANTS =====
-// Note: These constants are now managed by the config module
-// Use ConfigManager::get_fee_config() to get current values
-
-/// Platform fee percentage (2%)
-pub const PLATFORM_FEE_PERCENTAGE: i128 = crate::config::DEFAULT_PLATFORM_FEE_PERCENTAGE;
-
-/// Market creation fee (1 XLM = 10,000,000 stroops)
-pub const MARKET_CREATION_FEE: i128 = crate::config::DEFAULT_MARKET_CREATION_FEE;
-
-/// Minimum fee amount (0.1 XLM)
-pub const MIN_FEE_AMOUNT: i128 = crate::config::MIN_FEE_AMOUNT;
-
-/// Maximum fee amount (100 XLM)
-pub const MAX_FEE_AMOUNT: i128 = crate::config::MAX_FEE_AMOUNT;
-
-/// Fee collection threshold (minimum amount before fees can be collected)
-pub const FEE_COLLECTION_THRESHOLD: i128 = crate::config::FEE_COLLECTION_THRESHOLD; // 10 XLM
-
 // ===== DYNAMIC FEE CONSTANTS =====
 
 /// Maximum fee percentage (5%)
@@ -51,6 +38,9 @@ pub const MARKET_SIZE_SMALL: i128 = 100_000_000; // 10 XLM
 pub const MARKET_SIZE_MEDIUM: i128 = 1_000_000_000; // 100 XLM
 pub const MARKET_SIZE_LARGE: i128 = 10_000_000_000; // 1000 XLM
 
+/// Maximum number of entries kept in a market's fee history
+pub const MAX_FEE_HISTORY_ENTRIES: u32 = 50;
+
 // ===== FEE TYPES =====
 
 /// Comprehensive fee configuration structure for market operations.
@@ -82,6 +72,13 @@ pub const MARKET_SIZE_LARGE: i128 = 10_000_000_000; // 1000 XLM
 ///     max_fee_amount: 1_000_000_000, // 100 XLM maximum
 ///     collection_threshold: 100_000_000, // 10 XLM threshold
 ///     fees_enabled: true,
+///     creator_fee_percentage: 20, // 20% of the fee to the creator
+///     referral_fee_percentage: 10, // 10% of a claimant's fee to their referrer
+///     invalid_market_fee_percentage: 0, // Invalid markets refund stakes in full
+///     discount_volume_medium: 1_000_000_000, // 10% off from 100 XLM staked
+///     discount_volume_high: 10_000_000_000, // 25% off from 1000 XLM staked
+///     discount_percentage_medium: 10,
+///     discount_percentage_high: 25,
 /// };
 ///
 /// // Calculate platform fee for 50 XLM stake
@@ -126,6 +123,20 @@ pub struct FeeConfig {
     pub collection_threshold: i128,
     /// Whether fees are enabled
     pub fees_enabled: bool,
+    /// Share of the platform fee paid to the market creator (percentage)
+    pub creator_fee_percentage: i128,
+    /// Share of a claimant's platform fee credited to their referrer (percentage)
+    pub referral_fee_percentage: i128,
+    /// Share of each stake kept when a market resolves as invalid (percentage)
+    pub invalid_market_fee_percentage: i128,
+    /// Cumulative staked volume that earns the medium fee discount
+    pub discount_volume_medium: i128,
+    /// Cumulative staked volume that earns the high fee discount
+    pub discount_volume_high: i128,
+    /// Share of the platform fee waived in the medium discount tier (percentage)
+    pub discount_percentage_medium: i128,
+    /// Share of the platform fee waived in the high discount tier (percentage)
+    pub discount_percentage_high: i128,
 }
 
 /// Dynamic fee tier configuration based on market size
@@ -167,6 +178,8 @@ pub struct FeeTier {
     pub fee_percentage: i128,
     /// Tier name/description
     pub tier_name: String,
+    /// Creator fee share multiplier for this tier (100 = no change)
+    pub creator_fee_multiplier: i128,
 }
 
 /// Activity-based fee adjustment configuration
@@ -283,6 +296,7 @@ pub struct FeeCalculationFactors {
 ///     reason: String::from_str(&env, "Activity level increased"),
 ///     admin: Address::generate(&env),
 ///     calculation_factors: factors, // FeeCalculationFactors
+///     creator_fee: 0,
 /// };
 /// ```
 #[contracttype]
@@ -298,10 +312,12 @@ pub struct FeeHistory {
     pub new_fee_percentage: i128,
     /// Reason for the fee change
     pub reason: String,
-    /// Admin who made the change
+    /// Admin who made the change, or the claimant for claim discounts
     pub admin: Address,
     /// Calculation factors used
     pub calculation_factors: FeeCalculationFactors,
+    /// Share of the fee paid to the market creator
+    pub creator_fee: i128,
 }
 
 /// Record of a completed fee collection operation from a market.
@@ -405,6 +421,8 @@ pub struct FeeCollection {
 ///     average_fee_per_market: 40_000_000, // 4 XLM average
 ///     collection_history: Vec::new(&env), // Historical records
 ///     fee_distribution: Map::new(&env), // Distribution by market size
+///     total_fees_withdrawn: 600_000_000, // 60 XLM withdrawn by recipients
+///     treasury_balance: 400_000_000, // 40 XLM still held by the treasury
 /// };
 ///
 /// // Display analytics summary
@@ -468,6 +486,10 @@ pub struct FeeAnalytics {
     pub collection_history: Vec<FeeCollection>,
     /// Fee distribution by market size
     pub fee_distribution: Map<String, i128>,
+    /// Fees withdrawn from the treasury by recipients
+    pub total_fees_withdrawn: i128,
+    /// Fees accrued to the treasury and not yet withdrawn
+    pub treasury_balance: i128,
 }
 
 /// Result of fee validation operations with detailed feedback and suggestions.
@@ -504,7 +526,7 @@ pub struct FeeAnalytics {
 ///         total_staked: 1_000_000_000, // 100 XLM
 ///         fee_percentage: 200, // 2%
 ///         fee_amount: 20_000_000, // 2 XLM
-///         platform_fee: 20_000_000, // 2 XLM
+///         platform_fee: 16_000_000, // 1.6 XLM after the creator's share
 ///         user_payout_amount: 980_000_000, // 98 XLM
 ///     },
 /// };
@@ -582,7 +604,7 @@ pub struct FeeValidationResult {
 ///     total_staked: 1_000_000_000, // 100 XLM total stake
 ///     fee_percentage: 200, // 2.00% fee rate
 ///     fee_amount: 20_000_000, // 2 XLM fee
-///     platform_fee: 20_000_000, // 2 XLM platform fee
+///     platform_fee: 16_000_000, // 1.6 XLM after the creator's 0.4 XLM
 ///     user_payout_amount: 980_000_000, // 98 XLM after fees
 /// };
 ///
@@ -627,7 +649,7 @@ pub struct FeeBreakdown {
     pub fee_percentage: i128,
     /// Calculated fee amount
     pub fee_amount: i128,
-    /// Platform fee
+    /// Platform's share of the fee, after the market creator's share
     pub platform_fee: i128,
     /// User payout amount (after fees)
     pub user_payout_amount: i128,
@@ -1027,6 +1049,19 @@ pub struct FeeManager;
 
 impl FeeManager {
     /// Collect platform fees from a market
+    ///
+    /// The fee stays in the contract and is accrued to the treasury. The
+    /// market creator's share is credited to the creator; the rest is split
+    /// between the distribution recipients. Both can withdraw their share.
+    ///
+    /// The fee share of winners who have not claimed yet is held back, since
+    /// their discounts and referral credits are only known when they claim;
+    /// each later claim accrues what remains of its share.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::NoFeesToCollect` - Discounts, referral credits, dispute
+    ///   rewards and the held-back shares exceed the market's fee
     pub fn collect_fees(env: &Env, admin: Address, market_id: Symbol) -> Result<i128, Error> {
         // Require authentication from the admin
         admin.require_auth();
@@ -1036,19 +1071,47 @@ impl FeeManager {
 
         // Get and validate market
         let mut market = MarketStateManager::get_market(env, &market_id)?;
-        FeeValidator::validate_market_for_fee_collection(&market)?;
-
-        // Calculate fee amount
-        let fee_amount = FeeCalculator::calculate_platform_fee(&market)?;
+        let fee_config = FeeConfigManager::get_market_fee_config(env, &market_id);
+        FeeValidator::validate_market_for_fee_collection(&market, &fee_config)?;
+
+        // Collect the fee at the rate frozen when the market resolved, less
+        // the discounts already paid out to claimants, the shares already
+        // credited to their referrers, any dispute rewards it funded and the
+        // shares of the claims still to come. The fee was already withheld
+        // from payouts, so the configured fee bounds no longer apply.
+        let breakdown = Self::freeze_claim_fee(env, &market_id)?;
+        let reserve = Self::calculate_unclaimed_fee(&market, &breakdown)?;
+        let fee_amount = breakdown.fee_amount
+            - Self::get_claim_rebates(env, &market_id)
+            - ReferralManager::get_market_credits(env, &market_id)
+            - DisputeUtils::get_protocol_dispute_reward(env, &market_id)
+            - reserve;
+        if fee_amount < 0 {
+            return Err(Error::NoFeesToCollect);
+        }
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("fee_rsv"), market_id.clone()), &reserve);
 
-        // Validate fee amount
-        FeeValidator::validate_fee_amount(fee_amount)?;
+        // Carve the creator's share out of the platform fee
+        let creator_fee =
+            FeeCalculator::calculate_creator_fee(env, &market, &fee_config, fee_amount)?;
 
-        // Transfer fees to admin
-        FeeUtils::transfer_fees_to_admin(env, &admin, fee_amount)?;
+        // Accrue fees to the treasury. The market admin is the creator.
+        FeeTreasury::accrue(env, &market_id, fee_amount - creator_fee)?;
+        if creator_fee > 0 {
+            FeeTreasury::accrue_to(env, &market_id, &market.admin, creator_fee)?;
+        }
 
         // Record fee collection
         FeeTracker::record_fee_collection(env, &market_id, fee_amount, &admin)?;
+        FeeTracker::record_fee_history(
+            env,
+            &market_id,
+            &admin,
+            breakdown.fee_percentage,
+            creator_fee,
+        )?;
 
         // Mark fees as collected
         MarketStateManager::mark_fees_collected(&mut market, Some(&market_id));
@@ -1059,17 +1122,20 @@ impl FeeManager {
 
     /// Process market creation fee
     pub fn process_creation_fee(env: &Env, admin: &Address) -> Result<(), Error> {
+        let fee_config = FeeConfigManager::get_runtime_fee_config(env);
+        let creation_fee = fee_config.creation_fee;
+
         // Validate creation fee
-        FeeValidator::validate_creation_fee(MARKET_CREATION_FEE)?;
+        FeeValidator::validate_creation_fee(creation_fee, &fee_config)?;
 
         // Get token client
         let token_client = MarketUtils::get_token_client(env)?;
 
         // Transfer creation fee from admin to contract
-        token_client.transfer(admin, &env.current_contract_address(), &MARKET_CREATION_FEE);
+        token_client.transfer(admin, &env.current_contract_address(), &creation_fee);
 
         // Record creation fee
-        FeeTracker::record_creation_fee(env, admin, MARKET_CREATION_FEE)?;
+        FeeTracker::record_creation_fee(env, admin, creation_fee)?;
 
         Ok(())
     }
@@ -1079,12 +1145,15 @@ impl FeeManager {
         FeeAnalytics::calculate_analytics(env)
     }
 
-    /// Update fee configuration (admin only)
+    /// Queue a fee configuration update (admin only)
+    ///
+    /// Fees are part of the contract configuration, so the update is queued
+    /// behind the configuration timelock like any other configuration change.
     pub fn update_fee_config(
         env: &Env,
         admin: Address,
         new_config: FeeConfig,
-    ) -> Result<FeeConfig, Error> {
+    ) -> Result<PendingConfigUpdate, Error> {
         // Require authentication from the admin
         admin.require_auth();
 
@@ -1094,18 +1163,20 @@ impl FeeManager {
         // Validate new configuration
         FeeValidator::validate_fee_config(&new_config)?;
 
-        // Store new configuration
-        FeeConfigManager::store_fee_config(env, &new_config)?;
+        // Queue the contract configuration carrying the new fees
+        let mut config = ConfigManager::get_runtime_config(env);
+        FeeConfigManager::apply_to_contract_config(&new_config, &mut config);
+        let pending = ConfigManager::update_config(env, &admin, &config)?;
 
         // Record configuration change
         FeeTracker::record_config_change(env, &admin, &new_config)?;
 
-        Ok(new_config)
+        Ok(pending)
     }
 
     /// Get current fee configuration
     pub fn get_fee_config(env: &Env) -> Result<FeeConfig, Error> {
-        FeeConfigManager::get_fee_config(env)
+        Ok(FeeConfigManager::get_runtime_fee_config(env))
     }
 
     /// Validate fee calculation for a market
@@ -1114,7 +1185,8 @@ impl FeeManager {
         market_id: &Symbol,
     ) -> Result<FeeValidationResult, Error> {
         let market = MarketStateManager::get_market(env, market_id)?;
-        FeeValidator::validate_market_fees(&market)
+        let fee_config = FeeConfigManager::get_market_fee_config(env, market_id);
+        FeeValidator::validate_market_fees(env, &market, &fee_config)
     }
 
     /// Update fee structure with new fee tiers
@@ -1146,14 +1218,173 @@ impl FeeManager {
         Ok(())
     }
 
+    /// Freeze the fee rate that claims on a market pay.
+    ///
+    /// Called when the market resolves so every claimant pays the same
+    /// dynamic rate. Later calls return the stored breakdown unchanged.
+    pub fn freeze_claim_fee(env: &Env, market_id: &Symbol) -> Result<FeeBreakdown, Error> {
+        if let Some(breakdown) = Self::get_claim_fee_breakdown(env, market_id) {
+            return Ok(breakdown);
+        }
+
+        let breakdown = Self::preview_claim_fee(env, market_id)?;
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("fee_brk"), market_id.clone()), &breakdown);
+        Ok(breakdown)
+    }
+
+    /// Get the fee breakdown claims on a market pay without freezing it.
+    ///
+    /// Returns the frozen breakdown if there is one and otherwise the
+    /// breakdown `freeze_claim_fee` would store now. Used by queries, which
+    /// must not write to storage.
+    pub fn preview_claim_fee(env: &Env, market_id: &Symbol) -> Result<FeeBreakdown, Error> {
+        if let Some(breakdown) = Self::get_claim_fee_breakdown(env, market_id) {
+            return Ok(breakdown);
+        }
+
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let fee_config = FeeConfigManager::get_market_fee_config(env, market_id);
+        let fee_percentage = FeeCalculator::calculate_claim_fee_rate(env, market_id)?;
+        let fee_amount = (market.total_staked * fee_percentage) / 10_000;
+        let creator_fee =
+            FeeCalculator::calculate_creator_fee(env, &market, &fee_config, fee_amount)?;
+        Ok(FeeBreakdown {
+            total_staked: market.total_staked,
+            fee_percentage,
+            fee_amount,
+            platform_fee: fee_amount - creator_fee,
+            user_payout_amount: market.total_staked - fee_amount,
+        })
+    }
+
+    /// Get the fee breakdown frozen for a resolved market, if any
+    pub fn get_claim_fee_breakdown(env: &Env, market_id: &Symbol) -> Option<FeeBreakdown> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("fee_brk"), market_id.clone()))
+    }
+
+    /// Book the fee a claim pays after the market's fees were collected.
+    ///
+    /// Releases the claimant's `fee_share` from the fee `collect_fees` held
+    /// back and accrues whatever the claimant's discount and referrer did not
+    /// take (`committed`), split between the creator and the distribution
+    /// recipients like a collected fee.
+    pub fn release_claim_reserve(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        fee_share: i128,
+        committed: i128,
+    ) -> Result<(), Error> {
+        let reserve = Self::get_claim_reserve(env, market_id);
+        env.storage().persistent().set(
+            &(symbol_short!("fee_rsv"), market_id.clone()),
+            &(reserve - fee_share).max(0),
+        );
+
+        let amount = fee_share - committed;
+        if amount <= 0 {
+            return Ok(());
+        }
+        let fee_config = FeeConfigManager::get_market_fee_config(env, market_id);
+        let creator_fee = FeeCalculator::calculate_creator_fee(env, market, &fee_config, amount)?;
+        FeeTreasury::accrue(env, market_id, amount - creator_fee)?;
+        if creator_fee > 0 {
+            FeeTreasury::accrue_to(env, market_id, &market.admin, creator_fee)?;
+        }
+        Ok(())
+    }
+
+    /// Get the fee held back from a collected market for claims still to come
+    pub fn get_claim_reserve(env: &Env, market_id: &Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("fee_rsv"), market_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Sum the fee shares of the winners who have not claimed yet
+    fn calculate_unclaimed_fee(market: &Market, breakdown: &FeeBreakdown) -> Result<i128, Error> {
+        let winning_outcome = match &market.winning_outcome {
+            Some(outcome) => outcome,
+            None => return Ok(0),
+        };
+        let stats = MarketAnalytics::calculate_winning_stats(market, winning_outcome);
+
+        let mut unclaimed = 0;
+        for (user, outcome) in market.votes.iter() {
+            if &outcome != winning_outcome || market.claimed.get(user.clone()).unwrap_or(false) {
+                continue;
+            }
+            let stake = market.stakes.get(user).unwrap_or(0);
+            let payout = |discount| {
+                FeeCalculator::calculate_user_payout_after_fees(
+                    stake,
+                    stats.winning_total,
+                    stats.total_pool,
+                    breakdown,
+                    discount,
+                )
+            };
+            unclaimed += payout(100)? - payout(0)?;
+        }
+        Ok(unclaimed)
+    }
+
+    /// Get the fee discounts paid out to claimants of a market
+    pub fn get_claim_rebates(env: &Env, market_id: &Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("fee_rbt"), market_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Exempt an address from platform fees, or lift its exemption (admin only)
+    ///
+    /// Intended for market makers and other partners who should claim
+    /// without paying the platform fee.
+    pub fn set_fee_exemption(
+        env: &Env,
+        admin: Address,
+        user: Address,
+        exempt: bool,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        FeeValidator::validate_admin_permissions(env, &admin)?;
+
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("fee_exmp"), user), &exempt);
+        Ok(())
+    }
+
+    /// Check whether an address is exempt from platform fees
+    pub fn is_fee_exempt(env: &Env, user: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("fee_exmp"), user.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Get a user's cumulative staked volume across all markets
+    pub fn get_user_volume(env: &Env, user: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("usr_vol"), user.clone()))
+            .unwrap_or(0)
+    }
+
     /// Get fee history for a specific market
     pub fn get_fee_history(env: &Env, market_id: Symbol) -> Result<Vec<FeeHistory>, Error> {
-        let history_key = Symbol::new(env, "fee_history");
+        let history_key = (Symbol::new(env, "fee_history"), market_id);
 
         match env
             .storage()
             .persistent()
-            .get::<Symbol, Vec<FeeHistory>>(&history_key)
+            .get::<_, Vec<FeeHistory>>(&history_key)
         {
             Some(history) => Ok(history),
             None => Ok(Vec::new(env)),
@@ -1168,6 +1399,7 @@ impl FeeManager {
     ) -> Result<FeeValidationResult, Error> {
         // Get market
         let market = MarketStateManager::get_market(env, &market_id)?;
+        let fee_config = FeeConfigManager::get_market_fee_config(env, &market_id);
 
         // Perform comprehensive validation
         let mut errors = Vec::new(env);
@@ -1186,24 +1418,27 @@ impl FeeManager {
         }
 
         // Check fee amount validity
-        if fee_amount < MIN_FEE_AMOUNT {
+        if fee_amount < fee_config.min_fee_amount {
             errors.push_back(String::from_str(env, "Fee amount below minimum"));
             is_valid = false;
         }
 
-        if fee_amount > MAX_FEE_AMOUNT {
+        if fee_amount > fee_config.max_fee_amount {
             errors.push_back(String::from_str(env, "Fee amount exceeds maximum"));
             is_valid = false;
         }
 
         // Check if market has sufficient stakes
-        if market.total_staked < FEE_COLLECTION_THRESHOLD {
-            errors.push_back(String::from_str(env, "Insufficient stakes for fee collection"));
+        if market.total_staked < fee_config.collection_threshold {
+            errors.push_back(String::from_str(
+                env,
+                "Insufficient stakes for fee collection",
+            ));
             is_valid = false;
         }
 
         // Calculate fee breakdown
-        let breakdown = FeeCalculator::calculate_fee_breakdown(&market)?;
+        let breakdown = FeeCalculator::calculate_fee_breakdown(env, &market, &fee_config)?;
         let suggested_amount = breakdown.fee_amount;
 
         // Validate calculated fee matches provided fee
@@ -1227,7 +1462,8 @@ impl FeeManager {
         distribution: Map<Address, i128>,
     ) -> Result<FeeDistribution, Error> {
         // Validate distribution
-        FeeValidator::validate_fee_distribution(&distribution)?;
+        let fee_config = FeeConfigManager::get_market_fee_config(env, &market_id);
+        FeeValidator::validate_fee_distribution(&distribution, &fee_config)?;
 
         // Calculate total amount
         let mut total_amount: i128 = 0;
@@ -1274,6 +1510,7 @@ impl FeeManager {
     ) -> Result<FeeSafetyValidation, Error> {
         // Get market
         let market = MarketStateManager::get_market(env, &market_id)?;
+        let fee_config = FeeConfigManager::get_market_fee_config(env, &market_id);
 
         // Perform safety checks
         let mut safety_checks = Vec::new(env);
@@ -1302,7 +1539,7 @@ impl FeeManager {
         }
 
         // Check stake threshold
-        if market.total_staked >= FEE_COLLECTION_THRESHOLD {
+        if market.total_staked >= fee_config.collection_threshold {
             safety_checks.push_back(String::from_str(env, "Stake threshold: PASSED"));
         } else {
             safety_checks.push_back(String::from_str(env, "Stake threshold: FAILED"));
@@ -1443,11 +1680,12 @@ impl FeeManager {
 
         // Perform safety validation
         let safety_validation = Self::verify_fee_collection_safety(env, market_id.clone())?;
+        let fee_config = FeeConfigManager::get_market_fee_config(env, &market_id);
 
         // Determine eligibility
         let is_eligible = market.winning_outcome.is_some()
             && !market.fee_collected
-            && market.total_staked >= FEE_COLLECTION_THRESHOLD;
+            && market.total_staked >= fee_config.collection_threshold;
 
         // Generate warnings and recommendations
         let mut warnings = Vec::new(env);
@@ -1463,7 +1701,7 @@ impl FeeManager {
             recommendations.push_back(String::from_str(env, "No further action needed"));
         }
 
-        if market.total_staked < FEE_COLLECTION_THRESHOLD {
+        if market.total_staked < fee_config.collection_threshold {
             warnings.push_back(String::from_str(env, "Insufficient stakes for fee collection"));
             recommendations.push_back(String::from_str(env, "Wait for more stakes"));
         }
@@ -1502,6 +1740,8 @@ impl FeeManager {
         env: &Env,
         distribution: &Map<Address, i128>,
     ) -> Result<bool, Error> {
+        let max_fee_amount = FeeConfigManager::get_runtime_fee_config(env).max_fee_amount;
+
         // Check if distribution is empty
         if distribution.is_empty() {
             return Err(Error::InvalidInput);
@@ -1515,7 +1755,7 @@ impl FeeManager {
             }
 
             // Check for reasonable amount limits
-            if amount > MAX_FEE_AMOUNT {
+            if amount > max_fee_amount {
                 return Err(Error::InvalidInput);
             }
         }
@@ -1531,7 +1771,7 @@ impl FeeManager {
         }
 
         // Check for reasonable total limits
-        if total_amount > MAX_FEE_AMOUNT * 10 {
+        if total_amount > max_fee_amount * 10 {
             return Err(Error::InvalidInput);
         }
 
@@ -1597,8 +1837,8 @@ impl FeeManager {
             return Err(Error::InvalidInput);
         }
 
-        // Transfer fees to recipients
-        Self::transfer_fees_to_recipients(env, &distribution)?;
+        // Reassign the admin's treasury share to the recipients
+        Self::transfer_fees_to_recipients(env, &admin, &distribution)?;
 
         // Store execution record
         Self::store_distribution_execution(env, &execution)?;
@@ -1788,7 +2028,8 @@ impl FeeManager {
         }
 
         // Check for reasonable limits
-        if total_amount > MAX_FEE_AMOUNT * 10 {
+        let max_fee_amount = FeeConfigManager::get_runtime_fee_config(env).max_fee_amount;
+        if total_amount > max_fee_amount * 10 {
             return Err(Error::InvalidInput);
         }
 
@@ -1797,18 +2038,14 @@ impl FeeManager {
 
     // ===== PRIVATE HELPER METHODS =====
 
-    /// Transfer fees to recipients
+    /// Transfer fees to recipients within the treasury
     fn transfer_fees_to_recipients(
         env: &Env,
+        admin: &Address,
         distribution: &Map<Address, i128>,
     ) -> Result<(), Error> {
-        let token_client = MarketUtils::get_token_client(env)?;
-
-        for (recipient, amount) in distribution.iter() {
-            token_client.transfer(&env.current_contract_address(), &recipient, &amount);
-        }
-
-        Ok(())
+        let token = FeeTreasury::get_token(env)?;
+        FeeTreasury::reassign(env, &token, admin, distribution)
     }
 
     /// Store distribution execution
@@ -1864,42 +2101,76 @@ pub struct FeeCalculator;
 
 impl FeeCalculator {
     /// Calculate platform fee for a market
-    pub fn calculate_platform_fee(market: &Market) -> Result<i128, Error> {
+    ///
+    /// This is the total fee charged to the market, including the market
+    /// creator's share (see [`Self::calculate_creator_fee`]).
+    pub fn calculate_platform_fee(market: &Market, config: &FeeConfig) -> Result<i128, Error> {
         if market.total_staked == 0 {
             return Err(Error::NoFeesToCollect);
         }
 
-        let fee_amount = (market.total_staked * PLATFORM_FEE_PERCENTAGE) / 100;
+        let fee_amount = (market.total_staked * config.platform_fee_percentage) / 100;
 
-        if fee_amount < MIN_FEE_AMOUNT {
+        if fee_amount < config.min_fee_amount {
             return Err(Error::InsufficientStake);
         }
 
         Ok(fee_amount)
     }
 
+    /// Calculate the market creator's share of a platform fee
+    ///
+    /// The configured creator percentage is scaled by the market's size tier
+    /// and never exceeds the platform fee itself.
+    pub fn calculate_creator_fee(
+        env: &Env,
+        market: &Market,
+        config: &FeeConfig,
+        platform_fee: i128,
+    ) -> Result<i128, Error> {
+        let tier = Self::get_fee_tier_by_market_size(env, market.total_staked)?;
+        let creator_fee =
+            (platform_fee * config.creator_fee_percentage * tier.creator_fee_multiplier) / 10_000;
+
+        Ok(creator_fee.min(platform_fee))
+    }
+
     /// Calculate user payout after fees
+    ///
+    /// Uses the rate frozen in the market's `FeeBreakdown` (basis points),
+    /// reduced by the user's `discount_percentage`.
     pub fn calculate_user_payout_after_fees(
         user_stake: i128,
         winning_total: i128,
         total_pool: i128,
+        breakdown: &FeeBreakdown,
+        discount_percentage: i128,
     ) -> Result<i128, Error> {
         if winning_total == 0 {
             return Err(Error::NothingToClaim);
         }
 
-        let user_share = (user_stake * (100 - PLATFORM_FEE_PERCENTAGE)) / 100;
+        let fee_percentage = Self::apply_fee_discount(breakdown.fee_percentage, discount_percentage);
+        let user_share = (user_stake * (10_000 - fee_percentage)) / 10_000;
         let payout = (user_share * total_pool) / winning_total;
 
         Ok(payout)
     }
 
     /// Calculate fee breakdown for a market
-    pub fn calculate_fee_breakdown(market: &Market) -> Result<FeeBreakdown, Error> {
+    ///
+    /// `platform_fee` is what remains of the fee once the market creator's
+    /// share has been carved out.
+    pub fn calculate_fee_breakdown(
+        env: &Env,
+        market: &Market,
+        config: &FeeConfig,
+    ) -> Result<FeeBreakdown, Error> {
         let total_staked = market.total_staked;
-        let fee_percentage = PLATFORM_FEE_PERCENTAGE;
-        let fee_amount = Self::calculate_platform_fee(market)?;
-        let platform_fee = fee_amount;
+        let fee_percentage = config.platform_fee_percentage;
+        let fee_amount = Self::calculate_platform_fee(market, config)?;
+        let platform_fee =
+            fee_amount - Self::calculate_creator_fee(env, market, config, fee_amount)?;
         let user_payout_amount = total_staked - fee_amount;
 
         Ok(FeeBreakdown {
@@ -1912,8 +2183,8 @@ impl FeeCalculator {
     }
 
     /// Calculate dynamic fee based on market characteristics
-    pub fn calculate_dynamic_fee(market: &Market) -> Result<i128, Error> {
-        let base_fee = Self::calculate_platform_fee(market)?;
+    pub fn calculate_dynamic_fee(market: &Market, config: &FeeConfig) -> Result<i128, Error> {
+        let base_fee = Self::calculate_platform_fee(market, config)?;
 
         // Adjust fee based on market size
         let size_multiplier = if market.total_staked > 1_000_000_000 {
@@ -1927,8 +2198,8 @@ impl FeeCalculator {
         let adjusted_fee = (base_fee * size_multiplier) / 100;
 
         // Ensure minimum fee
-        if adjusted_fee < MIN_FEE_AMOUNT {
-            Ok(MIN_FEE_AMOUNT)
+        if adjusted_fee < config.min_fee_amount {
+            Ok(config.min_fee_amount)
         } else {
             Ok(adjusted_fee)
         }
@@ -1937,7 +2208,53 @@ impl FeeCalculator {
     /// Calculate dynamic fee based on market size and activity
     pub fn calculate_dynamic_fee_by_market_id(env: &Env, market_id: Symbol) -> Result<i128, Error> {
         let market = crate::markets::MarketStateManager::get_market(env, &market_id)?;
-        Self::calculate_dynamic_fee(&market)
+        let fee_config = FeeConfigManager::get_market_fee_config(env, &market_id);
+        Self::calculate_dynamic_fee(&market, &fee_config)
+    }
+
+    /// Get the share of the platform fee waived for a user (percentage)
+    ///
+    /// Fee-exempt addresses pay nothing; everyone else earns a discount tier
+    /// from their cumulative staked volume, using the tiers in `config`.
+    pub fn get_user_fee_discount(env: &Env, user: &Address, config: &FeeConfig) -> i128 {
+        if FeeManager::is_fee_exempt(env, user) {
+            return 100;
+        }
+
+        let volume = FeeManager::get_user_volume(env, user);
+        if volume >= config.discount_volume_high {
+            config.discount_percentage_high
+        } else if volume >= config.discount_volume_medium {
+            config.discount_percentage_medium
+        } else {
+            0
+        }
+    }
+
+    /// Apply a percentage discount to a fee rate
+    pub fn apply_fee_discount(fee_percentage: i128, discount_percentage: i128) -> i128 {
+        (fee_percentage * (100 - discount_percentage)) / 100
+    }
+
+    /// Calculate the fee rate (basis points) that claims on a market pay
+    ///
+    /// Starts from the market's configured platform fee and applies the size
+    /// and activity multipliers, so a medium-sized market with little
+    /// activity pays exactly the configured rate.
+    pub fn calculate_claim_fee_rate(env: &Env, market_id: &Symbol) -> Result<i128, Error> {
+        let config = FeeConfigManager::get_market_fee_config(env, market_id);
+        if !config.fees_enabled {
+            return Ok(0);
+        }
+
+        let factors = Self::get_fee_calculation_factors(env, market_id.clone())?;
+        let rate = (config.platform_fee_percentage
+            * 100
+            * factors.size_multiplier
+            * factors.activity_multiplier)
+            / (100 * 100);
+
+        Ok(rate.min(10_000))
     }
 
     /// Get fee tier based on market size
@@ -1976,6 +2293,17 @@ impl FeeCalculator {
             0
         };
 
+        // Creators of higher-volume markets earn a larger slice of the fee
+        let creator_fee_multiplier = if tier_name == String::from_str(env, "Large") {
+            150
+        } else if tier_name == String::from_str(env, "Medium") {
+            100
+        } else if tier_name == String::from_str(env, "Small") {
+            75
+        } else {
+            50
+        };
+
         let max_size = if tier_name == String::from_str(env, "Large") {
             i128::MAX
         } else if tier_name == String::from_str(env, "Medium") {
@@ -1993,6 +2321,7 @@ impl FeeCalculator {
             max_size,
             fee_percentage,
             tier_name,
+            creator_fee_multiplier,
         })
     }
 
@@ -2003,7 +2332,8 @@ impl FeeCalculator {
         activity_level: u32,
     ) -> Result<i128, Error> {
         let market = crate::markets::MarketStateManager::get_market(env, &market_id)?;
-        let base_fee = Self::calculate_dynamic_fee(&market)?;
+        let fee_config = FeeConfigManager::get_market_fee_config(env, &market_id);
+        let base_fee = Self::calculate_dynamic_fee(&market, &fee_config)?;
 
         let activity_multiplier = if activity_level >= ACTIVITY_LEVEL_HIGH {
             120 // 20% increase for high activity
@@ -2018,10 +2348,10 @@ impl FeeCalculator {
         let adjusted_fee = (base_fee * activity_multiplier) / 100;
 
         // Ensure fee is within limits
-        if adjusted_fee < MIN_FEE_AMOUNT {
-            Ok(MIN_FEE_AMOUNT)
-        } else if adjusted_fee > MAX_FEE_AMOUNT {
-            Ok(MAX_FEE_AMOUNT)
+        if adjusted_fee < fee_config.min_fee_amount {
+            Ok(fee_config.min_fee_amount)
+        } else if adjusted_fee > fee_config.max_fee_amount {
+            Ok(fee_config.max_fee_amount)
         } else {
             Ok(adjusted_fee)
         }
@@ -2150,7 +2480,10 @@ impl FeeValidator {
     }
 
     /// Validate market for fee collection
-    pub fn validate_market_for_fee_collection(market: &Market) -> Result<(), Error> {
+    pub fn validate_market_for_fee_collection(
+        market: &Market,
+        config: &FeeConfig,
+    ) -> Result<(), Error> {
         // Check if market is resolved
         if market.winning_outcome.is_none() {
             return Err(Error::MarketNotResolved);
@@ -2162,7 +2495,7 @@ impl FeeValidator {
         }
 
         // Check if there are sufficient stakes
-        if market.total_staked < FEE_COLLECTION_THRESHOLD {
+        if market.total_staked < config.collection_threshold {
             return Err(Error::InsufficientStake);
         }
 
@@ -2170,12 +2503,12 @@ impl FeeValidator {
     }
 
     /// Validate fee amount
-    pub fn validate_fee_amount(fee_amount: i128) -> Result<(), Error> {
-        if fee_amount < MIN_FEE_AMOUNT {
+    pub fn validate_fee_amount(fee_amount: i128, config: &FeeConfig) -> Result<(), Error> {
+        if fee_amount < config.min_fee_amount {
             return Err(Error::InsufficientStake);
         }
 
-        if fee_amount > MAX_FEE_AMOUNT {
+        if fee_amount > config.max_fee_amount {
             return Err(Error::InvalidInput);
         }
 
@@ -2183,8 +2516,8 @@ impl FeeValidator {
     }
 
     /// Validate creation fee
-    pub fn validate_creation_fee(fee_amount: i128) -> Result<(), Error> {
-        if fee_amount != MARKET_CREATION_FEE {
+    pub fn validate_creation_fee(fee_amount: i128, config: &FeeConfig) -> Result<(), Error> {
+        if fee_amount != config.creation_fee {
             return Err(Error::InvalidInput);
         }
 
@@ -2213,11 +2546,39 @@ impl FeeValidator {
             return Err(Error::InvalidInput);
         }
 
+        if config.creator_fee_percentage < 0 || config.creator_fee_percentage > 100 {
+            return Err(Error::InvalidInput);
+        }
+
+        if config.referral_fee_percentage < 0 || config.referral_fee_percentage > 100 {
+            return Err(Error::InvalidInput);
+        }
+
+        if config.invalid_market_fee_percentage < 0 || config.invalid_market_fee_percentage > 100 {
+            return Err(Error::InvalidInput);
+        }
+
+        if config.discount_volume_medium <= 0
+            || config.discount_volume_high < config.discount_volume_medium
+        {
+            return Err(Error::InvalidInput);
+        }
+
+        if config.discount_percentage_medium < 0
+            || config.discount_percentage_high < config.discount_percentage_medium
+            || config.discount_percentage_high > 100
+        {
+            return Err(Error::InvalidInput);
+        }
+
         Ok(())
     }
 
     /// Validate fee distribution for accuracy and compliance
-    pub fn validate_fee_distribution(distribution: &Map<Address, i128>) -> Result<(), Error> {
+    pub fn validate_fee_distribution(
+        distribution: &Map<Address, i128>,
+        config: &FeeConfig,
+    ) -> Result<(), Error> {
         // Check if distribution is empty
         if distribution.is_empty() {
             return Err(Error::InvalidInput);
@@ -2231,7 +2592,7 @@ impl FeeValidator {
             }
 
             // Check for reasonable amount limits
-            if amount > MAX_FEE_AMOUNT {
+            if amount > config.max_fee_amount {
                 return Err(Error::InvalidInput);
             }
         }
@@ -2247,7 +2608,7 @@ impl FeeValidator {
         }
 
         // Check for reasonable total limits
-        if total_amount > MAX_FEE_AMOUNT * 10 {
+        if total_amount > config.max_fee_amount * 10 {
             return Err(Error::InvalidInput);
         }
 
@@ -2255,14 +2616,18 @@ impl FeeValidator {
     }
 
     /// Validate market fees
-    pub fn validate_market_fees(market: &Market) -> Result<FeeValidationResult, Error> {
-        let mut errors = Vec::new(&Env::default());
+    pub fn validate_market_fees(
+        env: &Env,
+        market: &Market,
+        config: &FeeConfig,
+    ) -> Result<FeeValidationResult, Error> {
+        let mut errors = Vec::new(env);
         let mut is_valid = true;
 
         // Check if market has sufficient stakes
-        if market.total_staked < FEE_COLLECTION_THRESHOLD {
+        if market.total_staked < config.collection_threshold {
             errors.push_back(String::from_str(
-                &Env::default(),
+                env,
                 "Insufficient stakes for fee collection",
             ));
             is_valid = false;
@@ -2270,12 +2635,12 @@ impl FeeValidator {
 
         // Check if fees already collected
         if market.fee_collected {
-            errors.push_back(String::from_str(&Env::default(), "Fees already collected"));
+            errors.push_back(String::from_str(env, "Fees already collected"));
             is_valid = false;
         }
 
         // Calculate fee breakdown
-        let breakdown = FeeCalculator::calculate_fee_breakdown(market)?;
+        let breakdown = FeeCalculator::calculate_fee_breakdown(env, market, config)?;
         let suggested_amount = breakdown.fee_amount;
 
         Ok(FeeValidationResult {
@@ -2301,19 +2666,23 @@ impl FeeUtils {
     }
 
     /// Get fee statistics for a market
-    pub fn get_market_fee_stats(market: &Market) -> Result<FeeBreakdown, Error> {
-        FeeCalculator::calculate_fee_breakdown(market)
+    pub fn get_market_fee_stats(
+        env: &Env,
+        market: &Market,
+        config: &FeeConfig,
+    ) -> Result<FeeBreakdown, Error> {
+        FeeCalculator::calculate_fee_breakdown(env, market, config)
     }
 
     /// Check if fees can be collected for a market
-    pub fn can_collect_fees(market: &Market) -> bool {
+    pub fn can_collect_fees(market: &Market, config: &FeeConfig) -> bool {
         market.winning_outcome.is_some()
             && !market.fee_collected
-            && market.total_staked >= FEE_COLLECTION_THRESHOLD
+            && market.total_staked >= config.collection_threshold
     }
 
     /// Get fee collection eligibility for a market
-    pub fn get_fee_eligibility(market: &Market) -> (bool, String) {
+    pub fn get_fee_eligibility(market: &Market, config: &FeeConfig) -> (bool, String) {
         if market.winning_outcome.is_none() {
             return (
                 false,
@@ -2328,7 +2697,7 @@ impl FeeUtils {
             );
         }
 
-        if market.total_staked < FEE_COLLECTION_THRESHOLD {
+        if market.total_staked < config.collection_threshold {
             return (
                 false,
                 String::from_str(&Env::default(), "Insufficient stakes"),
@@ -2360,7 +2729,8 @@ impl FeeTracker {
             amount,
             collected_by: admin.clone(),
             timestamp: env.ledger().timestamp(),
-            fee_percentage: PLATFORM_FEE_PERCENTAGE,
+            fee_percentage: FeeConfigManager::get_market_fee_config(env, market_id)
+                .platform_fee_percentage,
         };
 
         // Store in fee collection history
@@ -2385,6 +2755,101 @@ impl FeeTracker {
         Ok(())
     }
 
+    /// Record a fee calculation in the market's fee history
+    pub fn record_fee_history(
+        env: &Env,
+        market_id: &Symbol,
+        admin: &Address,
+        fee_percentage: i128,
+        creator_fee: i128,
+    ) -> Result<(), Error> {
+        let entry = FeeHistory {
+            market_id: market_id.clone(),
+            timestamp: env.ledger().timestamp(),
+            old_fee_percentage: fee_percentage,
+            new_fee_percentage: fee_percentage,
+            reason: String::from_str(env, "Fees collected"),
+            admin: admin.clone(),
+            calculation_factors: FeeCalculator::get_fee_calculation_factors(
+                env,
+                market_id.clone(),
+            )?,
+            creator_fee,
+        };
+
+        Self::push_fee_history(env, market_id, entry);
+        Ok(())
+    }
+
+    /// Record a fee discount granted on a claim
+    ///
+    /// Books `rebate` (the extra payout the discount produced) against the
+    /// market's fee and records the discounted rate in its fee history.
+    pub fn record_claim_discount(
+        env: &Env,
+        market_id: &Symbol,
+        user: &Address,
+        breakdown: &FeeBreakdown,
+        discount_percentage: i128,
+        rebate: i128,
+    ) -> Result<(), Error> {
+        let rebate_key = (symbol_short!("fee_rbt"), market_id.clone());
+        let rebates = FeeManager::get_claim_rebates(env, market_id);
+        env.storage()
+            .persistent()
+            .set(&rebate_key, &(rebates + rebate));
+
+        let reason = if discount_percentage >= 100 {
+            "Fee exemption"
+        } else {
+            "Volume discount"
+        };
+        let entry = FeeHistory {
+            market_id: market_id.clone(),
+            timestamp: env.ledger().timestamp(),
+            old_fee_percentage: breakdown.fee_percentage,
+            new_fee_percentage: FeeCalculator::apply_fee_discount(
+                breakdown.fee_percentage,
+                discount_percentage,
+            ),
+            reason: String::from_str(env, reason),
+            admin: user.clone(),
+            calculation_factors: FeeCalculator::get_fee_calculation_factors(
+                env,
+                market_id.clone(),
+            )?,
+            creator_fee: 0,
+        };
+
+        Self::push_fee_history(env, market_id, entry);
+        Ok(())
+    }
+
+    /// Add a user's stake to their cumulative volume
+    pub fn record_user_volume(env: &Env, user: &Address, amount: i128) {
+        let volume = FeeManager::get_user_volume(env, user);
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("usr_vol"), user.clone()), &(volume + amount));
+    }
+
+    /// Append to a market's fee history, dropping the oldest entries beyond
+    /// [`MAX_FEE_HISTORY_ENTRIES`]
+    fn push_fee_history(env: &Env, market_id: &Symbol, entry: FeeHistory) {
+        let history_key = (Symbol::new(env, "fee_history"), market_id.clone());
+        let mut history: Vec<FeeHistory> = env
+            .storage()
+            .persistent()
+            .get(&history_key)
+            .unwrap_or(vec![env]);
+
+        while history.len() >= MAX_FEE_HISTORY_ENTRIES {
+            history.pop_front();
+        }
+        history.push_back(entry);
+        env.storage().persistent().set(&history_key, &history);
+    }
+
     /// Record creation fee
 
     pub fn record_creation_fee(env: &Env, _admin: &Address, amount: i128) -> Result<(), Error> {
@@ -2544,43 +3009,49 @@ impl FeeTracker {
 pub struct FeeConfigManager;
 
 impl FeeConfigManager {
-    /// Store fee configuration
-    pub fn store_fee_config(env: &Env, config: &FeeConfig) -> Result<(), Error> {
-        let config_key = symbol_short!("fee_cfg");
-        env.storage().persistent().set(&config_key, config);
-        Ok(())
+    /// Get the fee settings of the contract configuration in effect
+    pub fn get_runtime_fee_config(env: &Env) -> FeeConfig {
+        Self::from_contract_config(&ConfigManager::get_runtime_config(env))
     }
 
-    /// Get fee configuration
-    pub fn get_fee_config(env: &Env) -> Result<FeeConfig, Error> {
-        let config_key = symbol_short!("fee_cfg");
-        Ok(env
-            .storage()
-            .persistent()
-            .get(&config_key)
-            .unwrap_or(FeeConfig {
-                platform_fee_percentage: PLATFORM_FEE_PERCENTAGE,
-                creation_fee: MARKET_CREATION_FEE,
-                min_fee_amount: MIN_FEE_AMOUNT,
-                max_fee_amount: MAX_FEE_AMOUNT,
-                collection_threshold: FEE_COLLECTION_THRESHOLD,
-                fees_enabled: true,
-            }))
-    }
-
-    /// Reset fee configuration to defaults
-    pub fn reset_to_defaults(env: &Env) -> Result<FeeConfig, Error> {
-        let default_config = FeeConfig {
-            platform_fee_percentage: PLATFORM_FEE_PERCENTAGE,
-            creation_fee: MARKET_CREATION_FEE,
-            min_fee_amount: MIN_FEE_AMOUNT,
-            max_fee_amount: MAX_FEE_AMOUNT,
-            collection_threshold: FEE_COLLECTION_THRESHOLD,
-            fees_enabled: true,
-        };
+    /// Get the fee settings that govern a market
+    pub fn get_market_fee_config(env: &Env, market_id: &Symbol) -> FeeConfig {
+        Self::from_contract_config(&ConfigManager::get_market_runtime_config(env, market_id))
+    }
+
+    /// Copy fee settings into a contract configuration
+    pub fn apply_to_contract_config(fee_config: &FeeConfig, config: &mut ContractConfig) {
+        config.fees.platform_fee_percentage = fee_config.platform_fee_percentage;
+        config.fees.creation_fee = fee_config.creation_fee;
+        config.fees.min_fee_amount = fee_config.min_fee_amount;
+        config.fees.max_fee_amount = fee_config.max_fee_amount;
+        config.fees.collection_threshold = fee_config.collection_threshold;
+        config.fees.fees_enabled = fee_config.fees_enabled;
+        config.fees.creator_fee_percentage = fee_config.creator_fee_percentage;
+        config.fees.referral_fee_percentage = fee_config.referral_fee_percentage;
+        config.fees.invalid_market_fee_percentage = fee_config.invalid_market_fee_percentage;
+        config.fees.discount_volume_medium = fee_config.discount_volume_medium;
+        config.fees.discount_volume_high = fee_config.discount_volume_high;
+        config.fees.discount_percentage_medium = fee_config.discount_percentage_medium;
+        config.fees.discount_percentage_high = fee_config.discount_percentage_high;
+    }
 
-        Self::store_fee_config(env, &default_config)?;
-        Ok(default_config)
+    fn from_contract_config(config: &ContractConfig) -> FeeConfig {
+        FeeConfig {
+            platform_fee_percentage: config.fees.platform_fee_percentage,
+            creation_fee: config.fees.creation_fee,
+            min_fee_amount: config.fees.min_fee_amount,
+            max_fee_amount: config.fees.max_fee_amount,
+            collection_threshold: config.fees.collection_threshold,
+            fees_enabled: config.fees.fees_enabled,
+            creator_fee_percentage: config.fees.creator_fee_percentage,
+            referral_fee_percentage: config.fees.referral_fee_percentage,
+            invalid_market_fee_percentage: config.fees.invalid_market_fee_percentage,
+            discount_volume_medium: config.fees.discount_volume_medium,
+            discount_volume_high: config.fees.discount_volume_high,
+            discount_percentage_medium: config.fees.discount_percentage_medium,
+            discount_percentage_high: config.fees.discount_percentage_high,
+        }
     }
 }
 
@@ -2603,23 +3074,35 @@ impl FeeAnalytics {
         let fee_distribution = Map::new(env);
         // TODO: Implement proper fee distribution calculation
 
+        // Reconcile with the treasury holding the collected fees
+        let treasury = match FeeTreasury::get_token(env) {
+            Ok(token) => Some(FeeTreasury::get_balance(env, &token)),
+            Err(_) => None,
+        };
+
         Ok(FeeAnalytics {
             total_fees_collected: total_fees,
             markets_with_fees,
             average_fee_per_market: average_fee,
             collection_history: history,
             fee_distribution,
+            total_fees_withdrawn: treasury.as_ref().map_or(0, |t| t.total_withdrawn),
+            treasury_balance: treasury.as_ref().map_or(0, |t| t.outstanding()),
         })
     }
 
     /// Get fee statistics for a specific market
-    pub fn get_market_fee_stats(market: &Market) -> Result<FeeBreakdown, Error> {
-        FeeCalculator::calculate_fee_breakdown(market)
+    pub fn get_market_fee_stats(
+        env: &Env,
+        market: &Market,
+        config: &FeeConfig,
+    ) -> Result<FeeBreakdown, Error> {
+        FeeCalculator::calculate_fee_breakdown(env, market, config)
     }
 
     /// Calculate fee efficiency (fees collected vs potential)
-    pub fn calculate_fee_efficiency(market: &Market) -> Result<f64, Error> {
-        let potential_fee = FeeCalculator::calculate_platform_fee(market)?;
+    pub fn calculate_fee_efficiency(market: &Market, config: &FeeConfig) -> Result<f64, Error> {
+        let potential_fee = FeeCalculator::calculate_platform_fee(market, config)?;
         let actual_fee = if market.fee_collected {
             potential_fee
         } else {
@@ -2639,17 +3122,31 @@ impl FeeAnalytics {
 #[cfg(test)]
 pub mod testing {
     use super::*;
+    use crate::config::{
+        DEFAULT_CREATOR_FEE_PERCENTAGE, DEFAULT_DISCOUNT_PERCENTAGE_HIGH,
+        DEFAULT_DISCOUNT_PERCENTAGE_MEDIUM, DEFAULT_DISCOUNT_VOLUME_HIGH,
+        DEFAULT_DISCOUNT_VOLUME_MEDIUM, DEFAULT_INVALID_MARKET_FEE_PERCENTAGE,
+        DEFAULT_MARKET_CREATION_FEE, DEFAULT_PLATFORM_FEE_PERCENTAGE,
+        DEFAULT_REFERRAL_FEE_PERCENTAGE, FEE_COLLECTION_THRESHOLD, MAX_FEE_AMOUNT, MIN_FEE_AMOUNT,
+    };
     use soroban_sdk::testutils::Address as _;
 
     /// Create a test fee configuration
     pub fn create_test_fee_config() -> FeeConfig {
         FeeConfig {
-            platform_fee_percentage: PLATFORM_FEE_PERCENTAGE,
-            creation_fee: MARKET_CREATION_FEE,
+            platform_fee_percentage: DEFAULT_PLATFORM_FEE_PERCENTAGE,
+            creation_fee: DEFAULT_MARKET_CREATION_FEE,
             min_fee_amount: MIN_FEE_AMOUNT,
             max_fee_amount: MAX_FEE_AMOUNT,
             collection_threshold: FEE_COLLECTION_THRESHOLD,
             fees_enabled: true,
+            creator_fee_percentage: DEFAULT_CREATOR_FEE_PERCENTAGE,
+            referral_fee_percentage: DEFAULT_REFERRAL_FEE_PERCENTAGE,
+            invalid_market_fee_percentage: DEFAULT_INVALID_MARKET_FEE_PERCENTAGE,
+            discount_volume_medium: DEFAULT_DISCOUNT_VOLUME_MEDIUM,
+            discount_volume_high: DEFAULT_DISCOUNT_VOLUME_HIGH,
+            discount_percentage_medium: DEFAULT_DISCOUNT_PERCENTAGE_MEDIUM,
+            discount_percentage_high: DEFAULT_DISCOUNT_PERCENTAGE_HIGH,
         }
     }
 
@@ -2665,7 +3162,7 @@ pub mod testing {
             amount,
             collected_by: admin,
             timestamp: env.ledger().timestamp(),
-            fee_percentage: PLATFORM_FEE_PERCENTAGE,
+            fee_percentage: DEFAULT_PLATFORM_FEE_PERCENTAGE,
         }
     }
 
@@ -2673,9 +3170,9 @@ pub mod testing {
     pub fn create_test_fee_breakdown() -> FeeBreakdown {
         FeeBreakdown {
             total_staked: 1_000_000_000, // 100 XLM
-            fee_percentage: PLATFORM_FEE_PERCENTAGE,
+            fee_percentage: DEFAULT_PLATFORM_FEE_PERCENTAGE,
             fee_amount: 20_000_000, // 2 XLM
-            platform_fee: 20_000_000,
+            platform_fee: 16_000_000,
             user_payout_amount: 980_000_000, // 98 XLM
         }
     }
@@ -2721,6 +3218,7 @@ pub mod testing {
             max_size: 100_000_000, // 10 XLM
             fee_percentage: 150,   // 1.5%
             tier_name: String::from_str(env, "Small"),
+            creator_fee_multiplier: 75,
         }
     }
 
@@ -2756,6 +3254,7 @@ pub mod testing {
             reason: String::from_str(env, "Activity level increased"),
             admin: Address::generate(env),
             calculation_factors: testing::create_test_fee_calculation_factors(env),
+            creator_fee: 0,
         }
     }
 }
@@ -2765,7 +3264,7 @@ pub mod testing {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::testutils::{Address as _, Ledger};
 
     #[test]
     fn test_fee_calculator_platform_fee() {
@@ -2793,10 +3292,50 @@ mod tests {
         market.total_staked = 1_000_000_000; // 100 XLM
 
         // Calculate fee
-        let fee = FeeCalculator::calculate_platform_fee(&market).unwrap();
+        let config = testing::create_test_fee_config();
+        let fee = FeeCalculator::calculate_platform_fee(&market, &config).unwrap();
         assert_eq!(fee, 20_000_000); // 2% of 100 XLM = 2 XLM
     }
 
+    #[test]
+    fn test_fee_calculator_creator_fee_scales_with_tier() {
+        let env = Env::default();
+        let mut market = Market::new(
+            &env,
+            Address::generate(&env),
+            String::from_str(&env, "Test Market"),
+            soroban_sdk::vec![
+                &env,
+                String::from_str(&env, "yes"),
+                String::from_str(&env, "no"),
+            ],
+            env.ledger().timestamp() + 86400,
+            crate::types::OracleConfig::new(
+                crate::types::OracleProvider::Pyth,
+                String::from_str(&env, "BTC/USD"),
+                2_500_000,
+                String::from_str(&env, "gt"),
+            ),
+            crate::types::MarketState::Active,
+        );
+        let mut config = testing::create_test_fee_config();
+
+        // Medium tier: the configured 20% share applies unchanged
+        market.total_staked = MARKET_SIZE_MEDIUM;
+        let fee = FeeCalculator::calculate_creator_fee(&env, &market, &config, 20_000_000);
+        assert_eq!(fee.unwrap(), 4_000_000);
+
+        // Large tier: creators earn 1.5x the configured share
+        market.total_staked = MARKET_SIZE_LARGE;
+        let fee = FeeCalculator::calculate_creator_fee(&env, &market, &config, 20_000_000);
+        assert_eq!(fee.unwrap(), 6_000_000);
+
+        // Disabled creator share
+        config.creator_fee_percentage = 0;
+        let fee = FeeCalculator::calculate_creator_fee(&env, &market, &config, 20_000_000);
+        assert_eq!(fee.unwrap(), 0);
+    }
+
     #[test]
     fn test_fee_validator_admin_permissions() {
         let env = Env::default();
@@ -2820,14 +3359,16 @@ mod tests {
 
     #[test]
     fn test_fee_validator_fee_amount() {
+        let config = testing::create_test_fee_config();
+
         // Valid fee amount
-        assert!(FeeValidator::validate_fee_amount(MIN_FEE_AMOUNT).is_ok());
+        assert!(FeeValidator::validate_fee_amount(config.min_fee_amount, &config).is_ok());
 
         // Invalid fee amount (too small)
-        assert!(FeeValidator::validate_fee_amount(MIN_FEE_AMOUNT - 1).is_err());
+        assert!(FeeValidator::validate_fee_amount(config.min_fee_amount - 1, &config).is_err());
 
         // Invalid fee amount (too large)
-        assert!(FeeValidator::validate_fee_amount(MAX_FEE_AMOUNT + 1).is_err());
+        assert!(FeeValidator::validate_fee_amount(config.max_fee_amount + 1, &config).is_err());
     }
 
     #[test]
@@ -2852,37 +3393,55 @@ mod tests {
             crate::types::MarketState::Active,
         );
 
+        let config = testing::create_test_fee_config();
+
         // Market not resolved
-        assert!(!FeeUtils::can_collect_fees(&market));
+        assert!(!FeeUtils::can_collect_fees(&market, &config));
 
         // Set winning outcome
         market.winning_outcome = Some(String::from_str(&env, "yes"));
 
         // Insufficient stakes
-        market.total_staked = FEE_COLLECTION_THRESHOLD - 1;
-        assert!(!FeeUtils::can_collect_fees(&market));
+        market.total_staked = config.collection_threshold - 1;
+        assert!(!FeeUtils::can_collect_fees(&market, &config));
 
         // Sufficient stakes
-        market.total_staked = FEE_COLLECTION_THRESHOLD;
-        assert!(FeeUtils::can_collect_fees(&market));
+        market.total_staked = config.collection_threshold;
+        assert!(FeeUtils::can_collect_fees(&market, &config));
 
         // Fees already collected
         market.fee_collected = true;
-        assert!(!FeeUtils::can_collect_fees(&market));
+        assert!(!FeeUtils::can_collect_fees(&market, &config));
     }
 
     #[test]
-    fn test_fee_config_manager() {
+    fn test_fee_config_update_is_queued_in_contract_config() {
         let env = Env::default();
+        env.mock_all_auths();
         let contract_id = env.register(crate::PredictifyHybrid, ());
-        let config = testing::create_test_fee_config();
+        let admin = Address::generate(&env);
+        let mut config = testing::create_test_fee_config();
+        config.platform_fee_percentage = 5;
 
         env.as_contract(&contract_id, || {
-            // Store and retrieve config
-            FeeConfigManager::store_fee_config(&env, &config).unwrap();
-            let retrieved_config = FeeConfigManager::get_fee_config(&env).unwrap();
-
-            assert_eq!(config, retrieved_config);
+            crate::admin::AdminInitializer::initialize(&env, &admin).unwrap();
+            let pending =
+                FeeManager::update_fee_config(&env, admin.clone(), config.clone()).unwrap();
+            assert_eq!(pending.config.fees.platform_fee_percentage, 5);
+
+            // Fees only change once the configuration timelock expires
+            assert_eq!(
+                FeeManager::get_fee_config(&env)
+                    .unwrap()
+                    .platform_fee_percentage,
+                DEFAULT_PLATFORM_FEE_PERCENTAGE
+            );
+            env.ledger().set_timestamp(pending.effective_at);
+            assert_eq!(FeeManager::get_fee_config(&env).unwrap(), config);
+            assert_eq!(
+                FeeConfigManager::get_runtime_fee_config(&env).platform_fee_percentage,
+                5
+            );
         });
     }
 
@@ -2962,6 +3521,59 @@ mod tests {
             String::from_str(&env, "Activity level increased")
         );
     }
+
+    #[test]
+    fn test_fee_history_keeps_most_recent_entries() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let market_id = Symbol::new(&env, "test_market");
+
+        env.as_contract(&contract_id, || {
+            for i in 0..(MAX_FEE_HISTORY_ENTRIES + 5) {
+                env.cost_estimate().budget().reset_default();
+                let mut entry = testing::create_test_fee_history(&env, market_id.clone());
+                entry.new_fee_percentage = i as i128;
+                FeeTracker::push_fee_history(&env, &market_id, entry);
+            }
+
+            let history = FeeManager::get_fee_history(&env, market_id.clone()).unwrap();
+            assert_eq!(history.len(), MAX_FEE_HISTORY_ENTRIES);
+            assert_eq!(history.first().unwrap().new_fee_percentage, 5);
+            assert_eq!(
+                history.last().unwrap().new_fee_percentage,
+                (MAX_FEE_HISTORY_ENTRIES + 4) as i128
+            );
+        });
+    }
+
+    #[test]
+    fn test_user_fee_discount_uses_configured_tiers() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let user = Address::generate(&env);
+        let mut config = testing::create_test_fee_config();
+
+        env.as_contract(&contract_id, || {
+            FeeTracker::record_user_volume(&env, &user, 500_000_000);
+            assert_eq!(
+                FeeCalculator::get_user_fee_discount(&env, &user, &config),
+                0
+            );
+
+            config.discount_volume_medium = 500_000_000;
+            config.discount_percentage_medium = 15;
+            assert_eq!(
+                FeeCalculator::get_user_fee_discount(&env, &user, &config),
+                15
+            );
+
+            config.discount_volume_high = 500_000_000;
+            assert_eq!(
+                FeeCalculator::get_user_fee_discount(&env, &user, &config),
+                config.discount_percentage_high
+            );
+        });
+    }
 }
 
 /// Fee distribution configuration for multiple parties
@@ -3676,7 +4288,8 @@ impl FeeDistributionManager {
         FeeValidator::validate_admin_permissions(env, &admin)?;
 
         // Validate distribution
-        FeeValidator::validate_fee_distribution(&distribution)?;
+        let fee_config = FeeConfigManager::get_market_fee_config(env, &market_id);
+        FeeValidator::validate_fee_distribution(&distribution, &fee_config)?;
 
         // Get market for validation
         let market = MarketStateManager::get_market(env, &market_id)?;
@@ -3724,8 +4337,8 @@ impl FeeDistributionManager {
             return Err(Error::InvalidInput);
         }
 
-        // Transfer fees to recipients
-        Self::transfer_fees_to_recipients(env, &distribution)?;
+        // Reassign the admin's treasury share to the recipients
+        Self::transfer_fees_to_recipients(env, &admin, &distribution)?;
 
         // Store execution record
         Self::store_distribution_execution(env, &execution)?;
@@ -3918,7 +4531,8 @@ impl FeeDistributionManager {
         }
 
         // Check for reasonable limits
-        if total_amount > MAX_FEE_AMOUNT * 10 {
+        let max_fee_amount = FeeConfigManager::get_runtime_fee_config(env).max_fee_amount;
+        if total_amount > max_fee_amount * 10 {
             return Err(Error::InvalidInput);
         }
 
@@ -3927,18 +4541,14 @@ impl FeeDistributionManager {
 
     // ===== PRIVATE HELPER METHODS =====
 
-    /// Transfer fees to recipients
+    /// Transfer fees to recipients within the treasury
     fn transfer_fees_to_recipients(
         env: &Env,
+        admin: &Address,
         distribution: &Map<Address, i128>,
     ) -> Result<(), Error> {
-        let token_client = MarketUtils::get_token_client(env)?;
-
-        for (recipient, amount) in distribution.iter() {
-            token_client.transfer(&env.current_contract_address(), &recipient, &amount);
-        }
-
-        Ok(())
+        let token = FeeTreasury::get_token(env)?;
+        FeeTreasury::reassign(env, &token, admin, distribution)
     }
 
     /// Store distribution execution
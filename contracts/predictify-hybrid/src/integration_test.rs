@@ -46,7 +46,7 @@ impl IntegrationTestSuite {
         // Initialize contract
         let contract_id = env.register(PredictifyHybrid, ());
         let client = PredictifyHybridClient::new(&env, &contract_id);
-        client.initialize(&admin);
+        client.initialize(&admin, &Environment::Development);
 
         // Set token for staking
         env.as_contract(&contract_id, || {
@@ -109,6 +109,7 @@ impl IntegrationTestSuite {
             market_id,
             &String::from_str(&self.env, outcome),
             &stake,
+            &None,
         );
     }
 
@@ -288,6 +289,7 @@ fn test_error_scenario_integration() {
         &non_existent_market,
         &String::from_str(&test_suite.env, "yes"),
         &10_0000000,
+        &None,
     );
     // This should panic with MarketNotFound error
 }
@@ -9,18 +9,25 @@ static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 // Module declarations - all modules enabled
 mod admin;
 mod batch_operations;
+mod bonds;
 mod circuit_breaker;
 mod config;
+mod curation;
 mod disputes;
 mod errors;
 mod events;
 mod extensions;
 mod fees;
 mod markets;
+mod metadata;
 mod oracles;
+mod referrals;
+mod reputation;
 mod resolution;
 mod storage;
+mod treasury;
 mod types;
+mod upgrade;
 mod utils;
 mod validation;
 mod validation_tests;
@@ -37,32 +44,33 @@ mod integration_test;
 
 // Re-export commonly used items
 use admin::AdminInitializer;
+pub use config::Environment;
 pub use errors::Error;
 pub use types::*;
 
 use alloc::format;
 use soroban_sdk::{
-    contract, contractimpl, panic_with_error, Address, Env, Map, String, Symbol, Vec,
+    contract, contractimpl, panic_with_error, Address, BytesN, Env, Map, String, Symbol, Vec,
 };
 
 #[contract]
 pub struct PredictifyHybrid;
 
-const PERCENTAGE_DENOMINATOR: i128 = 100;
-const FEE_PERCENTAGE: i128 = 2; // 2% fee for the platform
-
 #[contractimpl]
 impl PredictifyHybrid {
     /// Initializes the Predictify Hybrid smart contract with an administrator.
     ///
     /// This function must be called once after contract deployment to set up the initial
     /// administrative configuration. It establishes the contract admin who will have
-    /// privileges to create markets and perform administrative functions.
+    /// privileges to create markets and perform administrative functions, and stores
+    /// the contract configuration for the target environment. Fees, voting stakes,
+    /// dispute thresholds and extension limits are all read from this configuration.
     ///
     /// # Parameters
     ///
     /// * `env` - The Soroban environment for blockchain operations
     /// * `admin` - The address that will be granted administrative privileges
+    /// * `environment` - Deployment environment whose configuration is stored
     ///
     /// # Panics
     ///
@@ -75,35 +83,41 @@ impl PredictifyHybrid {
     ///
     /// ```rust
     /// # use soroban_sdk::{Env, Address};
-    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # use predictify_hybrid::{PredictifyHybrid, Environment};
     /// # let env = Env::default();
     /// # let admin_address = Address::generate(&env);
     ///
-    /// // Initialize the contract with an admin
-    /// PredictifyHybrid::initialize(env.clone(), admin_address);
+    /// // Initialize the contract with an admin and mainnet configuration
+    /// PredictifyHybrid::initialize(env.clone(), admin_address, Environment::Mainnet);
     /// ```
     ///
     /// # Security
     ///
     /// The admin address should be carefully chosen as it will have significant
     /// control over the contract's operation, including market creation and resolution.
-    pub fn initialize(env: Env, admin: Address) {
-        match AdminInitializer::initialize(&env, &admin) {
+    pub fn initialize(env: Env, admin: Address, environment: Environment) {
+        match AdminInitializer::initialize_with_config(&env, &admin, &environment) {
             Ok(_) => (), // Success
             Err(e) => panic_with_error!(env, e),
         }
+        upgrade::UpgradeManager::initialize_version(&env);
     }
 
     /// Creates a new prediction market with specified parameters and oracle configuration.
     ///
-    /// This function allows authorized administrators to create prediction markets
-    /// with custom questions, possible outcomes, duration, and oracle integration.
+    /// This function allows anyone to create prediction markets with custom
+    /// questions, possible outcomes, duration, and oracle integration. Creators
+    /// without the `CreateMarket` admin permission post a creation bond of
+    /// `FeeConfig.creation_fee` in the stake token; it is returned when the
+    /// market resolves and slashed if a dispute cancels the market as invalid.
+    /// Such markets start in `MarketState::Pending` until a market admin
+    /// approves them through the curation queue.
     /// Each market gets a unique identifier and is stored in persistent contract storage.
     ///
     /// # Parameters
     ///
     /// * `env` - The Soroban environment for blockchain operations
-    /// * `admin` - The administrator address creating the market (must be authorized)
+    /// * `admin` - The address creating the market (must be authorized)
     /// * `question` - The prediction question (must be non-empty)
     /// * `outcomes` - Vector of possible outcomes (minimum 2 required, all non-empty)
     /// * `duration_days` - Market duration in days (must be between 1-365 days)
@@ -116,9 +130,11 @@ impl PredictifyHybrid {
     /// # Panics
     ///
     /// This function will panic with specific errors if:
-    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidState` - The creation bond could not be posted
     /// - `Error::InvalidQuestion` - Question is empty
     /// - `Error::InvalidOutcomes` - Less than 2 outcomes or any outcome is empty
+    /// - `Error::InvalidDuration` - Duration is outside the allowed range
+    /// - `Error::InvalidOracleConfig` - Oracle configuration is malformed
     /// - Storage operations fail
     ///
     /// # Example
@@ -163,69 +179,38 @@ impl PredictifyHybrid {
         duration_days: u32,
         oracle_config: OracleConfig,
     ) -> Symbol {
-        // Authenticate that the caller is the admin
-        admin.require_auth();
-
-        // Verify the caller is an admin
-        let stored_admin: Address = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| {
-                panic!("Admin not set");
-            });
-
-        if admin != stored_admin {
-            panic_with_error!(env, Error::Unauthorized);
-        }
-
-        // Validate inputs
-        if outcomes.len() < 2 {
-            panic_with_error!(env, Error::InvalidOutcomes);
-        }
-
-        if question.len() == 0 {
-            panic_with_error!(env, Error::InvalidQuestion);
-        }
-
-        // Generate a unique market ID
-        let counter_key = Symbol::new(&env, "MarketCounter");
-        let counter: u32 = env.storage().persistent().get(&counter_key).unwrap_or(0);
-        let new_counter = counter + 1;
-        env.storage().persistent().set(&counter_key, &new_counter);
-
-        let market_id = Symbol::new(&env, &format!("market_{}", new_counter));
-
-        // Calculate end time
-        let seconds_per_day: u64 = 24 * 60 * 60;
-        let duration_seconds: u64 = (duration_days as u64) * seconds_per_day;
-        let end_time: u64 = env.ledger().timestamp() + duration_seconds;
-
-        // Create a new market
-        let market = Market {
-            admin: admin.clone(),
+        match markets::MarketCreator::create_market(
+            &env,
+            admin,
             question,
             outcomes,
-            end_time,
+            duration_days,
             oracle_config,
-            oracle_result: None,
-            votes: Map::new(&env),
-            total_staked: 0,
-            dispute_stakes: Map::new(&env),
-            stakes: Map::new(&env),
-            claimed: Map::new(&env),
-            winning_outcome: None,
-            fee_collected: false,
-            state: MarketState::Active,
-            total_extension_days: 0,
-            max_extension_days: 30,
-            extension_history: Vec::new(&env),
-        };
-
-        // Store the market
-        env.storage().persistent().set(&market_id, &market);
+        ) {
+            Ok(market_id) => market_id,
+            Err(e) => panic_with_error!(env, e),
+        }
+    }
 
-        market_id
+    /// Creates a market from a `MarketCreationParams` bundle.
+    ///
+    /// Behaves like `create_market`, and additionally records the market's
+    /// resolution criteria, optional trading window and optional per-market
+    /// configuration overrides in `params`. All are validated before the
+    /// market is created. A market with a future `start_time` stays `Pending`
+    /// until then, and its voting period counts from that start time.
+    ///
+    /// # Panics
+    ///
+    /// Same as `create_market`, plus:
+    /// - `Error::InvalidInput` - A resolution criteria field is empty or too long
+    /// - `Error::InvalidInput` - The betting cutoff falls outside the voting period
+    /// - `Error::InvalidFeeConfig` / `Error::InvalidInput` - An override is invalid
+    pub fn create_market_with_params(env: Env, params: MarketCreationParams) -> Symbol {
+        match markets::MarketCreator::create_market_with_params(&env, params) {
+            Ok(market_id) => market_id,
+            Err(e) => panic_with_error!(env, e),
+        }
     }
 
     /// Allows users to vote on a market outcome by staking tokens.
@@ -241,14 +226,19 @@ impl PredictifyHybrid {
     /// * `market_id` - Unique identifier of the market to vote on
     /// * `outcome` - The outcome the user is voting for (must match a market outcome)
     /// * `stake` - Amount of tokens to stake on this prediction (in base token units)
+    /// * `referrer` - Optional address that referred the user; earns a share of
+    ///   the platform fee the user pays when claiming winnings
     ///
     /// # Panics
     ///
     /// This function will panic with specific errors if:
     /// - `Error::MarketNotFound` - Market with given ID doesn't exist
-    /// - `Error::MarketClosed` - Market voting period has ended
+    /// - `Error::MarketClosed` - Market has not opened yet, or its voting period
+    ///   or betting window has ended
     /// - `Error::InvalidOutcome` - Outcome doesn't match any market outcomes
+    /// - `Error::InsufficientStake` - Stake is below the market's minimum vote stake
     /// - `Error::AlreadyVoted` - User has already voted on this market
+    /// - `Error::InvalidInput` - The referrer is the user themselves
     ///
     /// # Example
     ///
@@ -265,7 +255,8 @@ impl PredictifyHybrid {
     ///     user,
     ///     market_id,
     ///     String::from_str(&env, "Yes"),
-    ///     1000
+    ///     1000,
+    ///     None
     /// );
     /// ```
     ///
@@ -273,46 +264,27 @@ impl PredictifyHybrid {
     ///
     /// The stake amount represents the user's confidence in their prediction.
     /// Higher stakes increase potential rewards but also increase risk.
-    /// Stakes are locked until market resolution and cannot be withdrawn early.
+    /// The stake is transferred from the user to the contract and locked until
+    /// market resolution; it cannot be withdrawn early.
     ///
     /// # Market State Requirements
     ///
     /// - Market must be in `Active` state
     /// - Current time must be before market end time
     /// - Market must not be cancelled or resolved
-    pub fn vote(env: Env, user: Address, market_id: Symbol, outcome: String, stake: i128) {
-        user.require_auth();
-
-        let mut market: Market = env
-            .storage()
-            .persistent()
-            .get(&market_id)
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::MarketNotFound);
-            });
-
-        // Check if the market is still active
-        if env.ledger().timestamp() >= market.end_time {
-            panic_with_error!(env, Error::MarketClosed);
-        }
-
-        // Validate outcome
-        let outcome_exists = market.outcomes.iter().any(|o| o == outcome);
-        if !outcome_exists {
-            panic_with_error!(env, Error::InvalidOutcome);
-        }
-
-        // Check if user already voted
-        if market.votes.get(user.clone()).is_some() {
-            panic_with_error!(env, Error::AlreadyVoted);
+    pub fn vote(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        stake: i128,
+        referrer: Option<Address>,
+    ) {
+        if let Err(e) =
+            voting::VotingManager::process_vote(&env, user, market_id, outcome, stake, referrer)
+        {
+            panic_with_error!(env, e);
         }
-
-        // Store the vote and stake
-        market.votes.set(user.clone(), outcome);
-        market.stakes.set(user.clone(), stake);
-        market.total_staked += stake;
-
-        env.storage().persistent().set(&market_id, &market);
     }
 
     /// Allows users to claim their winnings from resolved prediction markets.
@@ -333,7 +305,7 @@ impl PredictifyHybrid {
     /// - `Error::MarketNotFound` - Market with given ID doesn't exist
     /// - `Error::AlreadyClaimed` - User has already claimed winnings from this market
     /// - `Error::MarketNotResolved` - Market hasn't been resolved yet
-    /// - `Error::NothingToClaim` - User didn't vote or voted for losing outcome
+    /// - `Error::NothingToClaim` - User didn't vote on this market
     ///
     /// # Example
     ///
@@ -356,12 +328,14 @@ impl PredictifyHybrid {
     ///
     /// Winnings are calculated using the formula:
     /// ```text
-    /// user_payout = (user_stake * (100 - fee_percentage) / 100) * total_pool / winning_total
+    /// user_payout = (user_stake * (10_000 - fee_percentage) / 10_000) * total_pool / winning_total
     /// ```
     ///
     /// Where:
     /// - `user_stake` - Amount the user staked on the winning outcome
-    /// - `fee_percentage` - Platform fee (currently 2%)
+    /// - `fee_percentage` - The market's dynamic fee in basis points, frozen at
+    ///   resolution (see `get_market_fee_breakdown`), less the claimant's
+    ///   volume discount or fee exemption (see `get_user_fee_discount`)
     /// - `total_pool` - Sum of all stakes in the market
     /// - `winning_total` - Sum of stakes on the winning outcome
     ///
@@ -370,60 +344,15 @@ impl PredictifyHybrid {
     /// - Market must be in `Resolved` state with a winning outcome set
     /// - User must have voted for the winning outcome
     /// - User must not have previously claimed winnings
+    ///
+    /// The payout is transferred to the user; a losing position is marked
+    /// claimed without a transfer. If the market was resolved as invalid
+    /// (`Cancelled`), every voter instead gets their stake back less the
+    /// invalid market fee.
     pub fn claim_winnings(env: Env, user: Address, market_id: Symbol) {
-        user.require_auth();
-
-        let mut market: Market = env
-            .storage()
-            .persistent()
-            .get(&market_id)
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::MarketNotFound);
-            });
-
-        // Check if user has claimed already
-        if market.claimed.get(user.clone()).unwrap_or(false) {
-            panic_with_error!(env, Error::AlreadyClaimed);
-        }
-
-        // Check if market is resolved
-        let winning_outcome = match &market.winning_outcome {
-            Some(outcome) => outcome,
-            None => panic_with_error!(env, Error::MarketNotResolved),
-        };
-
-        // Get user's vote
-        let user_outcome = market
-            .votes
-            .get(user.clone())
-            .unwrap_or_else(|| panic_with_error!(env, Error::NothingToClaim));
-
-        let user_stake = market.stakes.get(user.clone()).unwrap_or(0);
-
-        // Calculate payout if user won
-        if &user_outcome == winning_outcome {
-            // Calculate total winning stakes
-            let mut winning_total = 0;
-            for (voter, outcome) in market.votes.iter() {
-                if &outcome == winning_outcome {
-                    winning_total += market.stakes.get(voter.clone()).unwrap_or(0);
-                }
-            }
-
-            if winning_total > 0 {
-                let user_share = (user_stake * (PERCENTAGE_DENOMINATOR - FEE_PERCENTAGE))
-                    / PERCENTAGE_DENOMINATOR;
-                let total_pool = market.total_staked;
-                let _payout = (user_share * total_pool) / winning_total;
-
-                // In a real implementation, transfer tokens here
-                // For now, we just mark as claimed
-            }
+        if let Err(e) = voting::VotingManager::process_claim(&env, user, market_id) {
+            panic_with_error!(env, e);
         }
-
-        // Mark as claimed
-        market.claimed.set(user.clone(), true);
-        env.storage().persistent().set(&market_id, &market);
     }
 
     /// Retrieves complete market information by market identifier.
@@ -530,6 +459,8 @@ impl PredictifyHybrid {
     /// 3. **Outcome Validation**: Confirms winning outcome is valid
     /// 4. **State Update**: Sets winning outcome and updates market state
     ///
+    /// Use `resolve_market_invalid` to declare a market invalid instead.
+    ///
     /// # Use Cases
     ///
     /// - **Subjective Markets**: Markets requiring human judgment
@@ -574,6 +505,9 @@ impl PredictifyHybrid {
         if env.ledger().timestamp() < market.end_time {
             panic_with_error!(env, Error::MarketClosed);
         }
+        if market.state == MarketState::Pending {
+            panic_with_error!(env, Error::InvalidState);
+        }
 
         // Validate winning outcome
         let outcome_exists = market.outcomes.iter().any(|o| o == winning_outcome);
@@ -583,12 +517,97 @@ impl PredictifyHybrid {
 
         // Set winning outcome and update state
         market.winning_outcome = Some(winning_outcome);
-        market.state = MarketState::Resolved;
+        markets::MarketStateManager::end_market_if_expired(&env, &market_id, &mut market);
+        if let Err(e) = markets::MarketStateManager::transition_state(
+            &env,
+            &market_id,
+            &mut market,
+            MarketState::Resolved,
+        ) {
+            panic_with_error!(env, e);
+        }
         env.storage().persistent().set(&market_id, &market);
+
+        if let Err(e) =
+            resolution::MarketResolutionManager::settle_resolved_market(&env, &market_id)
+        {
+            panic_with_error!(env, e);
+        }
     }
 
+    /// Declares an ended market invalid instead of naming a winning outcome (admin only).
+    ///
+    /// Used for ill-posed questions where no outcome can be picked fairly.
+    /// The market moves to `MarketState::Cancelled` without a winning
+    /// outcome, and every voter can claim back their stake, less the invalid
+    /// market fee, with `claim_winnings`. A creator who bonded the market gets
+    /// the bond back, since the admin rather than a dispute voided it.
+    ///
+    /// Disputed markets are voided through `cancel_disputed_market` instead,
+    /// which also settles their dispute bonds and votes.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `admin` - The administrator address voiding the market (must be authorized)
+    /// * `market_id` - Unique identifier of the market to void
+    ///
+    /// # Returns
+    ///
+    /// The resolution record, with `ResolutionMethod::Invalid` and an empty
+    /// final outcome.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::MarketClosed` - Market hasn't reached its end time yet
+    /// - `Error::InvalidState` - Market is pending review or has disputes
+    /// - `Error::MarketAlreadyResolved` - Market already has a winning outcome
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use soroban_sdk::{Env, Address, Symbol};
+    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # let env = Env::default();
+    /// # let admin = Address::generate(&env);
+    /// # let market_id = Symbol::new(&env, "market_1");
+    ///
+    /// // Void an ill-posed market so voters can claim refunds
+    /// let resolution = PredictifyHybrid::resolve_market_invalid(
+    ///     env.clone(),
+    ///     admin,
+    ///     market_id
+    /// ).unwrap();
+    /// ```
+    pub fn resolve_market_invalid(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+    ) -> Result<resolution::MarketResolution, Error> {
+        admin.require_auth();
+
+        let stored_admin: Option<Address> =
+            env.storage().persistent().get(&Symbol::new(&env, "Admin"));
+        if stored_admin != Some(admin) {
+            return Err(Error::Unauthorized);
+        }
 
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if env.ledger().timestamp() < market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        if market.state == MarketState::Pending {
+            return Err(Error::InvalidState);
+        }
 
+        resolution::MarketResolutionManager::void_market(
+            &env,
+            &market_id,
+            resolution::InvalidationReason::AdminVerdict,
+        )
+    }
 
     /// Fetches oracle result for a market from external oracle contracts.
     ///
@@ -992,23 +1011,100 @@ impl PredictifyHybrid {
         disputes::DisputeManager::resolve_dispute(&env, market_id, admin)
     }
 
-    /// Collect fees from a market (admin only)
-    pub fn collect_fees(env: Env, admin: Address, market_id: Symbol) -> Result<i128, Error> {
-        admin.require_auth();
+    /// Approve a pending market and open it for voting (market admin only)
+    pub fn approve_market(env: Env, admin: Address, market_id: Symbol) -> Result<(), Error> {
+        curation::CurationManager::approve(&env, &admin, &market_id)
+    }
 
-        // Verify admin
-        let stored_admin: Address = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::Unauthorized);
-            });
+    /// Edit the oracle configuration of a pending market (market admin only)
+    pub fn edit_pending_market_oracle(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        oracle_config: OracleConfig,
+    ) -> Result<(), Error> {
+        curation::CurationManager::edit_oracle_config(&env, &admin, &market_id, oracle_config)
+    }
 
-        if admin != stored_admin {
-            panic_with_error!(env, Error::Unauthorized);
-        }
+    /// Reject a pending market and refund its creation bond (market admin only)
+    pub fn reject_market(env: Env, admin: Address, market_id: Symbol) -> Result<(), Error> {
+        curation::CurationManager::reject(&env, &admin, &market_id)
+    }
+
+    /// Get the markets waiting for review, at most
+    /// `curation::MAX_PENDING_PROPOSALS` of them
+    pub fn get_pending_markets(env: Env) -> Vec<Symbol> {
+        curation::CurationManager::get_pending_markets(&env)
+    }
 
+    /// Get the proposal record for a market, if it was proposed for review
+    pub fn get_market_proposal(env: Env, market_id: Symbol) -> Option<curation::MarketProposal> {
+        curation::CurationManager::get_proposal(&env, &market_id)
+    }
+
+    /// Cancel a disputed market as invalid, slashing its creation bond (admin only)
+    pub fn cancel_disputed_market(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+    ) -> Result<(), Error> {
+        disputes::DisputeManager::cancel_disputed_market(&env, market_id, admin)
+    }
+
+    /// Claim a disputer's or dispute voter's payout from a settled dispute
+    pub fn claim_dispute_payout(env: Env, user: Address, market_id: Symbol) -> Result<i128, Error> {
+        disputes::DisputeManager::claim_dispute_payout(&env, user, market_id)
+    }
+
+    /// Get the settlement of a market's disputes, if they were settled
+    pub fn get_dispute_settlement(
+        env: Env,
+        market_id: Symbol,
+    ) -> Option<disputes::DisputeSettlement> {
+        disputes::DisputeManager::get_dispute_settlement(&env, &market_id)
+    }
+
+    /// Set how a market's disputes are resolved and its dispute votes weighted (admin only)
+    pub fn set_market_resolution_config(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        resolution: config::ResolutionConfig,
+    ) -> Result<(), Error> {
+        disputes::DisputeManager::set_market_resolution_config(&env, admin, market_id, resolution)
+    }
+
+    /// Escalate a market's dispute to another round by posting a doubled bond
+    pub fn escalate_dispute(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        reason: String,
+    ) -> Result<disputes::DisputeEscalation, Error> {
+        disputes::DisputeManager::escalate_dispute(&env, user, market_id, reason)
+    }
+
+    /// Get the escalation rounds of a market's dispute, oldest first
+    pub fn get_dispute_rounds(env: Env, market_id: Symbol) -> Vec<disputes::DisputeEscalation> {
+        disputes::DisputeManager::get_dispute_rounds(&env, &market_id)
+    }
+
+    /// Get the timeout of a market's current dispute round
+    pub fn get_dispute_timeout(
+        env: Env,
+        market_id: Symbol,
+    ) -> Result<disputes::DisputeTimeout, Error> {
+        disputes::DisputeUtils::get_dispute_timeout(&env, &market_id)
+    }
+
+    /// Get the creation bond backing a market, if any
+    pub fn get_creation_bond(env: Env, market_id: Symbol) -> Option<bonds::CreationBond> {
+        bonds::BondManager::get_bond(&env, &market_id)
+    }
+
+    /// Collect fees from a market (admin only)
+    pub fn collect_fees(env: Env, admin: Address, market_id: Symbol) -> Result<i128, Error> {
+        // Authentication and admin checks happen in the fee manager
         fees::FeeManager::collect_fees(&env, admin, market_id)
     }
 
@@ -1132,7 +1228,163 @@ impl PredictifyHybrid {
         fees::FeeManager::validate_distribution_totals(&env, &distribution)
     }
 
-    /// Extend market duration (admin only)
+    /// Withdraw the caller's accrued fee share of a token from the treasury
+    pub fn withdraw_fees(env: Env, recipient: Address, token: Address) -> Result<i128, Error> {
+        treasury::FeeTreasury::withdraw(&env, &recipient, &token)
+    }
+
+    /// Get the treasury's fee accounting for a token
+    pub fn get_treasury_balance(env: Env, token: Address) -> treasury::TreasuryBalance {
+        treasury::FeeTreasury::get_balance(&env, &token)
+    }
+
+    /// Get the fees a recipient can withdraw in a token
+    pub fn get_withdrawable_fees(env: Env, token: Address, recipient: Address) -> i128 {
+        treasury::FeeTreasury::get_withdrawable(&env, &token, &recipient)
+    }
+
+    /// Exempt an address from platform fees, or lift its exemption (admin only)
+    pub fn set_fee_exemption(
+        env: Env,
+        admin: Address,
+        user: Address,
+        exempt: bool,
+    ) -> Result<(), Error> {
+        fees::FeeManager::set_fee_exemption(&env, admin, user, exempt)
+    }
+
+    /// Get the share of the platform fee waived for a user (percentage)
+    pub fn get_user_fee_discount(env: Env, user: Address) -> i128 {
+        let config = fees::FeeConfigManager::get_runtime_fee_config(&env);
+        fees::FeeCalculator::get_user_fee_discount(&env, &user, &config)
+    }
+
+    /// Get a user's cumulative staked volume across all markets
+    pub fn get_user_volume(env: Env, user: Address) -> i128 {
+        fees::FeeManager::get_user_volume(&env, &user)
+    }
+
+    /// Get the referrer a user's vote on a market is attributed to, if any
+    pub fn get_referrer(env: Env, market_id: Symbol, user: Address) -> Option<Address> {
+        referrals::ReferralManager::get_referrer(&env, &market_id, &user)
+    }
+
+    /// Get a referrer's attributed votes, volume and credited fees
+    pub fn get_referral_stats(env: Env, referrer: Address) -> referrals::ReferralStats {
+        referrals::ReferralManager::get_referral_stats(&env, &referrer)
+    }
+
+    /// Get a user's prediction track record
+    pub fn get_user_reputation(env: Env, user: Address) -> reputation::UserReputation {
+        reputation::ReputationManager::get_reputation(&env, &user)
+    }
+
+    /// Get the registry of published event types, with their topic layout
+    /// and data fields
+    pub fn get_event_registry(env: Env) -> Map<String, events::EventSchema> {
+        events::EventDocumentation::get_event_type_docs(&env)
+    }
+
+    /// List markets matching `filter`, in creation order
+    ///
+    /// Returns lightweight summaries; use `get_market` for a market's full
+    /// vote and stake data. Pass the returned `next_cursor` as `cursor` to
+    /// continue scanning.
+    pub fn list_markets(env: Env, cursor: u32, limit: u32, filter: MarketFilter) -> MarketPage {
+        markets::MarketIndex::list_markets(&env, cursor, limit, &filter)
+    }
+
+    /// Set a market's category, tags and descriptive metadata
+    ///
+    /// Only the market creator or the contract admin may set metadata, and
+    /// only before the first vote or, for proposed markets, their approval.
+    pub fn set_market_metadata(
+        env: Env,
+        caller: Address,
+        market_id: Symbol,
+        metadata: metadata::MarketMetadata,
+    ) -> Result<(), Error> {
+        metadata::MarketMetadataManager::set_metadata(&env, &caller, &market_id, &metadata)
+    }
+
+    /// Get a market's metadata, if any was set
+    pub fn get_market_metadata(env: Env, market_id: Symbol) -> Option<metadata::MarketMetadata> {
+        metadata::MarketMetadataManager::get_metadata(&env, &market_id)
+    }
+
+    /// Amend a market's resolution criteria before its first vote
+    ///
+    /// Only the market creator or the contract admin may do so; once a vote
+    /// has been cast or a proposed market approved the criteria are immutable.
+    pub fn update_resolution_criteria(
+        env: Env,
+        caller: Address,
+        market_id: Symbol,
+        criteria: ResolutionCriteria,
+    ) -> Result<(), Error> {
+        metadata::MarketMetadataManager::update_resolution_criteria(
+            &env, &caller, &market_id, &criteria,
+        )
+    }
+
+    /// Get the resolution criteria a market was created with, if any
+    pub fn get_resolution_criteria(env: Env, market_id: Symbol) -> Option<ResolutionCriteria> {
+        metadata::MarketMetadataManager::get_resolution_criteria(&env, &market_id)
+    }
+
+    /// Get the number of markets created
+    pub fn get_market_count(env: Env) -> u32 {
+        markets::MarketIndex::get_market_count(&env)
+    }
+
+    /// Claim winnings from several resolved markets in one transfer
+    ///
+    /// Markets with nothing to claim are skipped. Returns the amount settled
+    /// for each market.
+    pub fn claim_all(
+        env: Env,
+        user: Address,
+        market_ids: Vec<Symbol>,
+    ) -> Result<Vec<voting::MarketClaim>, Error> {
+        voting::VotingManager::process_claim_all(&env, user, market_ids)
+    }
+
+    /// Claim winnings from every market the user voted in, in one transfer
+    pub fn claim_all_positions(env: Env, user: Address) -> Result<Vec<voting::MarketClaim>, Error> {
+        voting::VotingManager::process_claim_all_positions(&env, user)
+    }
+
+    /// Get a page of a user's positions across the markets they voted in
+    pub fn get_user_positions(
+        env: Env,
+        user: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<voting::UserPosition>, Error> {
+        voting::VotingManager::get_user_positions(&env, &user, offset, limit)
+    }
+
+    /// Get the total a user could claim across every market they voted in
+    pub fn get_claimable_total(env: Env, user: Address) -> Result<i128, Error> {
+        voting::VotingManager::get_claimable_total(&env, &user)
+    }
+
+    /// Get a page of a market's recent activity, newest first
+    pub fn get_market_activity(
+        env: Env,
+        market_id: Symbol,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<events::MarketActivity> {
+        events::EventLogger::get_market_activity(&env, &market_id, offset, limit)
+    }
+
+    /// Get the fee breakdown frozen when a market resolved, if any
+    pub fn get_market_fee_breakdown(env: Env, market_id: Symbol) -> Option<fees::FeeBreakdown> {
+        fees::FeeManager::get_claim_fee_breakdown(&env, &market_id)
+    }
+
+    /// Extend market duration (admins with the `ExtendMarket` permission)
     pub fn extend_market(
         env: Env,
         admin: Address,
@@ -1143,19 +1395,7 @@ impl PredictifyHybrid {
     ) -> Result<(), Error> {
         admin.require_auth();
 
-        // Verify admin
-        let stored_admin: Address = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::Unauthorized);
-            });
-
-        if admin != stored_admin {
-            panic_with_error!(env, Error::Unauthorized);
-        }
-
+        // Permission checks happen in the extension manager
         extensions::ExtensionManager::extend_market_duration(
             &env,
             admin,
@@ -1163,8 +1403,85 @@ impl PredictifyHybrid {
             additional_days,
             reason,
         )
+    }
+
+    // ===== CONFIGURATION FUNCTIONS =====
+
+    /// Queue a timelocked contract configuration update (admin only)
+    pub fn update_contract_config(
+        env: Env,
+        admin: Address,
+        new_config: config::ContractConfig,
+    ) -> Result<config::PendingConfigUpdate, Error> {
+        admin::AdminFunctions::update_contract_config(&env, &admin, &new_config)
+    }
+
+    /// Cancel a queued configuration update before it takes effect (admin only)
+    pub fn cancel_config_update(env: Env, admin: Address) -> Result<(), Error> {
+        admin::AdminFunctions::cancel_contract_config_update(&env, &admin)
+    }
+
+    /// Get the queued configuration update, if any
+    pub fn get_pending_config(env: Env) -> Option<config::PendingConfigUpdate> {
+        config::ConfigManager::get_pending_config(&env)
+    }
+
+    /// Get the configuration currently in effect
+    pub fn get_contract_config(env: Env) -> Result<config::ContractConfig, Error> {
+        config::ConfigManager::get_config(&env)
+    }
+
+    /// Get the configuration snapshot that governs a market
+    pub fn get_market_config(env: Env, market_id: Symbol) -> Result<config::ContractConfig, Error> {
+        config::ConfigManager::get_market_config(&env, &market_id)
+    }
+
+    /// Get the effective configuration of a market, including per-market overrides
+    pub fn get_effective_market_config(env: Env, market_id: Symbol) -> config::ContractConfig {
+        config::ConfigManager::get_market_runtime_config(&env, &market_id)
+    }
+
+    // ===== ADMIN OWNERSHIP FUNCTIONS =====
+
+    /// Propose a new contract admin; the proposal expires if not accepted (admin only)
+    pub fn propose_admin_transfer(
+        env: Env,
+        admin: Address,
+        new_admin: Address,
+    ) -> Result<admin::PendingAdminTransfer, Error> {
+        admin::AdminTransferManager::propose_transfer(&env, &admin, &new_admin)
+    }
+
+    /// Accept a pending admin transfer (must be signed by the proposed admin)
+    pub fn accept_admin_transfer(env: Env, new_admin: Address) -> Result<(), Error> {
+        admin::AdminTransferManager::accept_transfer(&env, &new_admin)
+    }
+
+    /// Cancel a pending admin transfer (admin only)
+    pub fn cancel_admin_transfer(env: Env, admin: Address) -> Result<(), Error> {
+        admin::AdminTransferManager::cancel_transfer(&env, &admin)
+    }
+
+    /// Get the pending admin transfer, if any
+    pub fn get_pending_admin_transfer(env: Env) -> Option<admin::PendingAdminTransfer> {
+        admin::AdminTransferManager::get_pending_transfer(&env)
+    }
 
+    // ===== UPGRADE FUNCTIONS =====
+
+    /// Replace the contract WASM with an uploaded build; call `migrate` next (admin only)
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        upgrade::UpgradeManager::upgrade(&env, &admin, &new_wasm_hash)
+    }
 
+    /// Migrate stored data to the version of the running code (admin only)
+    pub fn migrate(env: Env, admin: Address) -> Result<upgrade::ContractVersionInfo, Error> {
+        upgrade::UpgradeManager::migrate(&env, &admin)
+    }
+
+    /// Get the stored contract version and storage format
+    pub fn get_contract_version(env: Env) -> upgrade::ContractVersionInfo {
+        upgrade::UpgradeManager::get_version_info(&env)
     }
 
     // ===== STORAGE OPTIMIZATION FUNCTIONS =====
@@ -1218,8 +1535,20 @@ impl PredictifyHybrid {
         storage::StorageOptimizer::get_storage_config(&env)
     }
 
-    /// Update storage configuration
-    pub fn update_storage_config(env: Env, config: storage::StorageConfig) -> Result<(), Error> {
+    /// Update storage configuration (admin only)
+    pub fn update_storage_config(
+        env: Env,
+        admin: Address,
+        config: storage::StorageConfig,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let stored_admin: Option<Address> =
+            env.storage().persistent().get(&Symbol::new(&env, "Admin"));
+        if stored_admin != Some(admin) {
+            return Err(Error::Unauthorized);
+        }
+
         storage::StorageOptimizer::update_storage_config(&env, &config)
     }
 
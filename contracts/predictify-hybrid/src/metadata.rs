@@ -0,0 +1,142 @@
+//! Market metadata for Predictify Hybrid
+//!
+//! Descriptive information about a market lives outside the `Market` entry
+//! so votes and claims don't pay to load it:
+//! - A category and tags used by listing queries
+//! - A description and source URL for display
+//! - A hash of the market's image
+//! - The structured resolution criteria recorded at creation
+//!
+//! The market creator or the contract admin may change either until the
+//! first vote is cast, or until a market admin approves a proposed market.
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::curation::CurationManager;
+use crate::errors::Error;
+use crate::markets::MarketStateManager;
+use crate::types::ResolutionCriteria;
+use crate::validation::InputValidator;
+
+// ===== METADATA TYPES =====
+
+/// Optional descriptive record attached to a market
+///
+/// Empty strings and an empty tag list mean the field is not set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketMetadata {
+    /// Market category, e.g. "crypto" or "sports"
+    pub category: String,
+    /// Free-form tags
+    pub tags: Vec<String>,
+    /// Longer description of the market
+    pub description: String,
+    /// URL of the source the market resolves against
+    pub source_url: String,
+    /// Hash of the market's image, e.g. an IPFS CID
+    pub image_hash: String,
+}
+
+// ===== METADATA MANAGER =====
+
+/// Stores and validates market metadata
+pub struct MarketMetadataManager;
+
+impl MarketMetadataManager {
+    /// Set a market's metadata.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - The market does not exist
+    /// - `Error::Unauthorized` - The caller is neither the market creator nor
+    ///   the contract admin
+    /// - `Error::InvalidState` - The market already has votes or was approved
+    ///   from the review queue
+    /// - `Error::InvalidInput` - A field exceeds its length limit
+    pub fn set_metadata(
+        env: &Env,
+        caller: &Address,
+        market_id: &Symbol,
+        metadata: &MarketMetadata,
+    ) -> Result<(), Error> {
+        Self::require_editable(env, caller, market_id)?;
+        InputValidator::validate_market_metadata(metadata).map_err(|_| Error::InvalidInput)?;
+
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("mkt_meta"), market_id.clone()), metadata);
+        Ok(())
+    }
+
+    /// Get a market's metadata, if any was set.
+    pub fn get_metadata(env: &Env, market_id: &Symbol) -> Option<MarketMetadata> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("mkt_meta"), market_id.clone()))
+    }
+
+    /// Remove a market's metadata.
+    pub fn remove_metadata(env: &Env, market_id: &Symbol) {
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("mkt_meta"), market_id.clone()));
+    }
+
+    /// Replace a market's resolution criteria.
+    ///
+    /// # Errors
+    ///
+    /// Same as `set_metadata`, with `Error::InvalidInput` also covering an
+    /// empty field.
+    pub fn update_resolution_criteria(
+        env: &Env,
+        caller: &Address,
+        market_id: &Symbol,
+        criteria: &ResolutionCriteria,
+    ) -> Result<(), Error> {
+        Self::require_editable(env, caller, market_id)?;
+        InputValidator::validate_resolution_criteria(criteria).map_err(|_| Error::InvalidInput)?;
+
+        Self::store_resolution_criteria(env, market_id, criteria);
+        Ok(())
+    }
+
+    /// Store a market's resolution criteria without access checks.
+    ///
+    /// Used at creation, after the criteria have been validated.
+    pub fn store_resolution_criteria(env: &Env, market_id: &Symbol, criteria: &ResolutionCriteria) {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("mkt_crit"), market_id.clone()), criteria);
+    }
+
+    /// Get a market's resolution criteria, if it was created with any.
+    pub fn get_resolution_criteria(env: &Env, market_id: &Symbol) -> Option<ResolutionCriteria> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("mkt_crit"), market_id.clone()))
+    }
+
+    /// Remove a market's resolution criteria.
+    pub fn remove_resolution_criteria(env: &Env, market_id: &Symbol) {
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("mkt_crit"), market_id.clone()));
+    }
+
+    /// Check that `caller` may still edit a market's descriptive data.
+    fn require_editable(env: &Env, caller: &Address, market_id: &Symbol) -> Result<(), Error> {
+        caller.require_auth();
+
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let admin: Option<Address> = env.storage().persistent().get(&Symbol::new(env, "Admin"));
+        if *caller != market.admin && Some(caller) != admin.as_ref() {
+            return Err(Error::Unauthorized);
+        }
+        if !market.votes.is_empty() || CurationManager::is_reviewed(env, market_id) {
+            return Err(Error::InvalidState);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+//! Referral program for Predictify Hybrid
+//!
+//! Votes may name the address that referred the voter:
+//! - The referrer is attributed on-chain per market and voter
+//! - When the voter claims winnings, a configurable share of the platform fee
+//!   they paid is credited to the referrer in the fee treasury
+//! - Referrers withdraw their credits through the treasury like any other
+//!   fee recipient, and their running totals are queryable
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::fees::FeeConfigManager;
+use crate::treasury::FeeTreasury;
+use crate::validation::InputValidator;
+
+// ===== REFERRAL TYPES =====
+
+/// Running totals for a single referrer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralStats {
+    /// Referrer the totals belong to
+    pub referrer: Address,
+    /// Number of votes attributed to the referrer
+    pub referred_votes: u32,
+    /// Total stake of the attributed votes
+    pub referred_volume: i128,
+    /// Total fees credited to the referrer
+    pub total_earned: i128,
+}
+
+// ===== REFERRAL MANAGER =====
+
+/// Attributes votes to referrers and credits their share of fees
+pub struct ReferralManager;
+
+impl ReferralManager {
+    /// Attribute a user's vote on a market to a referrer.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - The referrer is the voter or the contract, or
+    ///   the referrer was referred to this market by the voter
+    pub fn record_referral(
+        env: &Env,
+        market_id: &Symbol,
+        user: &Address,
+        referrer: &Address,
+        stake: i128,
+    ) -> Result<(), Error> {
+        InputValidator::validate_referrer(env, user, referrer)
+            .map_err(|e| e.to_contract_error())?;
+
+        // Two voters may not refer each other on the same market
+        if Self::get_referrer(env, market_id, referrer).as_ref() == Some(user) {
+            return Err(Error::InvalidInput);
+        }
+
+        env.storage().persistent().set(
+            &(symbol_short!("referral"), market_id.clone(), user.clone()),
+            referrer,
+        );
+
+        let mut stats = Self::get_referral_stats(env, referrer);
+        stats.referred_votes += 1;
+        stats.referred_volume += stake;
+        Self::store_stats(env, &stats);
+        Ok(())
+    }
+
+    /// Credit the referrer of a claimant with their share of the fee paid.
+    ///
+    /// Returns the amount credited, which is zero if the claimant has no
+    /// referrer on this market.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidState` - No token is configured
+    pub fn credit_referrer(
+        env: &Env,
+        market_id: &Symbol,
+        user: &Address,
+        fee_paid: i128,
+    ) -> Result<i128, Error> {
+        let referrer = match Self::get_referrer(env, market_id, user) {
+            Some(referrer) => referrer,
+            None => return Ok(0),
+        };
+
+        let fee_config = FeeConfigManager::get_market_fee_config(env, market_id);
+        let credit = (fee_paid * fee_config.referral_fee_percentage) / 100;
+        if credit <= 0 {
+            return Ok(0);
+        }
+
+        FeeTreasury::accrue_to(env, market_id, &referrer, credit)?;
+
+        let credits = Self::get_market_credits(env, market_id);
+        env.storage().persistent().set(
+            &(symbol_short!("ref_paid"), market_id.clone()),
+            &(credits + credit),
+        );
+
+        let mut stats = Self::get_referral_stats(env, &referrer);
+        stats.total_earned += credit;
+        Self::store_stats(env, &stats);
+
+        EventEmitter::emit_referral_credited(env, market_id, &referrer, user, credit);
+        Ok(credit)
+    }
+
+    /// Get the referrer a user's vote on a market is attributed to, if any.
+    pub fn get_referrer(env: &Env, market_id: &Symbol, user: &Address) -> Option<Address> {
+        env.storage().persistent().get(&(
+            symbol_short!("referral"),
+            market_id.clone(),
+            user.clone(),
+        ))
+    }
+
+    /// Get the fees already credited to referrers out of a market.
+    pub fn get_market_credits(env: &Env, market_id: &Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("ref_paid"), market_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Get the running totals for a referrer.
+    pub fn get_referral_stats(env: &Env, referrer: &Address) -> ReferralStats {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("ref_stats"), referrer.clone()))
+            .unwrap_or(ReferralStats {
+                referrer: referrer.clone(),
+                referred_votes: 0,
+                referred_volume: 0,
+                total_earned: 0,
+            })
+    }
+
+    fn store_stats(env: &Env, stats: &ReferralStats) {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("ref_stats"), stats.referrer.clone()), stats);
+    }
+}
@@ -0,0 +1,80 @@
+//! Prediction reputation for Predictify Hybrid
+//!
+//! Each user's track record is kept on-chain:
+//! - When a market resolves, every voter's prediction is scored against the
+//!   winning outcome
+//! - When a market's disputes settle, every dispute voter is scored against
+//!   whether the disputes were upheld
+//! - The resulting accuracy score weights dispute votes on markets that use
+//!   `DisputeVoteWeighting::Reputation`
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env};
+
+use crate::types::Market;
+
+// ===== REPUTATION TYPES =====
+
+/// Prediction track record of a single user
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserReputation {
+    /// User the record belongs to
+    pub user: Address,
+    /// Number of correct predictions and dispute votes
+    pub correct_predictions: u32,
+    /// Number of scored predictions and dispute votes
+    pub total_predictions: u32,
+}
+
+// ===== REPUTATION MANAGER =====
+
+/// Records prediction accuracy and derives reputation scores from it
+pub struct ReputationManager;
+
+impl ReputationManager {
+    /// Score every voter of a resolved market against its winning outcome.
+    pub fn record_market_outcome(env: &Env, market: &Market) {
+        let winning_outcome = match &market.winning_outcome {
+            Some(outcome) => outcome,
+            None => return,
+        };
+
+        for (user, outcome) in market.votes.iter() {
+            Self::record_prediction(env, &user, outcome == *winning_outcome);
+        }
+    }
+
+    /// Add a scored prediction to a user's record.
+    pub fn record_prediction(env: &Env, user: &Address, correct: bool) {
+        let mut reputation = Self::get_reputation(env, user);
+        reputation.total_predictions += 1;
+        if correct {
+            reputation.correct_predictions += 1;
+        }
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("rep"), user.clone()), &reputation);
+    }
+
+    /// Get a user's prediction track record.
+    pub fn get_reputation(env: &Env, user: &Address) -> UserReputation {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("rep"), user.clone()))
+            .unwrap_or(UserReputation {
+                user: user.clone(),
+                correct_predictions: 0,
+                total_predictions: 0,
+            })
+    }
+
+    /// Get a user's accuracy score as a percentage.
+    ///
+    /// The score is smoothed towards 50 so that users without a track record
+    /// start in the middle and a single prediction cannot max it out.
+    pub fn get_accuracy_score(env: &Env, user: &Address) -> i128 {
+        let reputation = Self::get_reputation(env, user);
+        ((reputation.correct_predictions as i128 + 1) * 100)
+            / (reputation.total_predictions as i128 + 2)
+    }
+}
@@ -1,10 +1,15 @@
 use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 
+use crate::bonds::BondManager;
+use crate::disputes::{DisputeManager, DisputeUtils};
 use crate::errors::Error;
+use crate::events::{EventEmitter, EventLogger, MarketActivityKind};
+use crate::fees::FeeManager;
 
 use crate::markets::{CommunityConsensus, MarketAnalytics, MarketStateManager, MarketUtils};
 
 use crate::oracles::{OracleFactory, OracleUtils};
+use crate::reputation::ReputationManager;
 use crate::types::*;
 
 /// Resolution management system for Predictify Hybrid contract
@@ -375,6 +380,7 @@ pub struct MarketResolution {
 /// **Manual Methods:**
 /// - **Admin Override**: Administrative decision for exceptional circumstances
 /// - **Dispute Resolution**: Outcome determined through formal dispute process
+/// - **Invalid**: No outcome is fair; the market is voided and stakes refunded
 ///
 /// # Method Selection Logic
 ///
@@ -444,6 +450,9 @@ pub struct MarketResolution {
 ///     ResolutionMethod::DisputeResolution => {
 ///         println!("Using dispute resolution - conflicting data sources");
 ///     },
+///     ResolutionMethod::Invalid => {
+///         println!("Market voided - stakes refunded");
+///     },
 /// }
 /// ```
 ///
@@ -479,6 +488,12 @@ pub struct MarketResolution {
 /// - **Time**: Longest resolution time
 /// - **Use Case**: Contested or controversial outcomes
 ///
+/// **Invalid:**
+/// - **Outcome**: None; the market moves to `Cancelled`
+/// - **Payouts**: Every voter is refunded their stake, less the configured
+///   invalid market fee
+/// - **Use Case**: Ill-posed questions where neither outcome is fair
+///
 /// # Integration with Confidence Scoring
 ///
 /// Different methods contribute to confidence scores:
@@ -500,6 +515,17 @@ pub enum ResolutionMethod {
     AdminOverride,
     /// Dispute resolution
     DisputeResolution,
+    /// Market declared invalid and stakes refunded
+    Invalid,
+}
+
+/// Why a market is being voided, which decides what happens to its creation bond.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InvalidationReason {
+    /// The admin judged the question unresolvable; the creator gets the bond back
+    AdminVerdict,
+    /// Upheld disputes showed the market to be invalid; the bond is slashed
+    Dispute,
 }
 
 /// Comprehensive analytics and metrics for resolution system performance.
@@ -1212,6 +1238,111 @@ impl OracleResolutionManager {
 pub struct MarketResolutionManager;
 
 impl MarketResolutionManager {
+    /// Apply the economic effects of a market resolving to a valid outcome.
+    ///
+    /// Freezes the fee rate every claim on the market pays, scores every
+    /// voter's prediction for their reputation, logs the resolution in the
+    /// market's activity and returns the creator's bond. If the market was
+    /// disputed, its dispute bonds and votes are settled too: the disputes
+    /// are upheld when the final outcome differs from the oracle result.
+    pub fn settle_resolved_market(env: &Env, market_id: &Symbol) -> Result<(), Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if let Some(outcome) = &market.winning_outcome {
+            EventLogger::record_market_activity(
+                env,
+                market_id,
+                MarketActivityKind::Resolution,
+                &env.current_contract_address(),
+                outcome,
+                0,
+            );
+        }
+        ReputationManager::record_market_outcome(env, &market);
+        FeeManager::freeze_claim_fee(env, market_id)?;
+        BondManager::release_bond(env, market_id)?;
+
+        if market.total_dispute_stakes() > 0
+            && DisputeUtils::get_dispute_settlement(env, market_id).is_none()
+        {
+            let upheld = market.oracle_result.as_ref() != market.winning_outcome.as_ref();
+            DisputeManager::settle_disputes(env, &market, market_id, upheld)?;
+        }
+        Ok(())
+    }
+
+    /// Declare a market invalid and void it.
+    ///
+    /// The market moves to `MarketState::Cancelled` without a winning
+    /// outcome, so every voter can claim back their stake less the market's
+    /// `invalid_market_fee_percentage`. Predictions are not scored. Any
+    /// creation bond is slashed when disputes voided the market and returned
+    /// to the creator on an admin verdict. Callers are responsible for
+    /// authorization and for settling the market's disputes.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketAlreadyResolved` - The market already has a winning outcome
+    /// - `Error::InvalidState` - The market cannot move to `Cancelled`, or an
+    ///   admin verdict targets a disputed market
+    pub fn void_market(
+        env: &Env,
+        market_id: &Symbol,
+        reason: InvalidationReason,
+    ) -> Result<MarketResolution, Error> {
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.winning_outcome.is_some() {
+            return Err(Error::MarketAlreadyResolved);
+        }
+        if reason == InvalidationReason::AdminVerdict
+            && (market.state == MarketState::Disputed || market.total_dispute_stakes() > 0)
+        {
+            return Err(Error::InvalidState);
+        }
+
+        MarketStateManager::end_market_if_expired(env, market_id, &mut market);
+        MarketStateManager::transition_state(env, market_id, &mut market, MarketState::Cancelled)?;
+        MarketStateManager::update_market(env, market_id, &market);
+
+        // Invalid markets have no final outcome
+        let verdict = String::from_str(env, "");
+        let oracle_result = market
+            .oracle_result
+            .clone()
+            .unwrap_or_else(|| String::from_str(env, ""));
+        let community_consensus = MarketAnalytics::calculate_community_consensus(&market);
+        EventEmitter::emit_market_resolved(
+            env,
+            market_id,
+            &verdict,
+            &oracle_result,
+            &community_consensus.outcome,
+            &String::from_str(env, "Invalid"),
+            100,
+        );
+        EventLogger::record_market_activity(
+            env,
+            market_id,
+            MarketActivityKind::Resolution,
+            &env.current_contract_address(),
+            &verdict,
+            0,
+        );
+        match reason {
+            InvalidationReason::AdminVerdict => BondManager::release_bond(env, market_id)?,
+            InvalidationReason::Dispute => BondManager::slash_bond(env, market_id)?,
+        }
+
+        Ok(MarketResolution {
+            market_id: market_id.clone(),
+            final_outcome: verdict,
+            oracle_result,
+            community_consensus,
+            resolution_timestamp: env.ledger().timestamp(),
+            resolution_method: ResolutionMethod::Invalid,
+            confidence_score: 100,
+        })
+    }
+
     /// Resolve a market by combining oracle results and community votes
     pub fn resolve_market(env: &Env, market_id: &Symbol) -> Result<MarketResolution, Error> {
         // Get the market from storage
@@ -1259,9 +1390,12 @@ impl MarketResolutionManager {
         };
 
         // Set winning outcome
+        MarketStateManager::end_market_if_expired(env, market_id, &mut market);
         MarketStateManager::set_winning_outcome(&mut market, final_result.clone(), Some(market_id));
         MarketStateManager::update_market(env, market_id, &market);
 
+        Self::settle_resolved_market(env, market_id)?;
+
         Ok(resolution)
     }
 
@@ -1296,9 +1430,12 @@ impl MarketResolutionManager {
         };
 
         // Set final outcome
+        MarketStateManager::end_market_if_expired(env, market_id, &mut market);
         MarketStateManager::set_winning_outcome(&mut market, outcome.clone(), Some(market_id));
         MarketStateManager::update_market(env, market_id, &market);
 
+        Self::settle_resolved_market(env, market_id)?;
+
         Ok(resolution)
     }
 
@@ -1515,6 +1652,7 @@ impl MarketResolutionAnalytics {
             }
             ResolutionMethod::AdminOverride => 100,
             ResolutionMethod::DisputeResolution => 75,
+            ResolutionMethod::Invalid => 100,
         }
     }
 
@@ -78,6 +78,8 @@ pub struct StorageConfig {
     pub auto_cleanup_enabled: bool,
     /// Compression algorithm preference
     pub preferred_compression: String,
+    /// Maximum number of entries kept in each market's activity log
+    pub activity_log_capacity: u32,
 }
 
 /// Storage migration record
@@ -176,6 +178,9 @@ impl StorageOptimizer {
                 
                 // Remove from storage
                 MarketStateManager::remove_market(env, market_id);
+                events::EventLogger::clear_market_activity(env, market_id);
+                metadata::MarketMetadataManager::remove_metadata(env, market_id);
+                metadata::MarketMetadataManager::remove_resolution_criteria(env, market_id);
                 
                 // Emit cleanup event
                 events::EventEmitter::emit_storage_cleanup_event(
@@ -402,12 +407,20 @@ impl StorageOptimizer {
                 cleanup_threshold_days: 365,
                 auto_cleanup_enabled: false,
                 preferred_compression: String::from_str(env, "simple_optimization"),
+                activity_log_capacity: events::DEFAULT_ACTIVITY_LOG_CAPACITY,
             },
         }
     }
     
     /// Update storage configuration
+    ///
+    /// Returns `Error::InvalidInput` if the activity log capacity exceeds
+    /// `events::MAX_ACTIVITY_LOG_CAPACITY`.
     pub fn update_storage_config(env: &Env, config: &StorageConfig) -> Result<(), Error> {
+        if config.activity_log_capacity > events::MAX_ACTIVITY_LOG_CAPACITY {
+            return Err(Error::InvalidInput);
+        }
+
         env.storage()
             .persistent()
             .set(&Symbol::new(env, "storage_config"), config);
@@ -475,7 +488,11 @@ impl StorageOptimizer {
     /// Archive market data before deletion
     fn archive_market_data(env: &Env, market_id: &Symbol, market: &Market) -> Result<(), Error> {
         // Store archived version with timestamp
-        let archive_key = Symbol::new(env, &format!("archive_{:?}_{}", market_id, env.ledger().timestamp()));
+        let archive_key = (
+            Symbol::new(env, "archive"),
+            market_id.clone(),
+            env.ledger().timestamp(),
+        );
         env.storage().persistent().set(&archive_key, market);
         Ok(())
     }
@@ -17,11 +17,13 @@
 #![cfg(test)]
 
 use super::*;
+use crate::config::DisputeVoteWeighting;
 
 use soroban_sdk::{
-    testutils::{Address as _, Ledger, LedgerInfo},
+    symbol_short,
+    testutils::{Address as _, Events, Ledger, LedgerInfo},
     token::{self, StellarAssetClient},
-    vec, String, Symbol,
+    vec, IntoVal, String, Symbol, TryFromVal, Val,
 };
 
 // Test setup structures
@@ -57,6 +59,10 @@ pub struct PredictifyTest {
 
 impl PredictifyTest {
     pub fn setup() -> Self {
+        Self::setup_with_environment(Environment::Development)
+    }
+
+    pub fn setup_with_environment(environment: Environment) -> Self {
         let token_test = TokenTest::setup();
         let env = token_test.env.clone();
 
@@ -70,7 +76,7 @@ impl PredictifyTest {
         // Initialize contract
         let contract_id = env.register(PredictifyHybrid, ());
         let client = PredictifyHybridClient::new(&env, &contract_id);
-        client.initialize(&admin);
+        client.initialize(&admin, &environment);
 
         // Set token for staking
         env.as_contract(&contract_id, || {
@@ -175,20 +181,670 @@ fn test_create_market_successful() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #100)")] // Unauthorized = 100
 fn test_create_market_with_non_admin() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
-    let outcomes = vec![
-        &test.env,
-        String::from_str(&test.env, "yes"),
-        String::from_str(&test.env, "no"),
-    ];
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let balance_before = token_client.balance(&test.user);
+
+    // Non-admins can create markets by posting a creation bond
+    let market_id = create_user_market(&test);
+
+    let bond = client.get_creation_bond(&market_id).unwrap();
+    assert_eq!(bond.creator, test.user);
+    assert_eq!(bond.status, crate::bonds::BondStatus::Held);
+    assert_eq!(
+        token_client.balance(&test.user),
+        balance_before - bond.amount
+    );
+
+    // Markets created by the admin need no bond
+    let admin_market = test.create_test_market();
+    assert!(client.get_creation_bond(&admin_market).is_none());
+
+    // A clean resolution returns the bond
+    client.approve_market(&test.admin, &market_id);
+    test.env
+        .ledger()
+        .set_timestamp(test.env.ledger().timestamp() + 31 * 24 * 60 * 60);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    let bond = client.get_creation_bond(&market_id).unwrap();
+    assert_eq!(bond.status, crate::bonds::BondStatus::Returned);
+    assert_eq!(token_client.balance(&test.user), balance_before);
+}
+
+#[test]
+fn test_admin_invalid_verdict_returns_creation_bond() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let balance_before = token_client.balance(&test.user);
+    let market_id = create_user_market(&test);
+    client.approve_market(&test.admin, &market_id);
+
+    test.env
+        .ledger()
+        .set_timestamp(test.env.ledger().timestamp() + 31 * 24 * 60 * 60);
+    client.resolve_market_invalid(&test.admin, &market_id);
+
+    // Nothing was disputed, so the creator is not penalised
+    let bond = client.get_creation_bond(&market_id).unwrap();
+    assert_eq!(bond.status, crate::bonds::BondStatus::Returned);
+    assert_eq!(token_client.balance(&test.user), balance_before);
+    let treasury = client.get_treasury_balance(&test.token_test.token_id);
+    assert_eq!(treasury.total_accrued, 0);
+}
+
+#[test]
+fn test_cancelled_disputed_market_slashes_creation_bond() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = create_user_market(&test);
+    let disputer = Address::generate(&test.env);
+    StellarAssetClient::new(&test.env, &test.token_test.token_id).mint(&disputer, &100_0000000);
+    client.approve_market(&test.admin, &market_id);
+
+    test.env
+        .ledger()
+        .set_timestamp(test.env.ledger().timestamp() + 31 * 24 * 60 * 60);
+    test.env.as_contract(&test.contract_id, || {
+        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        market.oracle_result = Some(String::from_str(&test.env, "yes"));
+        test.env.storage().persistent().set(&market_id, &market);
+
+        crate::disputes::DisputeManager::process_dispute(
+            &test.env,
+            disputer.clone(),
+            market_id.clone(),
+            10_000_000,
+            None,
+        )
+        .unwrap();
+    });
+
+    client.cancel_disputed_market(&test.admin, &market_id);
+
+    let bond = client.get_creation_bond(&market_id).unwrap();
+    assert_eq!(bond.status, crate::bonds::BondStatus::Slashed);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Cancelled
+    );
+
+    // The slashed bond is booked as treasury revenue
+    let treasury = client.get_treasury_balance(&test.token_test.token_id);
+    assert_eq!(treasury.total_accrued, bond.amount);
+}
+
+#[test]
+fn test_admins_with_extend_permission_extend_bonded_markets() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = create_user_market(&test);
+    client.approve_market(&test.admin, &market_id);
+    let reason = String::from_str(&test.env, "Awaiting official results");
+
+    // The bonded creator holds no admin permission
+    assert_eq!(
+        client.try_extend_market(&test.user, &market_id, &1, &reason, &0),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // The primary admin can extend a market they did not create
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    client.extend_market(&test.admin, &market_id, &1, &reason, &0);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().end_time,
+        end_time + 24 * 60 * 60
+    );
+
+    // So can an admin whose role grants ExtendMarket
+    let market_admin = Address::generate(&test.env);
+    test.env.as_contract(&test.contract_id, || {
+        crate::admin::AdminRoleManager::assign_role(
+            &test.env,
+            &market_admin,
+            crate::admin::AdminRole::MarketAdmin,
+            &test.admin,
+        )
+        .unwrap();
+    });
+    client.extend_market(&market_admin, &market_id, &2, &reason, &0);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().end_time,
+        end_time + 3 * 24 * 60 * 60
+    );
+}
+
+/// Dispute a market resolved by the oracle as "yes" and have two users vote
+/// on the dispute, one on each side
+fn dispute_with_votes(
+    test: &PredictifyTest,
+    market_id: &Symbol,
+    disputer: &Address,
+    supporter: &Address,
+    opponent: &Address,
+) {
+    let token_admin = StellarAssetClient::new(&test.env, &test.token_test.token_id);
+    for user in [disputer, supporter, opponent] {
+        token_admin.mint(user, &100_0000000);
+    }
+
+    test.env
+        .ledger()
+        .set_timestamp(test.env.ledger().timestamp() + 31 * 24 * 60 * 60);
+    test.env.as_contract(&test.contract_id, || {
+        let mut market: Market = test.env.storage().persistent().get(market_id).unwrap();
+        market.oracle_result = Some(String::from_str(&test.env, "yes"));
+        test.env.storage().persistent().set(market_id, &market);
+
+        crate::disputes::DisputeManager::process_dispute(
+            &test.env,
+            disputer.clone(),
+            market_id.clone(),
+            10_0000000,
+            None,
+        )
+        .unwrap();
+    });
+
+    for (voter, support) in [(supporter, true), (opponent, false)] {
+        test.env.as_contract(&test.contract_id, || {
+            crate::disputes::DisputeManager::vote_on_dispute(
+                &test.env,
+                voter.clone(),
+                market_id.clone(),
+                market_id.clone(),
+                support,
+                5_0000000,
+                None,
+            )
+            .unwrap();
+        });
+    }
+}
+
+#[test]
+fn test_upheld_dispute_rewards_disputers_and_supporting_voters() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    let (disputer, supporter, opponent) = (
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+    );
+    dispute_with_votes(&test, &market_id, &disputer, &supporter, &opponent);
+    assert!(client.get_dispute_settlement(&market_id).is_none());
+
+    // Voiding a disputed market must go through the dispute settlement
+    assert_eq!(
+        client
+            .try_resolve_market_invalid(&test.admin, &market_id)
+            .err(),
+        Some(Ok(Error::InvalidState))
+    );
+    client.cancel_disputed_market(&test.admin, &market_id);
+
+    // Disputers earn 20% of their bond out of the opposing voters' stakes;
+    // supporting voters split the rest of those stakes
+    let settlement = client.get_dispute_settlement(&market_id).unwrap();
+    assert!(settlement.upheld);
+    assert_eq!(settlement.disputer_reward, 2_0000000);
+    assert_eq!(settlement.voter_reward, 3_0000000);
+    assert_eq!(settlement.slashed, 0);
+
+    assert_eq!(
+        client.claim_dispute_payout(&disputer, &market_id),
+        12_0000000
+    );
+    assert_eq!(
+        client.claim_dispute_payout(&supporter, &market_id),
+        8_0000000
+    );
+    assert_eq!(token_client.balance(&disputer), 102_0000000);
+    assert_eq!(token_client.balance(&supporter), 103_0000000);
+    assert_eq!(
+        client.try_claim_dispute_payout(&opponent, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+    assert_eq!(
+        client.try_claim_dispute_payout(&disputer, &market_id),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+}
+
+#[test]
+fn test_rejected_dispute_slashes_disputer_bond() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let (disputer, supporter, opponent) = (
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+    );
+    dispute_with_votes(&test, &market_id, &disputer, &supporter, &opponent);
+
+    // The oracle result stands, so the disputer loses their bond
+    let resolution = test.env.as_contract(&test.contract_id, || {
+        crate::disputes::DisputeManager::resolve_dispute(
+            &test.env,
+            market_id.clone(),
+            test.admin.clone(),
+        )
+        .unwrap()
+    });
+    assert_eq!(resolution.final_outcome, String::from_str(&test.env, "yes"));
+
+    let settlement = client.get_dispute_settlement(&market_id).unwrap();
+    assert!(!settlement.upheld);
+    assert_eq!(settlement.slashed, 10_0000000);
+    let treasury = client.get_treasury_balance(&test.token_test.token_id);
+    assert_eq!(treasury.total_accrued, 10_0000000);
+
+    // The opposing voter takes the supporting voter's stake
+    assert_eq!(
+        client.claim_dispute_payout(&opponent, &market_id),
+        10_0000000
+    );
+    assert_eq!(
+        client.try_claim_dispute_payout(&supporter, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+    assert_eq!(
+        client.try_claim_dispute_payout(&disputer, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_manual_resolution_of_disputed_market_settles_disputes() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    let (disputer, supporter, opponent) = (
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+    );
+    dispute_with_votes(&test, &market_id, &disputer, &supporter, &opponent);
+
+    // The admin overturns the oracle result, so the disputes are upheld
+    test.env.mock_all_auths();
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "no"));
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Resolved
+    );
+
+    let settlement = client.get_dispute_settlement(&market_id).unwrap();
+    assert!(settlement.upheld);
+    assert_eq!(settlement.disputer_reward, 2_0000000);
+    assert_eq!(
+        client.claim_dispute_payout(&disputer, &market_id),
+        12_0000000
+    );
+    assert_eq!(token_client.balance(&disputer), 102_0000000);
+    assert_eq!(
+        client.try_claim_dispute_payout(&opponent, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_dispute_escalation_rounds_double_bonds_until_arbitration() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let (disputer, supporter, opponent) = (
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+    );
+    dispute_with_votes(&test, &market_id, &disputer, &supporter, &opponent);
+    StellarAssetClient::new(&test.env, &test.token_test.token_id).mint(&disputer, &100_0000000);
+    let reason = String::from_str(&test.env, "Appeal");
+    let next_round = || {
+        test.env
+            .ledger()
+            .set_timestamp(test.env.ledger().timestamp() + 25 * 60 * 60);
+    };
+
+    let vote = |support: bool, stake: i128| {
+        let voter = Address::generate(&test.env);
+        StellarAssetClient::new(&test.env, &test.token_test.token_id).mint(&voter, &stake);
+        test.env.as_contract(&test.contract_id, || {
+            crate::disputes::DisputeManager::vote_on_dispute(
+                &test.env,
+                voter,
+                market_id.clone(),
+                market_id.clone(),
+                support,
+                stake,
+                None,
+            )
+            .unwrap();
+        });
+    };
+
+    // Rounds can only be escalated once the current voting window closes
+    assert_eq!(
+        client.try_escalate_dispute(&disputer, &market_id, &reason),
+        Err(Ok(Error::DisputeEscalationNotAllowed))
+    );
+    next_round();
+
+    // The tied round upholds the oracle, so only the dispute's side may appeal
+    assert_eq!(
+        client.try_escalate_dispute(&opponent, &market_id, &reason),
+        Err(Ok(Error::DisputeEscalationNotAllowed))
+    );
+    // The first bond doubles the 10 XLM dispute stake
+    let first = client.escalate_dispute(&disputer, &market_id, &reason);
+    assert_eq!(first.escalation_level, 1);
+    assert_eq!(first.bond, 20_0000000);
+    assert!(first.supports_dispute);
+    assert!(!first.requires_admin_review);
+    assert_eq!(
+        client.get_dispute_timeout(&market_id).expires_at,
+        first.round_end
+    );
+    assert_eq!(
+        client.try_escalate_dispute(&opponent, &market_id, &reason),
+        Err(Ok(Error::DisputeEscalationNotAllowed))
+    );
 
+    // The appeal round swings to the dispute; now its opponents appeal
+    vote(true, 10_0000000);
+    next_round();
+    assert_eq!(
+        client.try_escalate_dispute(&disputer, &market_id, &reason),
+        Err(Ok(Error::DisputeEscalationNotAllowed))
+    );
+    let second = client.escalate_dispute(&opponent, &market_id, &reason);
+    assert_eq!(second.bond, 40_0000000);
+    assert!(!second.supports_dispute);
+
+    // The bond doubles the recorded bond even if the minimum stake changes
+    test.env.as_contract(&test.contract_id, || {
+        let mut config =
+            crate::config::ConfigManager::get_market_runtime_config(&test.env, &market_id);
+        config.voting.min_dispute_stake *= 10;
+        test.env
+            .storage()
+            .persistent()
+            .set(&(symbol_short!("mkt_cfg"), market_id.clone()), &config);
+    });
+
+    // The final round goes to the admin arbiter
+    vote(false, 20_0000000);
+    next_round();
+    let last = client.escalate_dispute(&disputer, &market_id, &reason);
+    assert_eq!(last.escalation_level, 3);
+    assert_eq!(last.bond, 80_0000000);
+    assert!(last.requires_admin_review);
+    assert_eq!(
+        client.get_dispute_timeout(&market_id).expires_at,
+        last.round_end
+    );
+    next_round();
+    assert_eq!(
+        client.try_escalate_dispute(&opponent, &market_id, &reason),
+        Err(Ok(Error::DisputeEscalationNotAllowed))
+    );
+    assert_eq!(client.get_dispute_rounds(&market_id).len(), 3);
+
+    // The oracle result stands: the disputer's bond and escalation bonds are
+    // slashed, and the opponent gets their bond back with their vote winnings
+    test.env.as_contract(&test.contract_id, || {
+        crate::disputes::DisputeManager::resolve_dispute(
+            &test.env,
+            market_id.clone(),
+            test.admin.clone(),
+        )
+        .unwrap()
+    });
+    let settlement = client.get_dispute_settlement(&market_id).unwrap();
+    assert_eq!(settlement.slashed, 10_0000000 + 20_0000000 + 80_0000000);
+    assert_eq!(
+        client.claim_dispute_payout(&opponent, &market_id),
+        40_0000000 + 5_0000000 + 5_0000000 * 15 / 25
+    );
+}
+
+#[test]
+fn test_dispute_vote_weighting_strategies() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_0000000,
+        &None,
+    );
+
+    // The market counts each address with at least 1 XLM staked once
+    let mut resolution_config = client.get_effective_market_config(&market_id).resolution;
+    resolution_config.dispute_vote_weighting = DisputeVoteWeighting::OneAddressOneVote;
+    client.set_market_resolution_config(&test.admin, &market_id, &resolution_config);
+
+    let (disputer, supporter, opponent) = (
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+    );
+    dispute_with_votes(&test, &market_id, &disputer, &supporter, &opponent);
+
+    // A whale opposes the dispute; small holders and a dust voter support it
+    let token_admin = StellarAssetClient::new(&test.env, &test.token_test.token_id);
+    for (stake, support) in [
+        (45_0000000, false),
+        (1_0000000, true),
+        (1_0000000, true),
+        (1000000, true),
+    ] {
+        let voter = Address::generate(&test.env);
+        token_admin.mint(&voter, &stake);
+        test.env.as_contract(&test.contract_id, || {
+            crate::disputes::DisputeManager::vote_on_dispute(
+                &test.env,
+                voter,
+                market_id.clone(),
+                market_id.clone(),
+                support,
+                stake,
+                None,
+            )
+            .unwrap();
+        });
+    }
+
+    let tally = |weighting| {
+        test.env.as_contract(&test.contract_id, || {
+            let mut config = crate::config::ConfigManager::get_default_resolution_config();
+            config.dispute_vote_weighting = weighting;
+            crate::disputes::DisputeUtils::calculate_weighted_tally(&test.env, &market_id, &config)
+        })
+    };
+    assert_eq!(tally(DisputeVoteWeighting::Stake), (7_1000000, 50_0000000));
+    assert_eq!(
+        tally(DisputeVoteWeighting::QuadraticStake),
+        (7071 + 3162 * 2 + 1000, 7071 + 21213)
+    );
+    assert_eq!(tally(DisputeVoteWeighting::Reputation), (150, 100));
+    assert_eq!(tally(DisputeVoteWeighting::OneAddressOneVote), (3, 2));
+
+    let resolution = test.env.as_contract(&test.contract_id, || {
+        crate::disputes::DisputeManager::resolve_dispute(
+            &test.env,
+            market_id.clone(),
+            test.admin.clone(),
+        )
+        .unwrap()
+    });
+    assert_eq!(
+        resolution.vote_weighting,
+        DisputeVoteWeighting::OneAddressOneVote
+    );
+    assert_eq!(
+        (resolution.support_weight, resolution.against_weight),
+        (3, 2)
+    );
+    assert_eq!(
+        client.try_set_market_resolution_config(&test.admin, &market_id, &resolution_config),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // Resolution scores the market's voters and the dispute's voters
+    let reputation = client.get_user_reputation(&test.user);
+    assert_eq!(reputation.correct_predictions, 1);
+    assert_eq!(reputation.total_predictions, 1);
+    assert_eq!(client.get_user_reputation(&opponent).correct_predictions, 1);
+    assert_eq!(
+        client.get_user_reputation(&supporter).correct_predictions,
+        0
+    );
+}
+
+#[test]
+fn test_market_curation_queue() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let balance_before = token_client.balance(&test.user);
+
+    // User-created markets wait for review and accept no votes
+    let approved = create_user_market(&test);
+    let rejected = create_user_market(&test);
+    assert_eq!(client.get_pending_markets().len(), 2);
+    assert_eq!(
+        client.get_market(&approved).unwrap().state,
+        MarketState::Pending
+    );
+    assert!(client
+        .try_vote(
+            &test.user,
+            &approved,
+            &String::from_str(&test.env, "yes"),
+            &1_0000000,
+            &None,
+        )
+        .is_err());
+
+    // Only market admins can review
+    assert_eq!(
+        client.try_approve_market(&test.user, &approved),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // The reviewer can fix the oracle configuration before approving
+    let oracle_config = OracleConfig {
+        provider: OracleProvider::Reflector,
+        feed_id: String::from_str(&test.env, "ETH"),
+        threshold: 3000000,
+        comparison: String::from_str(&test.env, "lt"),
+    };
+    client.edit_pending_market_oracle(&test.admin, &approved, &oracle_config);
+
+    // Time spent in review does not shorten the voting period
+    let end_time = client.get_market(&approved).unwrap().end_time;
+    test.env
+        .ledger()
+        .set_timestamp(test.env.ledger().timestamp() + 24 * 60 * 60);
+    client.approve_market(&test.admin, &approved);
+
+    let market = client.get_market(&approved).unwrap();
+    assert_eq!(market.state, MarketState::Active);
+    assert_eq!(market.oracle_config, oracle_config);
+    assert_eq!(market.end_time, end_time + 24 * 60 * 60);
+
+    // Rejection cancels the market and refunds the bond
+    client.reject_market(&test.admin, &rejected);
+    assert_eq!(
+        client.get_market(&rejected).unwrap().state,
+        MarketState::Cancelled
+    );
+    assert_eq!(
+        client.get_creation_bond(&rejected).unwrap().status,
+        crate::bonds::BondStatus::Returned
+    );
+    assert!(client.try_approve_market(&test.admin, &rejected).is_err());
+
+    // Rejected proposals take no stakes, even before their end time
+    assert!(test.env.ledger().timestamp() < client.get_market(&rejected).unwrap().end_time);
+    assert_eq!(
+        client
+            .try_vote(
+                &test.admin,
+                &rejected,
+                &String::from_str(&test.env, "yes"),
+                &1_0000000,
+                &None,
+            )
+            .err(),
+        Some(Ok(soroban_sdk::Error::from_contract_error(
+            Error::MarketClosed as u32
+        )))
+    );
+
+    assert_eq!(client.get_pending_markets().len(), 0);
+    let bond_amount = client.get_creation_bond(&approved).unwrap().amount;
+    assert_eq!(
+        token_client.balance(&test.user),
+        balance_before - bond_amount
+    );
+}
+
+#[test]
+fn test_curation_queue_is_bounded() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.cost_estimate().budget().reset_unlimited();
+    StellarAssetClient::new(&test.env, &test.token_test.token_id).mint(&test.user, &100000_0000000);
+
+    let first = create_user_market(&test);
+    for _ in 1..crate::curation::MAX_PENDING_PROPOSALS {
+        create_user_market(&test);
+    }
+    assert_eq!(
+        client.get_pending_markets().len(),
+        crate::curation::MAX_PENDING_PROPOSALS
+    );
+
+    // A full queue takes no new proposals until one is reviewed
+    let mut params = create_market_params(&test);
+    params.admin = test.user.clone();
+    assert_eq!(
+        client.try_create_market_with_params(&params).err(),
+        Some(Ok(soroban_sdk::Error::from_contract_error(
+            Error::InvalidState as u32
+        )))
+    );
+    client.approve_market(&test.admin, &first);
+    let proposed = client.create_market_with_params(&params);
+    assert!(test.env.as_contract(&test.contract_id, || {
+        crate::curation::CurationManager::is_awaiting_review(&test.env, &proposed)
+    }));
+}
+
+fn create_user_market(test: &PredictifyTest) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
     client.create_market(
         &test.user,
         &String::from_str(&test.env, "Will BTC go above $25,000 by December 31?"),
-        &outcomes,
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
         &30,
         &OracleConfig {
             provider: OracleProvider::Reflector,
@@ -196,7 +852,7 @@ fn test_create_market_with_non_admin() {
             threshold: 2500000,
             comparison: String::from_str(&test.env, "gt"),
         },
-    );
+    )
 }
 
 #[test]
@@ -257,6 +913,7 @@ fn test_successful_vote() {
         &market_id,
         &String::from_str(&test.env, "yes"),
         &1_0000000,
+        &None,
     );
 
     let market = test.env.as_contract(&test.contract_id, || {
@@ -272,44 +929,49 @@ fn test_successful_vote() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #102)")] // MarketClosed = 102
-fn test_vote_on_closed_market() {
+fn test_vote_publishes_contract_event() {
     let test = PredictifyTest::setup();
     let market_id = test.create_test_market();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
 
-    // Get market end time and advance past it
-    let market = test.env.as_contract(&test.contract_id, || {
-        test.env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .unwrap()
-    });
-
-    test.env.ledger().set(LedgerInfo {
-        timestamp: market.end_time + 1,
-        protocol_version: 22,
-        sequence_number: test.env.ledger().sequence(),
-        network_id: Default::default(),
-        base_reserve: 10,
-        min_temp_entry_ttl: 1,
-        min_persistent_entry_ttl: 1,
-        max_entry_ttl: 10000,
-    });
-
     test.env.mock_all_auths();
     client.vote(
         &test.user,
         &market_id,
         &String::from_str(&test.env, "yes"),
         &1_0000000,
+        &None,
     );
+
+    // The vote is published under (event type, schema version, market, voter)
+    let topics: Vec<Val> = (
+        symbol_short!("vote"),
+        crate::events::EVENT_SCHEMA_VERSION,
+        market_id.clone(),
+        test.user.clone(),
+    )
+        .into_val(&test.env);
+    let (_, _, data) = test
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|(contract, event_topics, _)| {
+            *contract == test.contract_id && *event_topics == topics
+        })
+        .unwrap();
+    let event = crate::events::VoteCastEvent::try_from_val(&test.env, &data).unwrap();
+    assert_eq!(event.voter, test.user);
+    assert_eq!(event.stake, 1_0000000);
+
+    // Nothing is written to the old fixed storage slot
+    test.env.as_contract(&test.contract_id, || {
+        assert!(!test.env.storage().persistent().has(&symbol_short!("vote")));
+    });
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #108)")] // InvalidOutcome = 108
-fn test_vote_with_invalid_outcome() {
+fn test_market_lifecycle_publishes_one_state_event_per_transition() {
     let test = PredictifyTest::setup();
     let market_id = test.create_test_market();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
@@ -318,14 +980,596 @@ fn test_vote_with_invalid_outcome() {
     client.vote(
         &test.user,
         &market_id,
-        &String::from_str(&test.env, "invalid"),
-        &1_0000000,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+        &None,
+    );
+
+    // Only the latest invocation's events are recorded, so collect the state
+    // changes after each call
+    let topics: Vec<Val> = (
+        symbol_short!("mkt_state"),
+        crate::events::EVENT_SCHEMA_VERSION,
+        market_id.clone(),
+    )
+        .into_val(&test.env);
+    let mut transitions = Vec::new(&test.env);
+    let mut collect_transitions = || {
+        for (contract, event_topics, data) in test.env.events().all().iter() {
+            if contract == test.contract_id && event_topics == topics {
+                let event =
+                    crate::events::MarketStateChangedEvent::try_from_val(&test.env, &data).unwrap();
+                transitions.push_back((event.old_state, event.new_state));
+            }
+        }
+    };
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    collect_transitions();
+    client.collect_fees(&test.admin, &market_id);
+    collect_transitions();
+
+    assert_eq!(
+        transitions,
+        vec![
+            &test.env,
+            (MarketState::Active, MarketState::Ended),
+            (MarketState::Ended, MarketState::Resolved),
+            (MarketState::Resolved, MarketState::Closed),
+        ]
+    );
+
+    // The registry describes the canonical event's layout
+    let registry = client.get_event_registry();
+    let schema = registry
+        .get(String::from_str(&test.env, "MarketStateChanged"))
+        .unwrap();
+    assert_eq!(schema.topic, symbol_short!("mkt_state"));
+    assert_eq!(schema.version, crate::events::EVENT_SCHEMA_VERSION);
+    assert_eq!(
+        schema.topic_keys,
+        vec![&test.env, String::from_str(&test.env, "market_id")]
     );
+    assert_eq!(schema.fields.len(), 4);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #101)")] // MarketNotFound = 101
-fn test_vote_on_nonexistent_market() {
+fn test_market_activity_log_is_bounded_paginated_and_cleaned_up() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    test.env.mock_all_auths();
+    let mut storage_config = client.get_storage_config();
+    storage_config.activity_log_capacity = crate::events::MAX_ACTIVITY_LOG_CAPACITY + 1;
+    assert_eq!(
+        client.try_update_storage_config(&test.admin, &storage_config),
+        Err(Ok(Error::InvalidInput))
+    );
+    storage_config.activity_log_capacity = 2;
+    assert_eq!(
+        client.try_update_storage_config(&test.user, &storage_config),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.update_storage_config(&test.admin, &storage_config);
+
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+        &None,
+    );
+    client.vote(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &50_0000000,
+        &None,
+    );
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    // The oldest vote was dropped to stay within capacity
+    let activity = client.get_market_activity(&market_id, &0, &10);
+    assert_eq!(activity.len(), 2);
+    let resolution = activity.get(0).unwrap();
+    assert_eq!(
+        resolution.kind,
+        crate::events::MarketActivityKind::Resolution
+    );
+    assert_eq!(resolution.outcome, String::from_str(&test.env, "yes"));
+    let vote = activity.get(1).unwrap();
+    assert_eq!(vote.kind, crate::events::MarketActivityKind::Vote);
+    assert_eq!(vote.actor, test.admin);
+    assert_eq!(vote.amount, 50_0000000);
+
+    // Pages are taken from the newest entry
+    assert_eq!(
+        client.get_market_activity(&market_id, &1, &1),
+        vec![&test.env, vote]
+    );
+    assert!(client.get_market_activity(&market_id, &2, &10).is_empty());
+
+    // Cleaning up the closed market removes its log
+    client.collect_fees(&test.admin, &market_id);
+    test.env
+        .ledger()
+        .set_timestamp(end_time + 366 * 24 * 60 * 60);
+    assert!(client.cleanup_old_market_data(&market_id));
+    assert!(client.get_market_activity(&market_id, &0, &10).is_empty());
+}
+
+#[test]
+fn test_list_markets_paginates_and_filters_summaries() {
+    let test = PredictifyTest::setup();
+    let first = test.create_test_market();
+    let second = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &first,
+        &String::from_str(&test.env, "yes"),
+        &1_0000000,
+        &None,
+    );
+    let end_time = client.get_market(&first).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    client.resolve_market_manual(&test.admin, &first, &String::from_str(&test.env, "yes"));
+
+    let all = MarketFilter::all(&test.env);
+    assert_eq!(client.get_market_count(), 2);
+    let page = client.list_markets(&0, &10, &all);
+    assert_eq!(page.markets.len(), 2);
+    assert_eq!(page.next_cursor, None);
+    let summary = page.markets.get(0).unwrap();
+    assert_eq!(summary.market_id, first);
+    assert_eq!(summary.state, MarketState::Resolved);
+    assert_eq!(summary.participant_count, 1);
+    assert_eq!(summary.total_staked, 1_0000000);
+    assert_eq!(
+        summary.winning_outcome,
+        Some(String::from_str(&test.env, "yes"))
+    );
+
+    // Pages continue from the returned cursor
+    let page = client.list_markets(&0, &1, &all);
+    assert_eq!(page.markets.get(0).unwrap().market_id, first);
+    assert_eq!(page.next_cursor, Some(1));
+    let page = client.list_markets(&1, &1, &all);
+    assert_eq!(page.markets.len(), 1);
+    assert_eq!(page.markets.get(0).unwrap().market_id, second);
+    assert_eq!(page.next_cursor, None);
+
+    // Filters are combined
+    let mut active = MarketFilter::all(&test.env);
+    active.states = vec![&test.env, MarketState::Active];
+    active.oracle_providers = vec![&test.env, OracleProvider::Reflector];
+    active.creator = Some(test.admin.clone());
+    let page = client.list_markets(&0, &10, &active).markets;
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().market_id, second);
+
+    let mut other_creator = MarketFilter::all(&test.env);
+    other_creator.creator = Some(test.user.clone());
+    assert!(client
+        .list_markets(&0, &10, &other_creator)
+        .markets
+        .is_empty());
+
+    let mut ended_earlier = MarketFilter::all(&test.env);
+    ended_earlier.end_time_to = Some(end_time - 1);
+    assert!(client
+        .list_markets(&0, &10, &ended_earlier)
+        .markets
+        .is_empty());
+}
+
+#[test]
+fn test_invalid_verdict_cancels_market_and_refunds_stakes() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+
+    // Keep a 2% fee from refunded stakes
+    test.env.as_contract(&test.contract_id, || {
+        let mut config = crate::config::ConfigManager::get_runtime_config(&test.env);
+        config.fees.invalid_market_fee_percentage = 2;
+        crate::config::ConfigManager::store_config(&test.env, &config).unwrap();
+    });
+    let market_id = test.create_test_market();
+
+    test.env.mock_all_auths();
+    for (voter, outcome, stake) in [
+        (&test.user, "yes", 100_0000000),
+        (&test.admin, "no", 50_0000000),
+    ] {
+        client.vote(
+            voter,
+            &market_id,
+            &String::from_str(&test.env, outcome),
+            &stake,
+            &None,
+        );
+    }
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    assert_eq!(
+        client
+            .try_resolve_market_invalid(&test.user, &market_id)
+            .err(),
+        Some(Ok(Error::Unauthorized))
+    );
+    let resolution = client.resolve_market_invalid(&test.admin, &market_id);
+    assert_eq!(
+        resolution.resolution_method,
+        crate::resolution::ResolutionMethod::Invalid
+    );
+    assert_eq!(resolution.final_outcome, String::from_str(&test.env, ""));
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Cancelled);
+    assert_eq!(market.winning_outcome, None);
+    assert!(client
+        .try_resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"))
+        .is_err());
+
+    // Every voter, whatever they voted, gets their stake back less the fee
+    assert_eq!(client.get_claimable_total(&test.user), 98_0000000);
+    let balance_before = token_client.balance(&test.user);
+    let claims = client.claim_all(&test.user, &vec![&test.env, market_id.clone()]);
+    assert_eq!(claims.get(0).unwrap().amount, 98_0000000);
+    assert_eq!(
+        token_client.balance(&test.user),
+        balance_before + 98_0000000
+    );
+    assert_eq!(
+        client.try_claim_all(&test.user, &vec![&test.env, market_id.clone()]),
+        Err(Ok(Error::NothingToClaim))
+    );
+
+    let balance_before = token_client.balance(&test.admin);
+    client.claim_winnings(&test.admin, &market_id);
+    assert_eq!(
+        token_client.balance(&test.admin),
+        balance_before + 49_0000000
+    );
+    assert_eq!(
+        client.try_claim_winnings(&test.admin, &market_id).err(),
+        Some(Ok(soroban_sdk::Error::from_contract_error(
+            Error::AlreadyClaimed as u32
+        )))
+    );
+    let outsider = Address::generate(&test.env);
+    assert_eq!(
+        client.try_claim_winnings(&outsider, &market_id).err(),
+        Some(Ok(soroban_sdk::Error::from_contract_error(
+            Error::NothingToClaim as u32
+        )))
+    );
+
+    // The fees kept from both refunds go to the treasury
+    let treasury = client.get_treasury_balance(&test.token_test.token_id);
+    assert_eq!(treasury.total_accrued, 3_0000000);
+}
+
+#[test]
+fn test_outcome_named_invalid_resolves_like_any_other() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let invalid = String::from_str(&test.env, "invalid");
+
+    test.env.mock_all_auths();
+    let market_id = client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will the referee rule the goal invalid?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "valid"),
+            invalid.clone(),
+        ],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            feed_id: String::from_str(&test.env, "BTC"),
+            threshold: 2500000,
+            comparison: String::from_str(&test.env, "gt"),
+        },
+    );
+    client.vote(&test.user, &market_id, &invalid, &10_0000000, &None);
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &invalid);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.winning_outcome, Some(invalid));
+    assert_eq!(
+        client
+            .try_resolve_market_invalid(&test.admin, &market_id)
+            .err(),
+        Some(Ok(Error::MarketAlreadyResolved))
+    );
+}
+
+#[test]
+fn test_market_metadata_is_validated_and_filterable() {
+    let test = PredictifyTest::setup();
+    let first = test.create_test_market();
+    let second = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let env = &test.env;
+
+    let metadata = metadata::MarketMetadata {
+        category: String::from_str(env, "crypto"),
+        tags: vec![
+            env,
+            String::from_str(env, "btc"),
+            String::from_str(env, "price"),
+        ],
+        description: String::from_str(env, "Will BTC close above $100k?"),
+        source_url: String::from_str(env, "https://reflector.network"),
+        image_hash: String::from_str(env, ""),
+    };
+
+    test.env.mock_all_auths();
+    client.set_market_metadata(&test.admin, &first, &metadata);
+    assert_eq!(client.get_market_metadata(&first), Some(metadata.clone()));
+    assert_eq!(client.get_market_metadata(&second), None);
+
+    // Over-long fields and blank tags are rejected
+    let mut too_long = metadata.clone();
+    too_long.category = String::from_str(env, "a-category-name-well-over-the-limit");
+    assert_eq!(
+        client.try_set_market_metadata(&test.admin, &second, &too_long),
+        Err(Ok(Error::InvalidInput))
+    );
+    let mut blank_tag = metadata.clone();
+    blank_tag.tags = vec![env, String::from_str(env, "")];
+    assert_eq!(
+        client.try_set_market_metadata(&test.admin, &second, &blank_tag),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_set_market_metadata(&test.user, &second, &metadata),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Listings filter on category and tag
+    let mut crypto = MarketFilter::all(env);
+    crypto.category = Some(String::from_str(env, "crypto"));
+    let page = client.list_markets(&0, &10, &crypto).markets;
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().market_id, first);
+
+    let mut tagged = MarketFilter::all(env);
+    tagged.tag = Some(String::from_str(env, "price"));
+    assert_eq!(client.list_markets(&0, &10, &tagged).markets.len(), 1);
+    tagged.tag = Some(String::from_str(env, "eth"));
+    assert!(client.list_markets(&0, &10, &tagged).markets.is_empty());
+
+    // Metadata is frozen once voting starts
+    client.vote(
+        &test.user,
+        &first,
+        &String::from_str(env, "yes"),
+        &1_0000000,
+        &None,
+    );
+    assert_eq!(
+        client.try_set_market_metadata(&test.admin, &first, &metadata),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // Proposed markets are editable in review and frozen once approved
+    let proposed = create_user_market(&test);
+    client.set_market_metadata(&test.user, &proposed, &metadata);
+    client.approve_market(&test.admin, &proposed);
+    assert_eq!(
+        client.try_set_market_metadata(&test.user, &proposed, &metadata),
+        Err(Ok(Error::InvalidState))
+    );
+    assert_eq!(
+        client.try_update_resolution_criteria(
+            &test.user,
+            &proposed,
+            &create_market_params(&test).resolution_criteria
+        ),
+        Err(Ok(Error::InvalidState))
+    );
+}
+
+#[test]
+fn test_user_positions_and_claimable_total() {
+    let test = PredictifyTest::setup();
+    let first = test.create_test_market();
+    let second = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let yes = String::from_str(&test.env, "yes");
+
+    test.env.mock_all_auths();
+    client.vote(&test.user, &first, &yes, &100_0000000, &None);
+    client.vote(
+        &test.admin,
+        &first,
+        &String::from_str(&test.env, "no"),
+        &100_0000000,
+        &None,
+    );
+    client.vote(&test.user, &second, &yes, &50_0000000, &None);
+
+    let end_time = client.get_market(&first).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    client.resolve_market_manual(&test.admin, &first, &yes);
+
+    // Positions of markets whose claim fee is not frozen yet preview it
+    test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .remove(&(symbol_short!("fee_brk"), first.clone()));
+    });
+
+    let positions = client.get_user_positions(&test.user, &0, &10);
+    assert_eq!(positions.len(), 2);
+    let won = positions.get(0).unwrap();
+    assert_eq!(won.market_id, first);
+    assert_eq!(won.outcome, yes);
+    assert_eq!(won.stake, 100_0000000);
+    assert_eq!(won.state, MarketState::Resolved);
+    assert!(won.claimable > 100_0000000 && won.claimable < 200_0000000);
+    assert!(!won.claimed);
+    let open = positions.get(1).unwrap();
+    assert_eq!(open.market_id, second);
+    assert_eq!(open.claimable, 0);
+
+    assert_eq!(
+        client.get_user_positions(&test.user, &1, &1),
+        vec![&test.env, open]
+    );
+    assert_eq!(client.get_claimable_total(&test.user), won.claimable);
+    assert_eq!(client.get_claimable_total(&test.admin), 0);
+    assert_eq!(client.get_market_fee_breakdown(&first), None);
+
+    // Claimed positions are no longer claimable
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let balance = token_client.balance(&test.user);
+    client.claim_winnings(&test.user, &first);
+    assert_eq!(token_client.balance(&test.user) - balance, won.claimable);
+    assert!(client.get_market_fee_breakdown(&first).is_some());
+    let claimed = client
+        .get_user_positions(&test.user, &0, &1)
+        .get(0)
+        .unwrap();
+    assert!(claimed.claimed);
+    assert_eq!(claimed.claimable, 0);
+    assert_eq!(client.get_claimable_total(&test.user), 0);
+}
+
+#[test]
+fn test_claim_all_settles_markets_in_one_transfer() {
+    let test = PredictifyTest::setup();
+    let first = test.create_test_market();
+    let second = test.create_test_market();
+    let third = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    // Stakes move into the contract
+    test.env.mock_all_auths();
+    for market_id in [&first, &second, &third] {
+        for (voter, outcome) in [(&test.user, &yes), (&test.admin, &no)] {
+            client.vote(voter, market_id, outcome, &100_0000000, &None);
+        }
+    }
+
+    let end_time = client.get_market(&first).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    client.resolve_market_manual(&test.admin, &first, &yes);
+    client.resolve_market_manual(&test.admin, &second, &yes);
+    client.resolve_market_manual(&test.admin, &third, &no);
+
+    // Unclaimable and repeated markets are skipped
+    let expected = client.get_claimable_total(&test.user);
+    let balance_before = token_client.balance(&test.user);
+    let claims = client.claim_all(
+        &test.user,
+        &vec![&test.env, first.clone(), third.clone(), first.clone()],
+    );
+    assert_eq!(claims.len(), 1);
+    let claim = claims.get(0).unwrap();
+    assert_eq!(claim.market_id, first);
+    assert_eq!(
+        token_client.balance(&test.user),
+        balance_before + claim.amount
+    );
+
+    // The index-driven claim picks up the remaining market
+    let claims = client.claim_all_positions(&test.user);
+    assert_eq!(claims.len(), 1);
+    assert_eq!(claims.get(0).unwrap().market_id, second);
+    assert_eq!(token_client.balance(&test.user), balance_before + expected);
+    assert_treasury_invariant(&test, 600_0000000 - expected);
+
+    // Both claim paths share validation, so settled markets stay settled
+    assert_eq!(
+        client.try_claim_all_positions(&test.user),
+        Err(Ok(Error::NothingToClaim))
+    );
+    assert_eq!(
+        client.try_claim_winnings(&test.user, &second),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            Error::AlreadyClaimed as u32
+        )))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")] // MarketClosed = 102
+fn test_vote_on_closed_market() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    // Get market end time and advance past it
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, Market>(&market_id)
+            .unwrap()
+    });
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &1_0000000,
+        &None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #108)")] // InvalidOutcome = 108
+fn test_vote_with_invalid_outcome() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "invalid"),
+        &1_0000000,
+        &None,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #101)")] // MarketNotFound = 101
+fn test_vote_on_nonexistent_market() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
 
@@ -336,6 +1580,7 @@ fn test_vote_on_nonexistent_market() {
         &nonexistent_market,
         &String::from_str(&test.env, "yes"),
         &1_0000000,
+        &None,
     );
 }
 
@@ -355,6 +1600,7 @@ fn test_authentication_required() {
         &test.market_id,
         &String::from_str(&test.env, "yes"),
         &1_0000000,
+        &None,
     );
 }
 
@@ -374,6 +1620,7 @@ fn test_fee_calculation() {
         &market_id,
         &String::from_str(&test.env, "yes"),
         &100_0000000, // 100 XLM
+        &None,
     );
 
     let market = test.env.as_contract(&test.contract_id, || {
@@ -414,6 +1661,777 @@ fn test_configuration_constants() {
     assert_eq!(crate::config::MAX_FEE_AMOUNT, 1_000_000_000);
 }
 
+#[test]
+fn test_contract_config_update_is_timelocked() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let new_config = crate::config::ConfigManager::get_mainnet_config(&test.env);
+
+    test.env.mock_all_auths();
+    let pending = client.update_contract_config(&test.admin, &new_config);
+    assert_eq!(
+        pending.effective_at,
+        test.env.ledger().timestamp() + crate::config::CONFIG_UPDATE_DELAY_SECONDS
+    );
+    assert!(client.get_pending_config().is_some());
+    assert_eq!(
+        client.get_contract_config().network.environment,
+        Environment::Development
+    );
+
+    client.cancel_config_update(&test.admin);
+    assert!(client.get_pending_config().is_none());
+}
+
+#[test]
+fn test_environment_config_drives_vote_stake_minimum() {
+    // 0.15 XLM: above the testnet minimum, below the mainnet minimum
+    let stake = 1_500_000;
+
+    for (environment, accepted) in [(Environment::Testnet, true), (Environment::Mainnet, false)] {
+        let test = PredictifyTest::setup_with_environment(environment);
+        let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+        let market_id = test.create_test_market();
+
+        test.env.mock_all_auths();
+        let yes = String::from_str(&test.env, "yes");
+        let result = client.try_vote(&test.user, &market_id, &yes, &stake, &None);
+        assert_eq!(result.is_ok(), accepted);
+    }
+}
+
+// Contract token balance must equal stakes still owed to users plus fees
+// the treasury has not paid out yet
+fn assert_treasury_invariant(test: &PredictifyTest, outstanding_stakes: i128) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let treasury = client.get_treasury_balance(&test.token_test.token_id);
+    assert_eq!(
+        token_client.balance(&test.contract_id),
+        outstanding_stakes + treasury.outstanding()
+    );
+}
+
+#[test]
+fn test_fee_treasury_balance_invariant() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_id = test.token_test.token_id.clone();
+    let loser = Address::generate(&test.env);
+    test.env.mock_all_auths();
+    StellarAssetClient::new(&test.env, &token_id).mint(&loser, &1000_0000000);
+
+    // Stakes move into the contract
+    let token_client = token::Client::new(&test.env, &token_id);
+    for (voter, outcome) in [(&test.user, "yes"), (&loser, "no")] {
+        let outcome = String::from_str(&test.env, outcome);
+        client.vote(voter, &market_id, &outcome, &100_0000000, &None);
+    }
+    let mut outstanding_stakes = 200_0000000;
+    assert_eq!(token_client.balance(&loser), 900_0000000);
+    assert_treasury_invariant(&test, outstanding_stakes);
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    let balance_before = token_client.balance(&test.user);
+    client.claim_winnings(&test.user, &market_id);
+    let payout = token_client.balance(&test.user) - balance_before;
+    assert!(payout > 100_0000000);
+    outstanding_stakes -= payout;
+    assert_treasury_invariant(&test, outstanding_stakes);
+
+    // Losing positions are settled without a transfer
+    client.claim_winnings(&loser, &market_id);
+    assert_eq!(token_client.balance(&loser), 900_0000000);
+    assert_treasury_invariant(&test, outstanding_stakes);
+
+    // Fees are carved out of the remaining stakes and stay in the contract
+    let fee = client.collect_fees(&test.admin, &market_id);
+    outstanding_stakes -= fee;
+    assert_eq!(outstanding_stakes, 0);
+    assert_eq!(client.get_withdrawable_fees(&token_id, &test.admin), fee);
+    assert_treasury_invariant(&test, outstanding_stakes);
+
+    // Withdrawal drains the treasury and cannot be repeated
+    assert_eq!(client.withdraw_fees(&test.admin, &token_id), fee);
+    assert!(client.try_withdraw_fees(&test.admin, &token_id).is_err());
+    assert_treasury_invariant(&test, outstanding_stakes);
+
+    let analytics = test.env.as_contract(&test.contract_id, || {
+        crate::fees::FeeAnalytics::calculate_analytics(&test.env).unwrap()
+    });
+    assert_eq!(
+        analytics.total_fees_collected,
+        analytics.total_fees_withdrawn + analytics.treasury_balance
+    );
+    assert_eq!(analytics.total_fees_withdrawn, fee);
+}
+
+#[test]
+fn test_claims_pay_dynamic_fee_frozen_at_resolution() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let loser = Address::generate(&test.env);
+    test.env.mock_all_auths();
+    StellarAssetClient::new(&test.env, &test.token_test.token_id).mint(&loser, &1000_0000000);
+
+    for (voter, outcome) in [(&test.user, "yes"), (&loser, "no")] {
+        let outcome = String::from_str(&test.env, outcome);
+        client.vote(voter, &market_id, &outcome, &1_0000000, &None);
+    }
+    assert!(client.get_market_fee_breakdown(&market_id).is_none());
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    // A 2 XLM market is in the Micro tier: the 2% fee reduced by 10%
+    let breakdown = client.get_market_fee_breakdown(&market_id).unwrap();
+    assert_eq!(breakdown.fee_percentage, 180);
+    assert_eq!(breakdown.fee_amount, 360_000);
+
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let balance_before = token_client.balance(&test.user);
+    client.claim_winnings(&test.user, &market_id);
+    assert_eq!(
+        token_client.balance(&test.user) - balance_before,
+        breakdown.user_payout_amount
+    );
+}
+
+#[test]
+fn test_default_claim_fee_rate_is_configured_platform_fee() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let loser = Address::generate(&test.env);
+    test.env.mock_all_auths();
+    StellarAssetClient::new(&test.env, &test.token_test.token_id).mint(&loser, &1000_0000000);
+
+    for (voter, outcome) in [(&test.user, "yes"), (&loser, "no")] {
+        let outcome = String::from_str(&test.env, outcome);
+        client.vote(voter, &market_id, &outcome, &50_0000000, &None);
+    }
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    // A 100 XLM market with two votes pays the configured 2% unadjusted
+    let breakdown = client.get_market_fee_breakdown(&market_id).unwrap();
+    assert_eq!(breakdown.fee_percentage, 200);
+    assert_eq!(breakdown.fee_amount, 2_0000000);
+}
+
+#[test]
+fn test_fee_exempt_user_claims_without_platform_fee() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let loser = Address::generate(&test.env);
+    test.env.mock_all_auths();
+    StellarAssetClient::new(&test.env, &test.token_test.token_id).mint(&loser, &1000_0000000);
+
+    client.set_fee_exemption(&test.admin, &test.user, &true);
+    assert_eq!(client.get_user_fee_discount(&test.user), 100);
+    assert_eq!(client.get_user_fee_discount(&loser), 0);
+
+    for (voter, outcome) in [(&test.user, "yes"), (&loser, "no")] {
+        let outcome = String::from_str(&test.env, outcome);
+        client.vote(voter, &market_id, &outcome, &1_0000000, &None);
+    }
+    assert_eq!(client.get_user_volume(&test.user), 1_0000000);
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    let breakdown = client.get_market_fee_breakdown(&market_id).unwrap();
+
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let balance_before = token_client.balance(&test.user);
+    client.claim_winnings(&test.user, &market_id);
+    assert_eq!(token_client.balance(&test.user) - balance_before, 2_0000000);
+
+    // The waived fee is rebated out of the market's collectable fees
+    test.env.as_contract(&test.contract_id, || {
+        assert_eq!(
+            crate::fees::FeeManager::get_claim_rebates(&test.env, &market_id),
+            breakdown.fee_amount
+        );
+        let history =
+            crate::fees::FeeManager::get_fee_history(&test.env, market_id.clone()).unwrap();
+        let entry = history.last().unwrap();
+        assert_eq!(entry.admin, test.user);
+        assert_eq!(entry.old_fee_percentage, breakdown.fee_percentage);
+        assert_eq!(entry.new_fee_percentage, 0);
+    });
+}
+
+#[test]
+fn test_referrer_earns_share_of_referred_claim_fee() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let loser = Address::generate(&test.env);
+    let referrer = Address::generate(&test.env);
+    test.env.mock_all_auths();
+    StellarAssetClient::new(&test.env, &test.token_test.token_id).mint(&loser, &1000_0000000);
+
+    // Self-referral is rejected
+    let yes = String::from_str(&test.env, "yes");
+    let self_referral = Some(test.user.clone());
+    assert!(client
+        .try_vote(&test.user, &market_id, &yes, &100_0000000, &self_referral)
+        .is_err());
+
+    client.vote(
+        &test.user,
+        &market_id,
+        &yes,
+        &90_0000000,
+        &Some(referrer.clone()),
+    );
+    client.vote(
+        &loser,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &100_0000000,
+        &None,
+    );
+    assert_eq!(
+        client.get_referrer(&market_id, &test.user),
+        Some(referrer.clone())
+    );
+    assert_eq!(client.get_referrer(&market_id, &loser), None);
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    client.claim_winnings(&test.user, &market_id);
+
+    // The sole winner paid the whole frozen fee; 10% of it goes to the referrer
+    let breakdown = client.get_market_fee_breakdown(&market_id).unwrap();
+    let credit = breakdown.fee_amount / 10;
+    let stats = client.get_referral_stats(&referrer);
+    assert_eq!(stats.referred_votes, 1);
+    assert_eq!(stats.referred_volume, 90_0000000);
+    assert_eq!(stats.total_earned, credit);
+    assert_eq!(
+        client.get_withdrawable_fees(&test.token_test.token_id, &referrer),
+        credit
+    );
+
+    // The credit is not collected a second time
+    let fee = client.collect_fees(&test.admin, &market_id);
+    assert_eq!(fee, breakdown.fee_amount - credit);
+}
+
+#[test]
+fn test_claims_after_fee_collection_do_not_overcommit_treasury() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_id = test.token_test.token_id.clone();
+    let token_client = token::Client::new(&test.env, &token_id);
+    let referred = Address::generate(&test.env);
+    let referrer = Address::generate(&test.env);
+    let loser = Address::generate(&test.env);
+    test.env.mock_all_auths();
+    for voter in [&referred, &loser] {
+        StellarAssetClient::new(&test.env, &token_id).mint(voter, &100_0000000);
+    }
+
+    // One winner pays no fee, the other pays a fee their referrer shares
+    client.set_fee_exemption(&test.admin, &test.user, &true);
+    let yes = String::from_str(&test.env, "yes");
+    client.vote(&test.user, &market_id, &yes, &100_0000000, &None);
+    client.vote(
+        &referred,
+        &market_id,
+        &yes,
+        &100_0000000,
+        &Some(referrer.clone()),
+    );
+    let no = String::from_str(&test.env, "no");
+    client.vote(&loser, &market_id, &no, &100_0000000, &None);
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+
+    // Fees are collected before anyone claims, so both winners' fee shares
+    // are held back for their claims
+    let fee_amount = client
+        .get_market_fee_breakdown(&market_id)
+        .unwrap()
+        .fee_amount;
+    assert_eq!(client.collect_fees(&test.admin, &market_id), 0);
+    assert_treasury_invariant(&test, 300_0000000);
+
+    client.claim_winnings(&test.user, &market_id);
+    assert_treasury_invariant(&test, 300_0000000 - 150_0000000);
+    client.claim_winnings(&referred, &market_id);
+
+    // The exempt winner's share went back to them as a rebate, and the
+    // other winner's discount and referral credit came out of their own
+    // share; the rest of that share reached the treasury
+    let treasury = client.get_treasury_balance(&token_id);
+    let credit = client.get_referral_stats(&referrer).total_earned;
+    assert!(credit > 0);
+    assert!(treasury.outstanding() <= fee_amount / 2);
+    assert_treasury_invariant(&test, 0);
+
+    // Every recipient can withdraw what they are owed
+    let admin_fees = client.withdraw_fees(&test.admin, &token_id);
+    assert_eq!(client.withdraw_fees(&referrer, &token_id), credit);
+    assert_eq!(admin_fees + credit, treasury.outstanding());
+    assert_eq!(token_client.balance(&test.contract_id), 0);
+}
+
+#[test]
+fn test_collect_fees_ignores_fee_bounds_once_withheld() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    // The minimum fee is far above what this market's claims withhold
+    test.env.as_contract(&test.contract_id, || {
+        let mut config = crate::config::ConfigManager::get_runtime_config(&test.env);
+        config.fees.min_fee_amount = 1000_0000000;
+        crate::config::ConfigManager::store_config(&test.env, &config).unwrap();
+    });
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    test.env.mock_all_auths();
+    client.vote(&test.user, &market_id, &yes, &100_0000000, &None);
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    client.claim_winnings(&test.user, &market_id);
+
+    let fee = client.collect_fees(&test.admin, &market_id);
+    assert!(fee > 0);
+    assert!(fee < 1000_0000000);
+    assert_treasury_invariant(&test, 0);
+}
+
+#[test]
+fn test_market_creator_withdraws_fee_share() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_id = test.token_test.token_id.clone();
+    let token_client = token::Client::new(&test.env, &token_id);
+    let creator = test.user.clone();
+    let market_id = create_user_market(&test);
+    client.approve_market(&test.admin, &market_id);
+
+    let winner = Address::generate(&test.env);
+    let loser = Address::generate(&test.env);
+    for (voter, outcome) in [(&winner, "yes"), (&loser, "no")] {
+        StellarAssetClient::new(&test.env, &token_id).mint(voter, &100_0000000);
+        let outcome = String::from_str(&test.env, outcome);
+        client.vote(voter, &market_id, &outcome, &100_0000000, &None);
+    }
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    client.claim_winnings(&winner, &market_id);
+
+    // A 200 XLM market is in the medium tier: the creator earns 20% of the fee
+    let fee = client.collect_fees(&test.admin, &market_id);
+    let creator_fee = fee / 5;
+    assert!(creator_fee > 0);
+    assert_eq!(
+        client.get_withdrawable_fees(&token_id, &creator),
+        creator_fee
+    );
+    let breakdown = client.get_market_fee_breakdown(&market_id).unwrap();
+    assert_eq!(
+        breakdown.platform_fee,
+        breakdown.fee_amount - breakdown.fee_amount / 5
+    );
+
+    let balance_before = token_client.balance(&creator);
+    assert_eq!(client.withdraw_fees(&creator, &token_id), creator_fee);
+    assert_eq!(token_client.balance(&creator), balance_before + creator_fee);
+    assert_eq!(client.get_withdrawable_fees(&token_id, &creator), 0);
+}
+
+fn create_market_params(test: &PredictifyTest) -> MarketCreationParams {
+    MarketCreationParams::new(
+        test.admin.clone(),
+        String::from_str(&test.env, "Who wins the election?"),
+        vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        30,
+        OracleConfig {
+            provider: OracleProvider::Reflector,
+            feed_id: String::from_str(&test.env, "BTC"),
+            threshold: 2500000,
+            comparison: String::from_str(&test.env, "gt"),
+        },
+        10_000_000,
+        ResolutionCriteria {
+            description: String::from_str(&test.env, "Yes if the incumbent is declared winner"),
+            source: String::from_str(&test.env, "Official electoral commission results"),
+            edge_case_rule: String::from_str(
+                &test.env,
+                "If the election is postponed, resolves No",
+            ),
+            invalid_condition: String::from_str(&test.env, "Void if the election is cancelled"),
+        },
+    )
+}
+
+#[test]
+fn test_market_config_overrides_apply_to_single_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let default_market = test.create_test_market();
+
+    let overrides = crate::config::MarketConfigOverrides {
+        min_vote_stake: Some(50_000_000),
+        dispute_extension_hours: Some(72),
+        ..Default::default()
+    };
+
+    test.env.mock_all_auths();
+    let election = client
+        .create_market_with_params(&create_market_params(&test).with_config_overrides(overrides));
+
+    let effective = client.get_effective_market_config(&election);
+    let global = client.get_effective_market_config(&default_market);
+    assert_eq!(effective.voting.min_vote_stake, 50_000_000);
+    assert_eq!(effective.voting.dispute_extension_hours, 72);
+    assert_eq!(global.voting.dispute_extension_hours, 24);
+    assert_eq!(
+        effective.fees.platform_fee_percentage,
+        global.fees.platform_fee_percentage
+    );
+
+    // A 1 XLM vote clears the global minimum but not the election market's
+    let yes = String::from_str(&test.env, "yes");
+    for (market_id, accepted) in [(default_market, true), (election, false)] {
+        let result = client.try_vote(&test.user, &market_id, &yes, &10_000_000, &None);
+        assert_eq!(result.is_ok(), accepted);
+    }
+}
+
+#[test]
+fn test_resolution_criteria_recorded_at_creation_and_shown_in_disputes() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let params = create_market_params(&test);
+
+    test.env.mock_all_auths();
+    let market_id = client.create_market_with_params(&params);
+    assert_eq!(
+        client.get_resolution_criteria(&market_id),
+        Some(params.resolution_criteria.clone())
+    );
+    assert_eq!(client.get_resolution_criteria(&test.market_id), None);
+
+    // Every field is required
+    let mut incomplete = params.clone();
+    incomplete.resolution_criteria.invalid_condition = String::from_str(&test.env, "");
+    assert_eq!(
+        client.try_create_market_with_params(&incomplete).err(),
+        Some(Ok(soroban_sdk::Error::from_contract_error(
+            Error::InvalidInput as u32
+        )))
+    );
+
+    // The criteria can be amended until the first vote
+    let mut amended = params.resolution_criteria.clone();
+    amended.edge_case_rule = String::from_str(&test.env, "If postponed past end time, resolves No");
+    client.update_resolution_criteria(&test.admin, &market_id, &amended);
+    assert_eq!(
+        client.get_resolution_criteria(&market_id),
+        Some(amended.clone())
+    );
+
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &1_0000000,
+        &None,
+    );
+    assert_eq!(
+        client.try_update_resolution_criteria(&test.admin, &market_id, &params.resolution_criteria),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // Disputes carry the criteria they are judged against
+    let disputer = Address::generate(&test.env);
+    StellarAssetClient::new(&test.env, &test.token_test.token_id).mint(&disputer, &100_0000000);
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().set_timestamp(end_time + 1);
+    let event = test.env.as_contract(&test.contract_id, || {
+        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        market.oracle_result = Some(String::from_str(&test.env, "yes"));
+        test.env.storage().persistent().set(&market_id, &market);
+
+        crate::disputes::DisputeManager::process_dispute(
+            &test.env,
+            disputer.clone(),
+            market_id.clone(),
+            10_000_000,
+            Some(String::from_str(&test.env, "The result was announced late")),
+        )
+        .unwrap();
+
+        let topics: Vec<Val> = (
+            symbol_short!("dispt_crt"),
+            crate::events::EVENT_SCHEMA_VERSION,
+            market_id.clone(),
+            disputer.clone(),
+        )
+            .into_val(&test.env);
+        let (_, _, data) = test
+            .env
+            .events()
+            .all()
+            .iter()
+            .find(|(_, event_topics, _)| *event_topics == topics)
+            .unwrap();
+        crate::events::DisputeCreatedEvent::try_from_val(&test.env, &data).unwrap()
+    });
+    assert_eq!(event.resolution_criteria, amended);
+}
+
+#[test]
+fn test_scheduled_market_opens_at_start_time_and_closes_at_cutoff() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let day = 24 * 60 * 60;
+    let start = test.env.ledger().timestamp() + day;
+    let cutoff = start + 10 * day;
+    let yes = String::from_str(&test.env, "yes");
+
+    test.env.mock_all_auths();
+    let market_id = client.create_market_with_params(
+        &create_market_params(&test).with_schedule(Some(start), Some(cutoff)),
+    );
+
+    // Pre-announced markets wait for their start, and run a full period from it
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Pending);
+    assert_eq!(market.end_time, start + 30 * day);
+    assert_eq!(
+        client
+            .try_vote(&test.user, &market_id, &yes, &1_0000000, &None)
+            .err(),
+        Some(Ok(soroban_sdk::Error::from_contract_error(
+            Error::MarketClosed as u32
+        )))
+    );
+
+    test.env.ledger().set_timestamp(start);
+    client.vote(&test.user, &market_id, &yes, &1_0000000, &None);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Active
+    );
+
+    // Betting closes at the cutoff, well before the market ends
+    test.env.ledger().set_timestamp(cutoff);
+    assert_eq!(
+        client
+            .try_vote(&test.admin, &market_id, &yes, &1_0000000, &None)
+            .err(),
+        Some(Ok(soroban_sdk::Error::from_contract_error(
+            Error::MarketClosed as u32
+        )))
+    );
+    test.env.as_contract(&test.contract_id, || {
+        let market = crate::markets::MarketStateManager::get_market(&test.env, &market_id).unwrap();
+        assert_eq!(
+            crate::voting::VotingValidator::validate_market_for_voting(&test.env, &market),
+            Err(Error::MarketClosed)
+        );
+    });
+
+    // Resolution still waits for the end time
+    test.env.ledger().set_timestamp(start + 30 * day + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().winning_outcome,
+        Some(yes)
+    );
+
+    // A cutoff after the end time is rejected
+    let late_cutoff = test.env.ledger().timestamp() + 31 * day;
+    assert_eq!(
+        client
+            .try_create_market_with_params(
+                &create_market_params(&test).with_schedule(None, Some(late_cutoff))
+            )
+            .err(),
+        Some(Ok(soroban_sdk::Error::from_contract_error(
+            Error::InvalidInput as u32
+        )))
+    );
+}
+
+#[test]
+fn test_invalid_market_config_overrides_are_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    let overrides = crate::config::MarketConfigOverrides {
+        platform_fee_percentage: Some(50),
+        ..Default::default()
+    };
+
+    test.env.mock_all_auths();
+    let result = client.try_create_market_with_params(
+        &create_market_params(&test).with_config_overrides(overrides),
+    );
+    assert_eq!(
+        result.err(),
+        Some(Ok(soroban_sdk::Error::from_contract_error(
+            Error::InvalidFeeConfig as u32
+        )))
+    );
+
+    // No market is left behind by the rejected creation
+    let counter: Option<u32> = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&test.env, "MarketCounter"))
+    });
+    assert_eq!(counter, None);
+}
+
+#[test]
+fn test_admin_transfer_requires_acceptance() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let new_admin = Address::generate(&test.env);
+
+    test.env.mock_all_auths();
+    client.propose_admin_transfer(&test.admin, &new_admin);
+    assert_eq!(
+        client.get_pending_admin_transfer().unwrap().proposed_admin,
+        new_admin
+    );
+
+    client.accept_admin_transfer(&new_admin);
+    assert!(client.get_pending_admin_transfer().is_none());
+
+    // The old admin can no longer act; the new one can
+    assert!(client
+        .try_propose_admin_transfer(&test.admin, &test.user)
+        .is_err());
+    client.propose_admin_transfer(&new_admin, &test.admin);
+}
+
+// The next version of this contract, built with the `upgrade-test-v2` feature
+// by `make test-wasms`, which `make test` and CI run before the tests
+const UPGRADED_WASM: &[u8] = include_bytes!("../test_wasms/predictify_hybrid_v2.wasm");
+
+#[test]
+fn test_upgrade_to_next_version_migrates_storage() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    client.vote(&test.user, &market_id, &yes, &10_0000000, &None);
+    let info = client.get_contract_version();
+    assert_eq!(info.version, crate::upgrade::CONTRACT_VERSION);
+    assert_eq!(info.storage_format, crate::storage::StorageFormat::V1);
+
+    test.env.mock_all_auths();
+    test.env.cost_estimate().budget().reset_unlimited();
+    let wasm_hash = test.env.deployer().upload_contract_wasm(UPGRADED_WASM);
+
+    // Only the admin may swap the code
+    assert!(client.try_upgrade(&test.user, &wasm_hash).is_err());
+    client.upgrade(&test.admin, &wasm_hash);
+
+    // The new build runs on the old data until it is migrated
+    assert_eq!(client.get_contract_version(), info);
+    assert!(client.try_migrate(&test.user).is_err());
+    let migrated = client.migrate(&test.admin);
+    assert_eq!(migrated.version, info.version + 1);
+    assert_eq!(migrated.storage_format, crate::storage::StorageFormat::V2);
+    assert_eq!(client.get_contract_version(), migrated);
+    assert_eq!(
+        client.try_migrate(&test.admin),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // Markets created before the upgrade keep their votes and stay open
+    assert_eq!(
+        client.get_market(&market_id).unwrap().total_staked,
+        10_0000000
+    );
+    client.vote(&test.admin, &market_id, &yes, &5_0000000, &None);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().total_staked,
+        15_0000000
+    );
+}
+
+#[test]
+fn test_legacy_deployment_migrates_to_current_version() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    // Deployments made before versioning have no version record
+    test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .remove(&Symbol::new(&test.env, "ContractVersion"));
+    });
+    assert_eq!(client.get_contract_version().version, 0);
+
+    test.env.mock_all_auths();
+    let info = client.migrate(&test.admin);
+    assert_eq!(info.version, crate::upgrade::CONTRACT_VERSION);
+    assert_eq!(info.storage_format, crate::upgrade::CURRENT_STORAGE_FORMAT);
+
+    // Running the same migration twice is rejected
+    assert_eq!(
+        client.try_migrate(&test.admin),
+        Err(Ok(Error::InvalidState))
+    );
+}
+
+#[test]
+fn test_migrate_rejects_newer_storage_format() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    test.env.as_contract(&test.contract_id, || {
+        test.env.storage().persistent().set(
+            &Symbol::new(&test.env, "ContractVersion"),
+            &crate::upgrade::ContractVersionInfo {
+                version: 0,
+                storage_format: crate::storage::StorageFormat::V3,
+                updated_at: 0,
+            },
+        );
+    });
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_migrate(&test.admin),
+        Err(Ok(Error::InvalidState))
+    );
+}
+
 #[test]
 fn test_market_duration_limits() {
     // Test market duration constants
@@ -502,9 +2520,6 @@ fn test_market_creation_data() {
     let test = PredictifyTest::setup();
     let market_id = test.create_test_market();
 
-
-
-
     let market = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
@@ -532,6 +2547,7 @@ fn test_voting_data_integrity() {
         &market_id,
         &String::from_str(&test.env, "yes"),
         &1_0000000,
+        &None,
     );
 
     let market = test.env.as_contract(&test.contract_id, || {
@@ -0,0 +1,270 @@
+//! Fee treasury for Predictify Hybrid
+//!
+//! Collected platform fees stay in the contract and are booked here instead of
+//! being pushed to a single address:
+//! - Per-token balances of accrued and withdrawn fees
+//! - Per-recipient withdrawable shares, split by the active `FeeDistributionConfig`
+//! - Pull-based withdrawals, so each recipient moves their own share out
+//!
+//! Because fees only leave the contract through `withdraw`, the contract's
+//! token balance always equals outstanding stakes plus unwithdrawn fees.
+
+use soroban_sdk::{contracttype, symbol_short, token, Address, Env, Map, Symbol};
+
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::fees::FeeManager;
+
+// ===== TREASURY TYPES =====
+
+/// Fee accounting for a single token held by the treasury
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryBalance {
+    /// Token the fees are held in
+    pub token: Address,
+    /// Total fees accrued to the treasury
+    pub total_accrued: i128,
+    /// Total fees withdrawn by recipients
+    pub total_withdrawn: i128,
+}
+
+impl TreasuryBalance {
+    /// Fees accrued but not yet withdrawn
+    pub fn outstanding(&self) -> i128 {
+        self.total_accrued - self.total_withdrawn
+    }
+}
+
+// ===== FEE TREASURY =====
+
+/// Holds accrued fees and tracks what each recipient may withdraw
+pub struct FeeTreasury;
+
+impl FeeTreasury {
+    /// Get the token that stakes and fees are denominated in.
+    pub fn get_token(env: &Env) -> Result<Address, Error> {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, "TokenID"))
+            .ok_or(Error::InvalidState)
+    }
+
+    /// Book a collected fee and split it between the distribution recipients.
+    ///
+    /// Each recipient in the active `FeeDistributionConfig` is credited their
+    /// percentage; rounding remainders go to the last recipient so the shares
+    /// always add up to `amount`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidState` - No token is configured
+    /// - `Error::InvalidFeeConfig` - The distribution has no recipients
+    pub fn accrue(env: &Env, market_id: &Symbol, amount: i128) -> Result<(), Error> {
+        let token = Self::get_token(env)?;
+        Self::distribute(env, &token, amount)?;
+        Self::book_accrual(env, market_id, &token, amount);
+        Ok(())
+    }
+
+    /// Book a collected fee entirely to a single recipient.
+    ///
+    /// Used for fee slices that belong to one party, such as the market
+    /// creator's share, rather than to the distribution recipients.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidState` - No token is configured
+    /// - `Error::InvalidInput` - `amount` is negative
+    pub fn accrue_to(
+        env: &Env,
+        market_id: &Symbol,
+        recipient: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if amount < 0 {
+            return Err(Error::InvalidInput);
+        }
+        let token = Self::get_token(env)?;
+
+        Self::credit(env, &token, recipient, amount);
+        Self::book_accrual(env, market_id, &token, amount);
+        Ok(())
+    }
+
+    /// Move part of a recipient's withdrawable fees to other recipients.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - An amount is not positive
+    /// - `Error::NoFeesToCollect` - `from` has less than the total available
+    pub fn reassign(
+        env: &Env,
+        token: &Address,
+        from: &Address,
+        distribution: &Map<Address, i128>,
+    ) -> Result<(), Error> {
+        let mut total: i128 = 0;
+        for (_, amount) in distribution.iter() {
+            if amount <= 0 {
+                return Err(Error::InvalidInput);
+            }
+            total += amount;
+        }
+
+        if total > Self::get_withdrawable(env, token, from) {
+            return Err(Error::NoFeesToCollect);
+        }
+
+        Self::credit(env, token, from, -total);
+        for (recipient, amount) in distribution.iter() {
+            Self::credit(env, token, &recipient, amount);
+        }
+        Ok(())
+    }
+
+    /// Transfer a recipient's full withdrawable balance of `token` to them.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::NoFeesToCollect` - Nothing is withdrawable
+    pub fn withdraw(env: &Env, recipient: &Address, token: &Address) -> Result<i128, Error> {
+        recipient.require_auth();
+
+        let amount = Self::get_withdrawable(env, token, recipient);
+        if amount <= 0 {
+            return Err(Error::NoFeesToCollect);
+        }
+
+        Self::credit(env, token, recipient, -amount);
+        let mut balance = Self::get_balance(env, token);
+        balance.total_withdrawn += amount;
+        Self::store_balance(env, &balance);
+
+        token::Client::new(env, token).transfer(
+            &env.current_contract_address(),
+            recipient,
+            &amount,
+        );
+
+        EventEmitter::emit_fee_withdrawn(env, recipient, token, amount);
+        Ok(amount)
+    }
+
+    /// Get the fee accounting for a token.
+    pub fn get_balance(env: &Env, token: &Address) -> TreasuryBalance {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("trs_bal"), token.clone()))
+            .unwrap_or(TreasuryBalance {
+                token: token.clone(),
+                total_accrued: 0,
+                total_withdrawn: 0,
+            })
+    }
+
+    /// Get the fees a recipient can currently withdraw in a token.
+    pub fn get_withdrawable(env: &Env, token: &Address, recipient: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("trs_due"), token.clone(), recipient.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Credit `amount` across the distribution recipients by percentage;
+    /// rounding remainders go to the last recipient.
+    fn distribute(env: &Env, token: &Address, amount: i128) -> Result<(), Error> {
+        let config = FeeManager::get_fee_distribution_config(env)?;
+        if config.distribution.is_empty() || config.total_percentage <= 0 {
+            return Err(Error::InvalidFeeConfig);
+        }
+
+        let last = config.distribution.len() - 1;
+        let mut allocated: i128 = 0;
+        for (index, (recipient, percentage)) in config.distribution.iter().enumerate() {
+            let share = if index as u32 == last {
+                amount - allocated
+            } else {
+                (amount * percentage) / config.total_percentage
+            };
+            Self::credit(env, token, &recipient, share);
+            allocated += share;
+        }
+        Ok(())
+    }
+
+    fn credit(env: &Env, token: &Address, recipient: &Address, amount: i128) {
+        let key = (symbol_short!("trs_due"), token.clone(), recipient.clone());
+        let current: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(current + amount));
+    }
+
+    fn book_accrual(env: &Env, market_id: &Symbol, token: &Address, amount: i128) {
+        let mut balance = Self::get_balance(env, token);
+        balance.total_accrued += amount;
+        Self::store_balance(env, &balance);
+
+        EventEmitter::emit_fee_accrued(env, market_id, token, amount);
+    }
+
+    fn store_balance(env: &Env, balance: &TreasuryBalance) {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("trs_bal"), balance.token.clone()), balance);
+    }
+}
+
+// ===== TESTS =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::admin::AdminInitializer;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_accrue_splits_fee_between_recipients() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let partner = Address::generate(&env);
+        let token = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            AdminInitializer::initialize(&env, &admin).unwrap();
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token);
+        });
+
+        env.as_contract(&contract_id, || {
+            let mut distribution = Map::new(&env);
+            distribution.set(admin.clone(), 50);
+            distribution.set(partner.clone(), 50);
+            FeeManager::update_fee_distribution_config(&env, admin.clone(), distribution).unwrap();
+        });
+
+        env.as_contract(&contract_id, || {
+            FeeTreasury::accrue(&env, &Symbol::new(&env, "market"), 7).unwrap();
+
+            // The odd unit goes to one recipient; nothing is lost to rounding
+            let admin_share = FeeTreasury::get_withdrawable(&env, &token, &admin);
+            let partner_share = FeeTreasury::get_withdrawable(&env, &token, &partner);
+            assert_eq!(admin_share + partner_share, 7);
+            assert!(admin_share >= 3 && partner_share >= 3);
+
+            let balance = FeeTreasury::get_balance(&env, &token);
+            assert_eq!(balance.total_accrued, 7);
+            assert_eq!(balance.outstanding(), 7);
+
+            // Recipients can only reassign what they are owed
+            let mut transfer = Map::new(&env);
+            transfer.set(partner.clone(), admin_share + 1);
+            assert_eq!(
+                FeeTreasury::reassign(&env, &token, &admin, &transfer),
+                Err(Error::NoFeesToCollect)
+            );
+        });
+    }
+}
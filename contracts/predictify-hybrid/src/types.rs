@@ -2,6 +2,8 @@
 
 use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 
+use crate::metadata::MarketMetadata;
+
 // ===== MARKET STATE =====
 
 /// Enumeration of possible market states throughout the prediction market lifecycle.
@@ -15,16 +17,22 @@ use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 ///
 /// The typical market progression follows this pattern:
 /// ```text
-/// Active → Ended → [Disputed] → Resolved → Closed
+/// [Pending] → Active → Ended → [Disputed] → Resolved → Closed
 /// ```
 ///
 /// **Alternative flows:**
+/// - **Review**: `Pending → Active` (approved) or `Pending → Cancelled` (rejected)
 /// - **Cancellation**: `Active → Cancelled` (emergency situations)
 /// - **Direct Resolution**: `Active → Resolved` (admin override)
 /// - **Dispute Flow**: `Ended → Disputed → Resolved`
 ///
 /// # State Descriptions
 ///
+/// **Pending**: Market was proposed by a non-admin creator
+/// - Waiting in the curation queue for admin review
+/// - Oracle configuration may still be edited by the reviewer
+/// - No votes or stakes accepted
+///
 /// **Active**: Market is live and accepting user participation
 /// - Users can place votes and stakes
 /// - Market question and outcomes are fixed
@@ -72,6 +80,9 @@ use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 ///
 /// // Check market state and determine available operations
 /// match market.state {
+///     MarketState::Pending => {
+///         println!("Market awaiting review");
+///     },
 ///     MarketState::Active => {
 ///         if market.is_active(current_time) {
 ///             println!("Market is active - users can vote");
@@ -125,6 +136,8 @@ use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum MarketState {
+    /// Market is waiting for admin review or its scheduled start time
+    Pending,
     /// Market is active and accepting votes
     Active,
     /// Market has ended, waiting for resolution
@@ -690,7 +703,8 @@ impl OracleConfig {
 /// # State Management
 ///
 /// Market state transitions are carefully managed:
-/// - **Active**: Users can vote, stakes accepted
+/// - **Pending**: Awaiting review or its scheduled start time
+/// - **Active**: Users can vote until the betting cutoff, stakes accepted
 /// - **Ended**: Voting closed, resolution pending
 /// - **Disputed**: Under dispute resolution
 /// - **Resolved**: Outcome determined, payouts available
@@ -735,6 +749,11 @@ pub struct Market {
 
     /// Extension history
     pub extension_history: Vec<MarketExtension>,
+
+    /// When voting opens (Unix timestamp); unscheduled markets open at creation
+    pub start_time: Option<u64>,
+    /// When voting closes (Unix timestamp), if earlier than `end_time`
+    pub betting_cutoff: Option<u64>,
 }
 
 impl Market {
@@ -767,6 +786,9 @@ impl Market {
             total_extension_days: 0,
             max_extension_days: 30, // Default maximum extension days
             extension_history: Vec::new(env),
+
+            start_time: None,
+            betting_cutoff: None,
         }
     }
 
@@ -780,6 +802,22 @@ impl Market {
         current_time >= self.end_time
     }
 
+    /// Check if the market's scheduled start has passed
+    pub fn has_started(&self, current_time: u64) -> bool {
+        self.start_time.is_none_or(|start| current_time >= start)
+    }
+
+    /// Time at which the market stops accepting votes
+    pub fn voting_closes_at(&self) -> u64 {
+        self.betting_cutoff
+            .map_or(self.end_time, |cutoff| cutoff.min(self.end_time))
+    }
+
+    /// Check if the market's trading window is open
+    pub fn is_trading_open(&self, current_time: u64) -> bool {
+        self.has_started(current_time) && current_time < self.voting_closes_at()
+    }
+
     /// Check if the market is resolved
     pub fn is_resolved(&self) -> bool {
         self.winning_outcome.is_some()
@@ -825,6 +863,159 @@ impl Market {
     }
 }
 
+/// Lightweight view of a market returned by listing queries
+///
+/// Carries the fields needed to display and filter markets without the
+/// per-user vote, stake and claim maps of the full `Market`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketSummary {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Market creator
+    pub admin: Address,
+    /// Market question
+    pub question: String,
+    /// Available outcomes
+    pub outcomes: Vec<String>,
+    /// Market end time (Unix timestamp)
+    pub end_time: u64,
+    /// Current market state
+    pub state: MarketState,
+    /// Oracle provider resolving the market
+    pub oracle_provider: OracleProvider,
+    /// Total amount staked
+    pub total_staked: i128,
+    /// Number of voters
+    pub participant_count: u32,
+    /// Winning outcome, once resolved
+    pub winning_outcome: Option<String>,
+}
+
+impl MarketSummary {
+    /// Summarize a stored market
+    pub fn from_market(market_id: &Symbol, market: &Market) -> Self {
+        Self {
+            market_id: market_id.clone(),
+            admin: market.admin.clone(),
+            question: market.question.clone(),
+            outcomes: market.outcomes.clone(),
+            end_time: market.end_time,
+            state: market.state,
+            oracle_provider: market.oracle_config.provider.clone(),
+            total_staked: market.total_staked,
+            participant_count: market.votes.len(),
+            winning_outcome: market.winning_outcome.clone(),
+        }
+    }
+}
+
+/// Page of market summaries returned by listing queries
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketPage {
+    /// Matching markets, in creation order
+    pub markets: Vec<MarketSummary>,
+    /// Index position to pass as the cursor of the next call, or `None`
+    /// once every market has been scanned
+    pub next_cursor: Option<u32>,
+}
+
+/// Filter applied by market listing queries
+///
+/// Every criterion is optional; a market must match all criteria that are
+/// set. Empty lists match any value.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketFilter {
+    /// Market states to include
+    pub states: Vec<MarketState>,
+    /// Oracle providers to include
+    pub oracle_providers: Vec<OracleProvider>,
+    /// Only include markets created by this address
+    pub creator: Option<Address>,
+    /// Only include markets ending at or after this time
+    pub end_time_from: Option<u64>,
+    /// Only include markets ending at or before this time
+    pub end_time_to: Option<u64>,
+    /// Only include markets whose metadata has this category
+    pub category: Option<String>,
+    /// Only include markets whose metadata has this tag
+    pub tag: Option<String>,
+}
+
+impl MarketFilter {
+    /// Filter that matches every market
+    pub fn all(env: &Env) -> Self {
+        Self {
+            states: Vec::new(env),
+            oracle_providers: Vec::new(env),
+            creator: None,
+            end_time_from: None,
+            end_time_to: None,
+            category: None,
+            tag: None,
+        }
+    }
+
+    /// Check whether a market matches the filter
+    pub fn matches(&self, market: &Market) -> bool {
+        if !self.states.is_empty() && !self.states.contains(market.state) {
+            return false;
+        }
+        if !self.oracle_providers.is_empty()
+            && !self
+                .oracle_providers
+                .contains(market.oracle_config.provider.clone())
+        {
+            return false;
+        }
+        if let Some(creator) = &self.creator {
+            if *creator != market.admin {
+                return false;
+            }
+        }
+        if let Some(from) = self.end_time_from {
+            if market.end_time < from {
+                return false;
+            }
+        }
+        if let Some(to) = self.end_time_to {
+            if market.end_time > to {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Whether the filter needs the market's metadata
+    pub fn uses_metadata(&self) -> bool {
+        self.category.is_some() || self.tag.is_some()
+    }
+
+    /// Check whether a market's metadata matches the filter
+    pub fn matches_metadata(&self, metadata: Option<&MarketMetadata>) -> bool {
+        if !self.uses_metadata() {
+            return true;
+        }
+        let metadata = match metadata {
+            Some(metadata) => metadata,
+            None => return false,
+        };
+        if let Some(category) = &self.category {
+            if *category != metadata.category {
+                return false;
+            }
+        }
+        if let Some(tag) = &self.tag {
+            if !metadata.tags.contains(tag) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
 // ===== REFLECTOR ORACLE TYPES =====
 
 /// Enumeration of supported assets in the Reflector Oracle ecosystem.
@@ -1696,6 +1887,37 @@ pub struct ExtensionStats {
 
 // ===== MARKET CREATION TYPES =====
 
+/// Structured statement of how a market resolves.
+///
+/// Recorded when a market is created from `MarketCreationParams` so that
+/// voters and disputers work from the same definition of each outcome. The
+/// creator or admin may amend it until the first vote is cast or a proposed
+/// market is approved; after that it is immutable. Every field is required.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolutionCriteria {
+    /// What has to happen for each outcome to win
+    pub description: String,
+    /// Authoritative source the outcome is read from
+    pub source: String,
+    /// How edge cases resolve, e.g. "if cancelled, resolves No"
+    pub edge_case_rule: String,
+    /// When the market is considered invalid and void
+    pub invalid_condition: String,
+}
+
+impl ResolutionCriteria {
+    /// Criteria with every field empty, for markets created without any
+    pub fn unspecified(env: &Env) -> Self {
+        Self {
+            description: String::from_str(env, ""),
+            source: String::from_str(env, ""),
+            edge_case_rule: String::from_str(env, ""),
+            invalid_condition: String::from_str(env, ""),
+        }
+    }
+}
+
 /// Comprehensive parameters for creating new prediction markets.
 ///
 /// This structure contains all necessary information to create a new prediction
@@ -1716,6 +1938,15 @@ pub struct ExtensionStats {
 ///
 /// **Oracle Integration:**
 /// - **Oracle Config**: Configuration for automated resolution
+/// - **Resolution Criteria**: What counts as each outcome, recorded up front
+///
+/// **Trading Window:**
+/// - **Start Time**: Optional opening time for pre-announced markets
+/// - **Betting Cutoff**: Optional time voting closes ahead of the end time
+///
+/// **Configuration Overrides:**
+/// - **Config Overrides**: Optional per-market fee, voting and extension settings
+///   replacing the global contract configuration for this market only
 ///
 /// # Market Creation Workflow
 ///
@@ -1728,7 +1959,9 @@ pub struct ExtensionStats {
 ///
 /// ```rust
 /// # use soroban_sdk::{Env, Address, String, Vec};
-/// # use predictify_hybrid::types::{MarketCreationParams, OracleConfig, OracleProvider};
+/// # use predictify_hybrid::types::{
+/// #     MarketCreationParams, OracleConfig, OracleProvider, ResolutionCriteria,
+/// # };
 /// # let env = Env::default();
 /// # let admin = Address::generate(&env);
 ///
@@ -1747,7 +1980,13 @@ pub struct ExtensionStats {
 ///         100_000_00, // $100,000 threshold
 ///         String::from_str(&env, "gt")
 ///     ),
-///     5_000_000 // 5 XLM creation fee
+///     5_000_000, // 5 XLM creation fee
+///     ResolutionCriteria {
+///         description: String::from_str(&env, "Yes if BTC/USD closes above $100,000"),
+///         source: String::from_str(&env, "Reflector BTC/USD feed"),
+///         edge_case_rule: String::from_str(&env, "If the feed is unavailable, resolves No"),
+///         invalid_condition: String::from_str(&env, "Void if the feed is discontinued"),
+///     },
 /// );
 ///
 /// // Validate parameters before market creation
@@ -1928,7 +2167,8 @@ pub struct ExtensionStats {
 ///             threshold,
 ///             String::from_str(&env, "gt")
 ///         ),
-///         calculate_creation_fee(days)
+///         calculate_creation_fee(days),
+///         btc_resolution_criteria(&env, threshold),
 ///     )
 /// };
 ///
@@ -1970,6 +2210,14 @@ pub struct MarketCreationParams {
     pub oracle_config: OracleConfig,
     /// Creation fee amount
     pub creation_fee: i128,
+    /// How the market resolves, fixed once voting starts
+    pub resolution_criteria: ResolutionCriteria,
+    /// When voting opens (none opens the market at creation)
+    pub start_time: Option<u64>,
+    /// When voting closes, if earlier than the market's end time
+    pub betting_cutoff: Option<u64>,
+    /// Per-market overrides of the contract configuration (none by default)
+    pub config_overrides: crate::config::MarketConfigOverrides,
 }
 
 impl MarketCreationParams {
@@ -1981,6 +2229,7 @@ impl MarketCreationParams {
         duration_days: u32,
         oracle_config: OracleConfig,
         creation_fee: i128,
+        resolution_criteria: ResolutionCriteria,
     ) -> Self {
         Self {
             admin,
@@ -1989,8 +2238,30 @@ impl MarketCreationParams {
             duration_days,
             oracle_config,
             creation_fee,
+            resolution_criteria,
+            start_time: None,
+            betting_cutoff: None,
+            config_overrides: crate::config::MarketConfigOverrides::default(),
         }
     }
+
+    /// Schedule the market's trading window
+    ///
+    /// The voting period counts from `start_time` when it is in the future.
+    pub fn with_schedule(mut self, start_time: Option<u64>, betting_cutoff: Option<u64>) -> Self {
+        self.start_time = start_time;
+        self.betting_cutoff = betting_cutoff;
+        self
+    }
+
+    /// Attach per-market configuration overrides to these parameters
+    pub fn with_config_overrides(
+        mut self,
+        overrides: crate::config::MarketConfigOverrides,
+    ) -> Self {
+        self.config_overrides = overrides;
+        self
+    }
 }
 
 // ===== ADDITIONAL TYPES =====
@@ -0,0 +1,174 @@
+//! Contract upgrade management for Predictify Hybrid
+//!
+//! This module provides the upgrade path for deployed contracts:
+//! - Admin-gated replacement of the contract WASM
+//! - Stored contract version and storage format tracking
+//! - Post-upgrade migration hooks that step storage through format bumps
+//! - Upgrade and migration events recording old and new versions
+//!
+//! Upgrading is a two-call process. `upgrade` swaps the executable; the new
+//! code only runs from the next invocation, so `migrate` must then be called
+//! to bring stored data up to the format the new build expects.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, Symbol};
+
+use crate::admin::{AdminAccessControl, AdminActionLogger};
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::storage::{StorageFormat, StorageOptimizer};
+
+// ===== UPGRADE CONSTANTS =====
+
+/// Version of this contract build. Bump on every release that is deployed
+/// through `upgrade`.
+#[cfg(not(feature = "upgrade-test-v2"))]
+pub const CONTRACT_VERSION: u32 = 1;
+#[cfg(feature = "upgrade-test-v2")]
+pub const CONTRACT_VERSION: u32 = 2;
+
+/// Storage format this build reads and writes
+#[cfg(not(feature = "upgrade-test-v2"))]
+pub const CURRENT_STORAGE_FORMAT: StorageFormat = StorageFormat::V1;
+#[cfg(feature = "upgrade-test-v2")]
+pub const CURRENT_STORAGE_FORMAT: StorageFormat = StorageFormat::V2;
+
+/// Storage key for the stored version record
+const CONTRACT_VERSION_KEY: &str = "ContractVersion";
+
+// ===== UPGRADE TYPES =====
+
+/// Version record of the code and data currently deployed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractVersionInfo {
+    /// Contract version the stored data was last migrated to
+    pub version: u32,
+    /// Storage format of the stored data
+    pub storage_format: StorageFormat,
+    /// Timestamp of the last initialization or migration
+    pub updated_at: u64,
+}
+
+// ===== UPGRADE MANAGER =====
+
+/// Manages WASM upgrades and post-upgrade migrations
+pub struct UpgradeManager;
+
+impl UpgradeManager {
+    /// Record this build's version on first deployment.
+    pub fn initialize_version(env: &Env) {
+        let info = ContractVersionInfo {
+            version: CONTRACT_VERSION,
+            storage_format: CURRENT_STORAGE_FORMAT,
+            updated_at: env.ledger().timestamp(),
+        };
+        Self::store_version_info(env, &info);
+    }
+
+    /// Get the stored version record.
+    ///
+    /// Deployments made before versioning existed have no record and are
+    /// reported as version 0 with the original storage format.
+    pub fn get_version_info(env: &Env) -> ContractVersionInfo {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, CONTRACT_VERSION_KEY))
+            .unwrap_or(ContractVersionInfo {
+                version: 0,
+                storage_format: StorageFormat::V1,
+                updated_at: 0,
+            })
+    }
+
+    /// Replace the contract WASM with a previously uploaded build.
+    ///
+    /// The new code takes effect from the next invocation; call `migrate`
+    /// afterwards to update stored data.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::AdminNotSet` - The contract has not been initialized
+    /// - `Error::Unauthorized` - Caller is not the SuperAdmin
+    pub fn upgrade(env: &Env, admin: &Address, new_wasm_hash: &BytesN<32>) -> Result<(), Error> {
+        AdminAccessControl::validate_admin_for_action(env, admin, "upgrade_contract")?;
+
+        let info = Self::get_version_info(env);
+        EventEmitter::emit_contract_upgraded(env, admin, info.version, new_wasm_hash);
+
+        AdminActionLogger::log_action(
+            env,
+            admin,
+            "upgrade_contract",
+            None,
+            Map::new(env),
+            true,
+            None,
+        )?;
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        Ok(())
+    }
+
+    /// Bring stored data up to this build's version.
+    ///
+    /// Steps the storage format one version at a time through
+    /// `StorageOptimizer::migrate_storage_format` until it matches
+    /// `CURRENT_STORAGE_FORMAT`, then records `CONTRACT_VERSION`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the SuperAdmin
+    /// - `Error::InvalidState` - Data is already at this version, the stored
+    ///   format is newer than this build, or a format migration failed
+    pub fn migrate(env: &Env, admin: &Address) -> Result<ContractVersionInfo, Error> {
+        AdminAccessControl::validate_admin_for_action(env, admin, "upgrade_contract")?;
+
+        let current = Self::get_version_info(env);
+        if current.version >= CONTRACT_VERSION {
+            return Err(Error::InvalidState);
+        }
+
+        let mut format = current.storage_format.clone();
+        while format != CURRENT_STORAGE_FORMAT {
+            let next = match format {
+                StorageFormat::V1 => StorageFormat::V2,
+                StorageFormat::V2 => StorageFormat::V3,
+                // Never downgrade stored data
+                StorageFormat::V3 => return Err(Error::InvalidState),
+            };
+            let migration = StorageOptimizer::migrate_storage_format(env, format, next.clone())?;
+            if migration.error_message.is_some() {
+                return Err(Error::InvalidState);
+            }
+            format = next;
+        }
+
+        let migrated = ContractVersionInfo {
+            version: CONTRACT_VERSION,
+            storage_format: format,
+            updated_at: env.ledger().timestamp(),
+        };
+        Self::store_version_info(env, &migrated);
+
+        EventEmitter::emit_contract_migrated(env, admin, current.version, migrated.version);
+        AdminActionLogger::log_action(
+            env,
+            admin,
+            "migrate_contract",
+            None,
+            Map::new(env),
+            true,
+            None,
+        )?;
+
+        Ok(migrated)
+    }
+
+    fn store_version_info(env: &Env, info: &ContractVersionInfo) {
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, CONTRACT_VERSION_KEY), info);
+    }
+}
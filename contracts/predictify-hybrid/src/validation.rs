@@ -5,7 +5,8 @@ extern crate alloc;
 use crate::{
     config,
     errors::Error,
-    types::{Market, OracleConfig, OracleProvider},
+    metadata::MarketMetadata,
+    types::{Market, OracleConfig, OracleProvider, ResolutionCriteria},
 };
 // use alloc::string::ToString; // Removed to fix Display/ToString trait errors
 use soroban_sdk::{contracttype, vec, Address, Env, IntoVal, Map, String, Symbol, Vec};
@@ -1041,6 +1042,23 @@ impl InputValidator {
         Ok(())
     }
 
+    /// Validate a vote's referrer, rejecting self-referrals
+    pub fn validate_referrer(
+        env: &Env,
+        user: &Address,
+        referrer: &Address,
+    ) -> Result<(), ValidationError> {
+        if referrer == user {
+            return Err(ValidationError::InvalidInput);
+        }
+
+        if *referrer == env.current_contract_address() {
+            return Err(ValidationError::InvalidInput);
+        }
+
+        Ok(())
+    }
+
     /// Validate timestamp bounds
     pub fn validate_timestamp_bounds(
         timestamp: u64,
@@ -1112,6 +1130,49 @@ impl InputValidator {
         Ok(())
     }
 
+    /// Validate market metadata field lengths
+    ///
+    /// Every field is optional and may be empty, but tags must not be.
+    pub fn validate_market_metadata(metadata: &MarketMetadata) -> Result<(), ValidationError> {
+        let optional_fields = [
+            (&metadata.category, config::MAX_CATEGORY_LENGTH),
+            (&metadata.description, config::MAX_DESCRIPTION_LENGTH),
+            (&metadata.source_url, config::MAX_SOURCE_URL_LENGTH),
+            (&metadata.image_hash, config::MAX_IMAGE_HASH_LENGTH),
+        ];
+        for (value, max_length) in optional_fields {
+            if value.len() > max_length {
+                return Err(ValidationError::StringTooLong);
+            }
+        }
+
+        if metadata.tags.len() > config::MAX_MARKET_TAGS {
+            return Err(ValidationError::ArrayTooLarge);
+        }
+        for tag in metadata.tags.iter() {
+            Self::validate_string_length(&tag, config::MAX_TAG_LENGTH)?;
+        }
+
+        Ok(())
+    }
+
+    /// Validate a market's resolution criteria
+    ///
+    /// Every field is required and bounded by `MAX_RESOLUTION_CRITERIA_LENGTH`.
+    pub fn validate_resolution_criteria(
+        criteria: &ResolutionCriteria,
+    ) -> Result<(), ValidationError> {
+        for field in [
+            &criteria.description,
+            &criteria.source,
+            &criteria.edge_case_rule,
+            &criteria.invalid_condition,
+        ] {
+            Self::validate_string_length(field, config::MAX_RESOLUTION_CRITERIA_LENGTH)?;
+        }
+        Ok(())
+    }
+
     /// Validate string length and content
     pub fn validate_string(
         env: &Env,
@@ -1821,9 +1882,9 @@ impl MarketValidator {
             return Err(ValidationError::InvalidMarket);
         }
 
-        // Check if market is still active
+        // Check if market is open and within its betting window
         let current_time = env.ledger().timestamp();
-        if current_time >= market.end_time {
+        if !market.is_trading_open(current_time) {
             return Err(ValidationError::InvalidMarket);
         }
 
@@ -1887,7 +1948,8 @@ impl MarketValidator {
         }
 
         // Check if there are sufficient stakes
-        if market.total_staked < config::FEE_COLLECTION_THRESHOLD {
+        let fee_config = config::ConfigManager::get_market_runtime_config(env, market_id).fees;
+        if market.total_staked < fee_config.collection_threshold {
             return Err(ValidationError::InvalidFee);
         }
 
@@ -2155,12 +2217,8 @@ impl VoteValidator {
             return Err(ValidationError::InvalidVote);
         }
 
-        // Validate stake amount with numeric range
-        if let Err(_) =
-            InputValidator::validate_numeric_range(*stake_amount, config::MIN_VOTE_STAKE, i128::MAX)
-        {
-            return Err(ValidationError::InvalidStake);
-        }
+        // Validate stake amount against the market's minimum
+        Self::validate_stake_amount(env, market_id, stake_amount)?;
 
         // Check if user has already voted
         if market.votes.contains_key(user.clone()) {
@@ -2187,13 +2245,18 @@ impl VoteValidator {
         Ok(())
     }
 
-    /// Validate stake amount
-    pub fn validate_stake_amount(stake_amount: &i128) -> Result<(), ValidationError> {
+    /// Validate stake amount against the minimum vote stake of the market
+    pub fn validate_stake_amount(
+        env: &Env,
+        market_id: &Symbol,
+        stake_amount: &i128,
+    ) -> Result<(), ValidationError> {
         if let Err(_) = InputValidator::validate_positive_number(stake_amount) {
             return Err(ValidationError::InvalidStake);
         }
 
-        if *stake_amount < config::MIN_VOTE_STAKE {
+        let voting_config = config::ConfigManager::get_market_runtime_config(env, market_id).voting;
+        if *stake_amount < voting_config.min_vote_stake {
             return Err(ValidationError::InvalidStake);
         }
 
@@ -2312,14 +2375,9 @@ impl DisputeValidator {
             return Err(ValidationError::InvalidMarket);
         }
 
-        // Validate dispute stake with numeric range
-        if let Err(_) = InputValidator::validate_numeric_range(
-            *dispute_stake,
-            config::MIN_DISPUTE_STAKE,
-            i128::MAX,
-        ) {
-            return Err(ValidationError::InvalidStake);
-        }
+        // Validate dispute stake against the market's minimum
+        Self::validate_dispute_stake(env, market_id, dispute_stake)
+            .map_err(|_| ValidationError::InvalidStake)?;
 
         // Check if user has already disputed
         if market.dispute_stakes.contains_key(user.clone()) {
@@ -2329,9 +2387,18 @@ impl DisputeValidator {
         Ok(())
     }
 
-    /// Validate dispute stake amount with comprehensive validation
-    pub fn validate_dispute_stake(stake_amount: &i128) -> Result<(), ValidationError> {
-        InputValidator::validate_numeric_range(*stake_amount, config::MIN_DISPUTE_STAKE, i128::MAX)
+    /// Validate dispute stake amount against the minimum dispute stake of the market
+    pub fn validate_dispute_stake(
+        env: &Env,
+        market_id: &Symbol,
+        stake_amount: &i128,
+    ) -> Result<(), ValidationError> {
+        let voting_config = config::ConfigManager::get_market_runtime_config(env, market_id).voting;
+        InputValidator::validate_numeric_range(
+            *stake_amount,
+            voting_config.min_dispute_stake,
+            i128::MAX,
+        )
     }
 }
 
@@ -2664,7 +2731,8 @@ impl ComprehensiveValidator {
         }
 
         // Add recommendations
-        if market.total_staked < config::FEE_COLLECTION_THRESHOLD {
+        let fee_config = config::ConfigManager::get_market_runtime_config(env, market_id).fees;
+        if market.total_staked < fee_config.collection_threshold {
             result.add_recommendation();
         }
 
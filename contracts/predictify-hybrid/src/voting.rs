@@ -1,41 +1,19 @@
 #![allow(dead_code)]
 
 use crate::{
+    config::{self, ConfigManager, FeeConfig, VotingConfig},
+    disputes::DisputeValidator,
     errors::Error,
-    markets::{MarketAnalytics, MarketStateManager, MarketUtils, MarketValidator},
-    types::Market,
+    events::{EventEmitter, EventLogger, MarketActivityKind},
+    fees::{FeeBreakdown, FeeCalculator, FeeConfigManager, FeeManager, FeeTracker},
+    markets::{MarketAnalytics, MarketIndex, MarketStateManager, MarketUtils, MarketValidator},
+    referrals::ReferralManager,
+    treasury::FeeTreasury,
+    types::{Market, MarketState},
 };
 
 use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, Map, String, Symbol, Vec};
 
-// ===== CONSTANTS =====
-// Note: These constants are now managed by the config module
-// Use ConfigManager::get_voting_config() to get current values
-
-/// Minimum stake amount for voting (0.1 XLM)
-pub const MIN_VOTE_STAKE: i128 = crate::config::MIN_VOTE_STAKE;
-
-/// Minimum stake amount for disputes (10 XLM)
-pub const MIN_DISPUTE_STAKE: i128 = crate::config::MIN_DISPUTE_STAKE;
-
-/// Maximum dispute threshold (100 XLM)
-pub const MAX_DISPUTE_THRESHOLD: i128 = crate::config::MAX_DISPUTE_THRESHOLD;
-
-/// Base dispute threshold (10 XLM)
-pub const BASE_DISPUTE_THRESHOLD: i128 = crate::config::BASE_DISPUTE_THRESHOLD;
-
-/// Market size threshold for large markets (1000 XLM)
-pub const LARGE_MARKET_THRESHOLD: i128 = crate::config::LARGE_MARKET_THRESHOLD;
-
-/// Activity level threshold for high activity (100 votes)
-pub const HIGH_ACTIVITY_THRESHOLD: u32 = crate::config::HIGH_ACTIVITY_THRESHOLD;
-
-/// Platform fee percentage (2%)
-pub const FEE_PERCENTAGE: i128 = crate::config::DEFAULT_PLATFORM_FEE_PERCENTAGE;
-
-/// Dispute extension period in hours
-pub const DISPUTE_EXTENSION_HOURS: u32 = crate::config::DISPUTE_EXTENSION_HOURS;
-
 // ===== VOTING STRUCTURES =====
 
 /// Represents a user's vote on a prediction market.
@@ -234,6 +212,31 @@ pub struct ThresholdHistoryEntry {
     pub timestamp: u64,
 }
 
+/// A user's position in one market, as returned by portfolio queries.
+///
+/// `claimable` is what a claim would pay out right now: zero until the
+/// market resolves, for positions on a losing outcome and once claimed. For
+/// markets cancelled as invalid it is the refund of the user's stake.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserPosition {
+    pub market_id: Symbol,
+    pub outcome: String,
+    pub stake: i128,
+    pub state: MarketState,
+    pub winning_outcome: Option<String>,
+    pub claimable: i128,
+    pub claimed: bool,
+}
+
+/// Amount settled for one market by a multi-market claim.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketClaim {
+    pub market_id: Symbol,
+    pub amount: i128,
+}
+
 // ===== VOTING MANAGER =====
 
 /// Comprehensive voting manager for prediction market voting operations.
@@ -278,7 +281,7 @@ pub struct ThresholdHistoryEntry {
 /// let stake = 5000000i128; // 0.5 XLM
 ///
 /// // Process a user vote
-/// match VotingManager::process_vote(&env, user.clone(), market_id.clone(), outcome, stake) {
+/// match VotingManager::process_vote(&env, user.clone(), market_id.clone(), outcome, stake, None) {
 ///     Ok(()) => println!("Vote processed successfully"),
 ///     Err(e) => println!("Vote processing failed: {:?}", e),
 /// }
@@ -301,30 +304,69 @@ pub struct VotingManager;
 
 impl VotingManager {
     /// Process a user's vote on a market
+    ///
+    /// Transfers the stake from the user to the contract and attributes the
+    /// vote to `referrer`, if any.
     pub fn process_vote(
         env: &Env,
         user: Address,
         market_id: Symbol,
         outcome: String,
         stake: i128,
+        referrer: Option<Address>,
     ) -> Result<(), Error> {
         // Require authentication from the user
         user.require_auth();
 
         // Get and validate market
         let mut market = MarketStateManager::get_market(env, &market_id)?;
+        MarketStateManager::open_market_if_started(env, &market_id, &mut market);
         VotingValidator::validate_market_for_voting(env, &market)?;
+        let voting_config = ConfigManager::get_market_runtime_config(env, &market_id).voting;
 
         // Validate vote parameters
-        VotingValidator::validate_vote_parameters(env, &outcome, &market.outcomes, stake)?;
+        VotingValidator::validate_vote_parameters(
+            env,
+            &outcome,
+            &market.outcomes,
+            stake,
+            &voting_config,
+        )?;
+        if market.votes.contains_key(user.clone()) {
+            return Err(Error::AlreadyVoted);
+        }
+
+        // Attribute the vote to its referrer
+        if let Some(referrer) = referrer {
+            ReferralManager::record_referral(env, &market_id, &user, &referrer, stake)?;
+        }
 
         // Process stake transfer
         VotingUtils::transfer_stake(env, &user, stake)?;
 
+        // Count the stake towards the user's fee discount tier
+        FeeTracker::record_user_volume(env, &user, stake);
+
         // Add vote to market (pass market_id for event emission)
-        MarketStateManager::add_vote(&mut market, user, outcome, stake, Some(&market_id));
+        MarketStateManager::add_vote(
+            &mut market,
+            user.clone(),
+            outcome.clone(),
+            stake,
+            Some(&market_id),
+        );
         MarketStateManager::update_market(env, &market_id, &market);
 
+        EventEmitter::emit_vote_cast(env, &market_id, &user, &outcome, stake);
+        MarketIndex::add_user_market(env, &user, &market_id);
+        EventLogger::record_market_activity(
+            env,
+            &market_id,
+            MarketActivityKind::Vote,
+            &user,
+            &outcome,
+            stake,
+        );
         Ok(())
     }
 
@@ -341,18 +383,40 @@ impl VotingManager {
         // Get and validate market
         let mut market = MarketStateManager::get_market(env, &market_id)?;
         VotingValidator::validate_market_for_dispute(env, &market)?;
+        let voting_config = ConfigManager::get_market_runtime_config(env, &market_id).voting;
 
         // Validate dispute stake
-        VotingValidator::validate_dispute_stake(stake)?;
+        VotingValidator::validate_dispute_stake(stake, &voting_config)?;
 
         // Process stake transfer
         VotingUtils::transfer_stake(env, &user, stake)?;
 
         // Add dispute stake and extend market (pass market_id for event emission)
-        MarketStateManager::add_dispute_stake(&mut market, user, stake, Some(&market_id));
-        MarketStateManager::extend_for_dispute(&mut market, env, DISPUTE_EXTENSION_HOURS.into());
+        MarketStateManager::end_market_if_expired(env, &market_id, &mut market);
+        MarketStateManager::add_dispute_stake(&mut market, user.clone(), stake, Some(&market_id));
+        MarketStateManager::extend_for_dispute(
+            &mut market,
+            env,
+            voting_config.dispute_extension_hours.into(),
+        );
         MarketStateManager::update_market(env, &market_id, &market);
 
+        EventEmitter::emit_dispute_created(
+            env,
+            &market_id,
+            &user,
+            stake,
+            None,
+            DisputeValidator::get_resolution_criteria(env, &market_id),
+        );
+        EventLogger::record_market_activity(
+            env,
+            &market_id,
+            MarketActivityKind::Dispute,
+            &user,
+            &String::from_str(env, ""),
+            stake,
+        );
         Ok(())
     }
 
@@ -361,25 +425,174 @@ impl VotingManager {
         // Require authentication from the user
         user.require_auth();
 
-        // Get and validate market
-        let mut market = MarketStateManager::get_market(env, &market_id)?;
-        VotingValidator::validate_market_for_claim(env, &market, &user)?;
+        let claims = Self::settle_and_transfer(env, &user, &vec![env, market_id], false)?;
+        Ok(claims.iter().map(|claim| claim.amount).sum())
+    }
+
+    /// Claim winnings from several resolved markets at once
+    ///
+    /// Settles every position in `market_ids` that currently has something
+    /// to claim and skips the rest, then pays the total out in a single
+    /// transfer. Returns the amount settled for each market.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - More than `MAX_MARKETS_PER_PAGE` markets
+    /// - `Error::NothingToClaim` - None of the markets has anything to claim
+    pub fn process_claim_all(
+        env: &Env,
+        user: Address,
+        market_ids: Vec<Symbol>,
+    ) -> Result<Vec<MarketClaim>, Error> {
+        user.require_auth();
+
+        if market_ids.len() > config::MAX_MARKETS_PER_PAGE {
+            return Err(Error::InvalidInput);
+        }
+
+        let claims = Self::settle_and_transfer(env, &user, &market_ids, true)?;
+        if claims.is_empty() {
+            return Err(Error::NothingToClaim);
+        }
+        Ok(claims)
+    }
+
+    /// Claim winnings from every market the user has voted in
+    ///
+    /// Collects up to `MAX_MARKETS_PER_PAGE` claimable markets from the
+    /// user's market index and claims them with `process_claim_all`.
+    pub fn process_claim_all_positions(
+        env: &Env,
+        user: Address,
+    ) -> Result<Vec<MarketClaim>, Error> {
+        let mut market_ids = Vec::new(env);
+        let count = MarketIndex::get_user_market_count(env, &user);
+        let page_size = config::MAX_MARKETS_PER_PAGE;
+        let mut offset = 0;
+        while offset < count && market_ids.len() < page_size {
+            for position in Self::get_user_positions(env, &user, offset, page_size)?.iter() {
+                if position.claimable > 0 && market_ids.len() < page_size {
+                    market_ids.push_back(position.market_id);
+                }
+            }
+            offset += page_size;
+        }
+
+        Self::process_claim_all(env, user, market_ids)
+    }
+
+    /// Settle a user's claims on several markets and pay them out in one
+    /// transfer
+    ///
+    /// Each market is validated with `validate_market_for_claim` before it
+    /// is settled. With `skip_unclaimable`, markets that fail validation are
+    /// skipped instead of failing the whole claim. Returns the markets that
+    /// paid out something and the amount each paid.
+    fn settle_and_transfer(
+        env: &Env,
+        user: &Address,
+        market_ids: &Vec<Symbol>,
+        skip_unclaimable: bool,
+    ) -> Result<Vec<MarketClaim>, Error> {
+        let mut claims = Vec::new(env);
+        let mut total = 0;
+        for market_id in market_ids.iter() {
+            let validated = MarketStateManager::get_market(env, &market_id).and_then(|market| {
+                VotingValidator::validate_market_for_claim(env, &market, user).map(|_| market)
+            });
+            let mut market = match validated {
+                Ok(market) => market,
+                Err(_) if skip_unclaimable => continue,
+                Err(e) => return Err(e),
+            };
+
+            let amount = Self::settle_claim(env, user, &market_id, &mut market)?;
+            if amount > 0 {
+                total += amount;
+                claims.push_back(MarketClaim { market_id, amount });
+            }
+        }
+
+        if total > 0 {
+            VotingUtils::transfer_winnings(env, user, total)?;
+        }
+        Ok(claims)
+    }
+
+    /// Settle a validated claim without transferring the payout
+    ///
+    /// Calculates the payout at the fee rate frozen at resolution, less the
+    /// user's fee discount, records the discount and referral credit, and
+    /// marks the position claimed.
+    fn settle_claim(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        market: &mut Market,
+    ) -> Result<i128, Error> {
+        if market.state == MarketState::Cancelled {
+            return Self::settle_refund(env, user, market_id, market);
+        }
 
-        // Calculate and process payout
-        let payout = VotingUtils::calculate_user_payout(env, &market, &user)?;
+        let breakdown = FeeManager::freeze_claim_fee(env, market_id)?;
+        let fee_config = FeeConfigManager::get_market_fee_config(env, market_id);
+        let discount = FeeCalculator::get_user_fee_discount(env, user, &fee_config);
+        let payout = VotingUtils::calculate_user_payout(env, market, user, &breakdown, discount)?;
+        let mut rebate = 0;
+        if payout > 0 && discount > 0 {
+            let full_fee_payout =
+                VotingUtils::calculate_user_payout(env, market, user, &breakdown, 0)?;
+            rebate = payout - full_fee_payout;
+            FeeTracker::record_claim_discount(env, market_id, user, &breakdown, discount, rebate)?;
+        }
 
-        // Transfer winnings if any
+        // Credit the user's referrer with a share of the fee they paid
         if payout > 0 {
-            VotingUtils::transfer_winnings(env, &user, payout)?;
+            let fee_free_payout =
+                VotingUtils::calculate_user_payout(env, market, user, &breakdown, 100)?;
+            let credit =
+                ReferralManager::credit_referrer(env, market_id, user, fee_free_payout - payout)?;
+
+            // Fees collected before this claim held its fee share back
+            if market.fee_collected {
+                FeeManager::release_claim_reserve(
+                    env,
+                    market_id,
+                    market,
+                    fee_free_payout - (payout - rebate),
+                    rebate + credit,
+                )?;
+            }
         }
 
         // Mark as claimed
-        MarketStateManager::mark_claimed(&mut market, user, Some(&market_id));
-        MarketStateManager::update_market(env, &market_id, &market);
+        MarketStateManager::mark_claimed(market, user.clone(), Some(market_id));
+        MarketStateManager::update_market(env, market_id, market);
 
         Ok(payout)
     }
 
+    /// Settle a user's refund from an invalid market without transferring it
+    ///
+    /// The invalid market fee kept from the stake is booked to the fee
+    /// treasury.
+    fn settle_refund(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        market: &mut Market,
+    ) -> Result<i128, Error> {
+        let (refund, fee) = VotingUtils::calculate_user_refund(env, market_id, market, user)?;
+        if fee > 0 {
+            FeeTreasury::accrue(env, market_id, fee)?;
+        }
+
+        MarketStateManager::mark_claimed(market, user.clone(), Some(market_id));
+        MarketStateManager::update_market(env, market_id, market);
+
+        Ok(refund)
+    }
+
     /// Collect platform fees from a market (moved to fees module)
     /// This function is deprecated and should use FeeManager::collect_fees instead
     pub fn collect_fees(env: &Env, admin: Address, market_id: Symbol) -> Result<i128, Error> {
@@ -394,18 +607,22 @@ impl VotingManager {
         market_id: Symbol,
     ) -> Result<DisputeThreshold, Error> {
         let _market = MarketStateManager::get_market(env, &market_id)?;
+        let voting_config = ConfigManager::get_market_runtime_config(env, &market_id).voting;
 
         // Get adjustment factors
         let factors = ThresholdUtils::get_threshold_adjustment_factors(env, &market_id)?;
 
         // Calculate adjusted threshold
-        let adjusted_threshold =
-            ThresholdUtils::calculate_adjusted_threshold(BASE_DISPUTE_THRESHOLD, &factors)?;
+        let adjusted_threshold = ThresholdUtils::calculate_adjusted_threshold(
+            voting_config.base_dispute_threshold,
+            &factors,
+            &voting_config,
+        )?;
 
         // Create threshold data
         let threshold = DisputeThreshold {
             market_id: market_id.clone(),
-            base_threshold: BASE_DISPUTE_THRESHOLD,
+            base_threshold: voting_config.base_dispute_threshold,
             adjusted_threshold,
             market_size_factor: factors.market_size_factor,
             activity_factor: factors.activity_factor,
@@ -434,7 +651,8 @@ impl VotingManager {
         VotingValidator::validate_admin_authentication(env, &admin)?;
 
         // Validate new threshold
-        ThresholdValidator::validate_threshold_limits(new_threshold)?;
+        let voting_config = ConfigManager::get_market_runtime_config(env, &market_id).voting;
+        ThresholdValidator::validate_threshold_limits(new_threshold, &voting_config)?;
 
         // Get current threshold
         let current_threshold = ThresholdUtils::get_dispute_threshold(env, &market_id)?;
@@ -479,6 +697,78 @@ impl VotingManager {
     ) -> Result<Vec<ThresholdHistoryEntry>, Error> {
         ThresholdUtils::get_threshold_history(env, &market_id)
     }
+
+    /// Get a page of the markets a user voted in, in the order they voted
+    pub fn get_user_positions(
+        env: &Env,
+        user: &Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<UserPosition>, Error> {
+        let mut positions = Vec::new(env);
+        for market_id in MarketIndex::get_user_markets(env, user, offset, limit).iter() {
+            if let Some(position) = Self::get_user_position(env, &market_id, user)? {
+                positions.push_back(position);
+            }
+        }
+        Ok(positions)
+    }
+
+    /// Get the total a user could claim across every market they voted in
+    pub fn get_claimable_total(env: &Env, user: &Address) -> Result<i128, Error> {
+        let mut total = 0;
+        let count = MarketIndex::get_user_market_count(env, user);
+        let page_size = config::MAX_MARKETS_PER_PAGE;
+        let mut offset = 0;
+        while offset < count {
+            for position in Self::get_user_positions(env, user, offset, page_size)?.iter() {
+                total += position.claimable;
+            }
+            offset += page_size;
+        }
+        Ok(total)
+    }
+
+    /// Get a user's position in a market, if the market still exists and
+    /// the user voted in it
+    pub fn get_user_position(
+        env: &Env,
+        market_id: &Symbol,
+        user: &Address,
+    ) -> Result<Option<UserPosition>, Error> {
+        let market = match MarketStateManager::get_market(env, market_id) {
+            Ok(market) => market,
+            Err(_) => return Ok(None),
+        };
+        let stats = MarketAnalytics::get_user_stats(&market, user);
+        let outcome = match stats.voted_outcome {
+            Some(outcome) => outcome,
+            None => return Ok(None),
+        };
+
+        let claimable = if stats.has_claimed {
+            0
+        } else if market.state == MarketState::Cancelled {
+            VotingUtils::calculate_user_refund(env, market_id, &market, user)?.0
+        } else if market.winning_outcome.is_some() {
+            let breakdown = FeeManager::preview_claim_fee(env, market_id)?;
+            let fee_config = FeeConfigManager::get_market_fee_config(env, market_id);
+            let discount = FeeCalculator::get_user_fee_discount(env, user, &fee_config);
+            VotingUtils::calculate_user_payout(env, &market, user, &breakdown, discount)?
+        } else {
+            0
+        };
+
+        Ok(Some(UserPosition {
+            market_id: market_id.clone(),
+            outcome,
+            stake: stats.stake,
+            state: market.state,
+            winning_outcome: market.winning_outcome,
+            claimable,
+            claimed: stats.has_claimed,
+        }))
+    }
 }
 
 // ===== THRESHOLD UTILITIES =====
@@ -543,10 +833,14 @@ impl ThresholdUtils {
         market_id: &Symbol,
     ) -> Result<ThresholdAdjustmentFactors, Error> {
         let market = MarketStateManager::get_market(env, market_id)?;
+        let voting_config = ConfigManager::get_market_runtime_config(env, market_id).voting;
 
         // Calculate market size factor
-        let market_size_factor =
-            Self::adjust_threshold_by_market_size(env, market_id, BASE_DISPUTE_THRESHOLD)?;
+        let market_size_factor = Self::adjust_threshold_by_market_size(
+            env,
+            market_id,
+            voting_config.base_dispute_threshold,
+        )?;
 
         // Calculate activity factor
 
@@ -554,7 +848,7 @@ impl ThresholdUtils {
             Self::modify_threshold_by_activity(env, market_id, market.votes.len() as u32)?;
 
         // Calculate complexity factor (based on number of outcomes)
-        let complexity_factor = Self::calculate_complexity_factor(&market)?;
+        let complexity_factor = Self::calculate_complexity_factor(&market, &voting_config)?;
 
         let total_adjustment = market_size_factor + activity_factor + complexity_factor;
 
@@ -573,9 +867,10 @@ impl ThresholdUtils {
         base_threshold: i128,
     ) -> Result<i128, Error> {
         let market = MarketStateManager::get_market(env, market_id)?;
+        let voting_config = ConfigManager::get_market_runtime_config(env, market_id).voting;
 
         // For large markets, increase threshold
-        if market.total_staked > LARGE_MARKET_THRESHOLD {
+        if market.total_staked > voting_config.large_market_threshold {
             // Increase by 50% for large markets
             Ok((base_threshold * 150) / 100)
         } else {
@@ -590,25 +885,29 @@ impl ThresholdUtils {
         activity_level: u32,
     ) -> Result<i128, Error> {
         let _market = MarketStateManager::get_market(env, market_id)?;
+        let voting_config = ConfigManager::get_market_runtime_config(env, market_id).voting;
 
         // For high activity markets, increase threshold
-        if activity_level > HIGH_ACTIVITY_THRESHOLD {
+        if activity_level > voting_config.high_activity_threshold {
             // Increase by 25% for high activity
-            Ok((BASE_DISPUTE_THRESHOLD * 25) / 100)
+            Ok((voting_config.base_dispute_threshold * 25) / 100)
         } else {
             Ok(0) // No adjustment for lower activity
         }
     }
 
     /// Calculate complexity factor based on market characteristics
-    pub fn calculate_complexity_factor(market: &Market) -> Result<i128, Error> {
+    pub fn calculate_complexity_factor(
+        market: &Market,
+        config: &VotingConfig,
+    ) -> Result<i128, Error> {
         // More outcomes = higher complexity = higher threshold
         let outcome_count = market.outcomes.len() as i128;
 
         if outcome_count > 3 {
             // Increase by 10% per additional outcome beyond 3
             let additional_outcomes = outcome_count - 3;
-            Ok((BASE_DISPUTE_THRESHOLD * 10 * additional_outcomes) / 100)
+            Ok((config.base_dispute_threshold * 10 * additional_outcomes) / 100)
         } else {
             Ok(0)
         }
@@ -618,15 +917,16 @@ impl ThresholdUtils {
     pub fn calculate_adjusted_threshold(
         base_threshold: i128,
         factors: &ThresholdAdjustmentFactors,
+        config: &VotingConfig,
     ) -> Result<i128, Error> {
         let adjusted = base_threshold + factors.total_adjustment;
 
         // Ensure within limits
-        if adjusted < MIN_DISPUTE_STAKE {
+        if adjusted < config.min_dispute_stake {
             return Err(Error::ThresholdBelowMinimum);
         }
 
-        if adjusted > MAX_DISPUTE_THRESHOLD {
+        if adjusted > config.max_dispute_threshold {
             return Err(Error::ThresholdExceedsMaximum);
         }
 
@@ -647,14 +947,17 @@ impl ThresholdUtils {
     /// Get dispute threshold
     pub fn get_dispute_threshold(env: &Env, market_id: &Symbol) -> Result<DisputeThreshold, Error> {
         let key = symbol_short!("dispute_t");
+        let base_threshold = ConfigManager::get_market_runtime_config(env, market_id)
+            .voting
+            .base_dispute_threshold;
         Ok(env
             .storage()
             .persistent()
             .get(&key)
             .unwrap_or(DisputeThreshold {
                 market_id: market_id.clone(),
-                base_threshold: BASE_DISPUTE_THRESHOLD,
-                adjusted_threshold: BASE_DISPUTE_THRESHOLD,
+                base_threshold,
+                adjusted_threshold: base_threshold,
                 market_size_factor: 0,
                 activity_factor: 0,
                 complexity_factor: 0,
@@ -711,12 +1014,16 @@ impl ThresholdUtils {
     }
 
     /// Validate dispute threshold
-    pub fn validate_dispute_threshold(threshold: i128, _market_id: &Symbol) -> Result<bool, Error> {
-        if threshold < MIN_DISPUTE_STAKE {
+    pub fn validate_dispute_threshold(
+        threshold: i128,
+        _market_id: &Symbol,
+        config: &VotingConfig,
+    ) -> Result<bool, Error> {
+        if threshold < config.min_dispute_stake {
             return Err(Error::ThresholdBelowMinimum);
         }
 
-        if threshold > MAX_DISPUTE_THRESHOLD {
+        if threshold > config.max_dispute_threshold {
             return Err(Error::ThresholdExceedsMaximum);
         }
 
@@ -750,11 +1057,13 @@ impl ThresholdUtils {
 /// ```rust
 /// # use soroban_sdk::{Env, Address};
 /// # use predictify_hybrid::voting::ThresholdValidator;
+/// # use predictify_hybrid::config::ConfigManager;
 /// # let env = Env::default();
+/// let config = ConfigManager::get_default_voting_config();
 ///
 /// // Validate threshold limits
 /// let threshold = 100000000i128; // 10 XLM
-/// match ThresholdValidator::validate_threshold_limits(threshold) {
+/// match ThresholdValidator::validate_threshold_limits(threshold, &config) {
 ///     Ok(()) => println!("Threshold is valid"),
 ///     Err(e) => println!("Threshold validation failed: {:?}", e),
 /// }
@@ -777,12 +1086,12 @@ pub struct ThresholdValidator;
 
 impl ThresholdValidator {
     /// Validate threshold limits
-    pub fn validate_threshold_limits(threshold: i128) -> Result<(), Error> {
-        if threshold < MIN_DISPUTE_STAKE {
+    pub fn validate_threshold_limits(threshold: i128, config: &VotingConfig) -> Result<(), Error> {
+        if threshold < config.min_dispute_stake {
             return Err(Error::ThresholdBelowMinimum);
         }
 
-        if threshold > MAX_DISPUTE_THRESHOLD {
+        if threshold > config.max_dispute_threshold {
             return Err(Error::ThresholdExceedsMaximum);
         }
 
@@ -830,7 +1139,9 @@ impl ThresholdValidator {
 /// # use soroban_sdk::{Env, Address, String, Vec};
 /// # use predictify_hybrid::voting::VotingValidator;
 /// # use predictify_hybrid::types::Market;
+/// # use predictify_hybrid::config::ConfigManager;
 /// # let env = Env::default();
+/// let config = ConfigManager::get_default_voting_config();
 ///
 /// // Validate vote parameters
 /// let outcome = String::from_str(&env, "yes");
@@ -840,14 +1151,14 @@ impl ThresholdValidator {
 /// ];
 /// let stake = 5000000i128; // 0.5 XLM
 ///
-/// match VotingValidator::validate_vote_parameters(&env, &outcome, &valid_outcomes, stake) {
+/// match VotingValidator::validate_vote_parameters(&env, &outcome, &valid_outcomes, stake, &config) {
 ///     Ok(()) => println!("Vote parameters are valid"),
 ///     Err(e) => println!("Vote validation failed: {:?}", e),
 /// }
 ///
 /// // Validate dispute stake
 /// let dispute_stake = 100000000i128; // 10 XLM
-/// match VotingValidator::validate_dispute_stake(dispute_stake) {
+/// match VotingValidator::validate_dispute_stake(dispute_stake, &config) {
 ///     Ok(()) => println!("Dispute stake is sufficient"),
 ///     Err(e) => println!("Dispute stake validation failed: {:?}", e),
 /// }
@@ -888,9 +1199,10 @@ impl VotingValidator {
 
     /// Validate market state for voting
     pub fn validate_market_for_voting(env: &Env, market: &Market) -> Result<(), Error> {
-        // Check if market is active
+        // Check if market is open and within its betting window; pending,
+        // rejected and cancelled markets never take stakes
         let current_time = env.ledger().timestamp();
-        if current_time >= market.end_time {
+        if !market.is_trading_open(current_time) || market.state != MarketState::Active {
             return Err(Error::MarketClosed);
         }
 
@@ -931,8 +1243,8 @@ impl VotingValidator {
             return Err(Error::AlreadyClaimed);
         }
 
-        // Check if market is resolved
-        if market.winning_outcome.is_none() {
+        // Check if market is resolved, or cancelled as invalid
+        if market.winning_outcome.is_none() && market.state != MarketState::Cancelled {
             return Err(Error::MarketNotResolved);
         }
 
@@ -957,6 +1269,7 @@ impl VotingValidator {
         outcome: &String,
         valid_outcomes: &Vec<String>,
         stake: i128,
+        config: &VotingConfig,
     ) -> Result<(), Error> {
         // Validate outcome
         if let Err(e) = MarketValidator::validate_outcome(env, outcome, valid_outcomes) {
@@ -964,7 +1277,7 @@ impl VotingValidator {
         }
 
         // Validate stake
-        if let Err(e) = MarketValidator::validate_stake(stake, MIN_VOTE_STAKE) {
+        if let Err(e) = MarketValidator::validate_stake(stake, config.min_vote_stake) {
             return Err(e);
         }
 
@@ -972,8 +1285,8 @@ impl VotingValidator {
     }
 
     /// Validate dispute stake
-    pub fn validate_dispute_stake(stake: i128) -> Result<(), Error> {
-        if stake < MIN_DISPUTE_STAKE {
+    pub fn validate_dispute_stake(stake: i128, config: &VotingConfig) -> Result<(), Error> {
+        if stake < config.min_dispute_stake {
             return Err(Error::InsufficientStake);
         }
 
@@ -1101,6 +1414,8 @@ impl VotingUtils {
         _env: &Env,
         market: &Market,
         user: &Address,
+        breakdown: &FeeBreakdown,
+        discount_percentage: i128,
     ) -> Result<i128, Error> {
         let winning_outcome = market
             .winning_outcome
@@ -1123,21 +1438,47 @@ impl VotingUtils {
         let winning_stats = MarketAnalytics::calculate_winning_stats(market, winning_outcome);
 
         // Calculate payout
-        let payout = MarketUtils::calculate_payout(
+        let payout = FeeCalculator::calculate_user_payout_after_fees(
             user_stake,
             winning_stats.winning_total,
             winning_stats.total_pool,
-            FEE_PERCENTAGE,
+            breakdown,
+            discount_percentage,
         )?;
 
         Ok(payout)
     }
 
+    /// Calculate the refund owed to a user of an invalid market
+    ///
+    /// Returns the refund and the fee kept from the user's stake at the
+    /// market's `invalid_market_fee_percentage`.
+    pub fn calculate_user_refund(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        user: &Address,
+    ) -> Result<(i128, i128), Error> {
+        if !market.votes.contains_key(user.clone()) {
+            return Err(Error::NothingToClaim);
+        }
+
+        let stake = market.stakes.get(user.clone()).unwrap_or(0);
+        let fee_percentage = ConfigManager::get_market_runtime_config(env, market_id)
+            .fees
+            .invalid_market_fee_percentage;
+        let fee = (stake * fee_percentage) / 100;
+        Ok((stake - fee, fee))
+    }
+
     /// Calculate fee amount for a market (moved to fees module)
     /// This function is deprecated and should use FeeCalculator::calculate_platform_fee instead
-    pub fn calculate_fee_amount(market: &Market) -> Result<i128, Error> {
+    pub fn calculate_fee_amount(
+        market: &Market,
+        config: &crate::fees::FeeConfig,
+    ) -> Result<i128, Error> {
         // Delegate to the fees module
-        crate::fees::FeeCalculator::calculate_platform_fee(market)
+        crate::fees::FeeCalculator::calculate_platform_fee(market, config)
     }
 
     /// Get voting statistics for a market
@@ -1524,11 +1865,15 @@ mod tests {
 
     #[test]
     fn test_voting_validator_stake_validation() {
+        let config = ConfigManager::get_default_voting_config();
+
         // Valid stake
-        assert!(VotingValidator::validate_dispute_stake(MIN_DISPUTE_STAKE).is_ok());
+        assert!(VotingValidator::validate_dispute_stake(config.min_dispute_stake, &config).is_ok());
 
         // Invalid stake
-        assert!(VotingValidator::validate_dispute_stake(MIN_DISPUTE_STAKE - 1).is_err());
+        assert!(
+            VotingValidator::validate_dispute_stake(config.min_dispute_stake - 1, &config).is_err()
+        );
     }
 
     #[test]
@@ -1554,7 +1899,8 @@ mod tests {
         );
         market.total_staked = 100_000_000; // 10 XLM
 
-        let fee = VotingUtils::calculate_fee_amount(&market).unwrap();
+        let config = crate::fees::testing::create_test_fee_config();
+        let fee = VotingUtils::calculate_fee_amount(&market, &config).unwrap();
         assert_eq!(fee, 2_000_000); // 2% of 100_000_000 = 2_000_000 (0.2 XLM)
     }
 